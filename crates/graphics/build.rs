@@ -1,10 +1,12 @@
-use std::path::Path;
+use slang_build::Builder;
 
 fn main() {
-    slang_build::Builder::new(slang_build::Config {
-        input_dir: Path::new("shaders"),
-        output_dir: Path::new("src/shaders"),
-        optimization: slang_build::OptimizationLevel::Maximal,
-    })
-    .compile();
+    // The error shows the failing shader lines, which is more
+    // useful than the `Debug` output of returning it from `main`.
+    if let Err(error) =
+        Builder::from_config_file().and_then(Builder::compile)
+    {
+        eprintln!("{error}");
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,43 @@
+use ash::vk;
+
+use crate::color::{BlendFactor, BlendMode};
+
+impl From<BlendFactor> for vk::BlendFactor {
+    #[inline]
+    fn from(factor: BlendFactor) -> Self {
+        match factor {
+            BlendFactor::Zero => Self::ZERO,
+            BlendFactor::One => Self::ONE,
+            BlendFactor::SourceAlpha => Self::SRC_ALPHA,
+            BlendFactor::OneMinusSourceAlpha => {
+                Self::ONE_MINUS_SRC_ALPHA
+            }
+            BlendFactor::DestinationAlpha => Self::DST_ALPHA,
+            BlendFactor::OneMinusDestinationAlpha => {
+                Self::ONE_MINUS_DST_ALPHA
+            }
+        }
+    }
+}
+
+impl BlendMode {
+    /// Fixed-function blend state for shaders writing
+    /// premultiplied colors. `None` for modes that have to go
+    /// through `layer_blend.slang` instead.
+    pub fn color_blend_attachment(
+        self,
+    ) -> Option<vk::PipelineColorBlendAttachmentState> {
+        let (source, destination) = self.factors()?;
+
+        Some(vk::PipelineColorBlendAttachmentState {
+            blend_enable: vk::TRUE,
+            src_color_blend_factor: source.into(),
+            dst_color_blend_factor: destination.into(),
+            color_blend_op: vk::BlendOp::ADD,
+            src_alpha_blend_factor: source.into(),
+            dst_alpha_blend_factor: destination.into(),
+            alpha_blend_op: vk::BlendOp::ADD,
+            color_write_mask: vk::ColorComponentFlags::RGBA,
+        })
+    }
+}
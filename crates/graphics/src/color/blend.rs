@@ -0,0 +1,552 @@
+use std::ops;
+
+use super::Color;
+
+/// Color with its RGB channels multiplied by alpha, the form
+/// blending and filtering operate on. [`Color`] is always
+/// straight (unassociated) alpha.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PremultipliedColor {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl PremultipliedColor {
+    pub const TRANSPARENT: Self = Self {
+        r: 0.0,
+        g: 0.0,
+        b: 0.0,
+        a: 0.0,
+    };
+
+    /// Divides alpha back out. Fully transparent colors become
+    /// [`Color::TRANSPARENT`].
+    #[inline]
+    pub fn unpremultiply(self) -> Color {
+        if self.a <= 0.0 {
+            return Color::TRANSPARENT;
+        }
+
+        let scale = self.a.recip();
+
+        Color {
+            r: self.r * scale,
+            g: self.g * scale,
+            b: self.b * scale,
+            a: self.a,
+        }
+    }
+
+    /// Composites `self` as the source over `backdrop`.
+    pub fn blend(
+        self,
+        backdrop: PremultipliedColor,
+        mode: BlendMode,
+    ) -> PremultipliedColor {
+        let (sa, ba) = (self.a, backdrop.a);
+
+        if let Some((source_factor, backdrop_factor)) =
+            mode.porter_duff_factors(sa, ba)
+        {
+            let combine = |source: f32, backdrop: f32| {
+                (source * source_factor
+                    + backdrop * backdrop_factor)
+                    .min(1.0)
+            };
+
+            return Self {
+                r: combine(self.r, backdrop.r),
+                g: combine(self.g, backdrop.g),
+                b: combine(self.b, backdrop.b),
+                a: combine(sa, ba),
+            };
+        }
+
+        // Mixing modes composite source-over, with the blend
+        // result applied where both colors overlap.
+        let source = self.unpremultiply();
+        let straight = backdrop.unpremultiply();
+        let [r, g, b] = mode.blend_straight(
+            [straight.r, straight.g, straight.b],
+            [source.r, source.g, source.b],
+        );
+
+        let composite =
+            |source: f32, backdrop: f32, mixed: f32| {
+                source * (1.0 - ba)
+                    + backdrop * (1.0 - sa)
+                    + sa * ba * mixed
+            };
+
+        Self {
+            r: composite(self.r, backdrop.r, r),
+            g: composite(self.g, backdrop.g, g),
+            b: composite(self.b, backdrop.b, b),
+            a: sa + ba * (1.0 - sa),
+        }
+    }
+}
+
+impl ops::Mul<f32> for PremultipliedColor {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            r: self.r * rhs,
+            g: self.g * rhs,
+            b: self.b * rhs,
+            a: self.a * rhs,
+        }
+    }
+}
+
+impl ops::Add for PremultipliedColor {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            r: self.r + rhs.r,
+            g: self.g + rhs.g,
+            b: self.b + rhs.b,
+            a: self.a + rhs.a,
+        }
+    }
+}
+
+impl ops::Sub for PremultipliedColor {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            r: self.r - rhs.r,
+            g: self.g - rhs.g,
+            b: self.b - rhs.b,
+            a: self.a - rhs.a,
+        }
+    }
+}
+
+impl From<Color> for PremultipliedColor {
+    #[inline]
+    fn from(color: Color) -> Self {
+        color.premultiply()
+    }
+}
+
+impl From<PremultipliedColor> for Color {
+    #[inline]
+    fn from(color: PremultipliedColor) -> Self {
+        color.unpremultiply()
+    }
+}
+
+impl Color {
+    #[inline]
+    pub fn premultiply(self) -> PremultipliedColor {
+        PremultipliedColor {
+            r: self.r * self.a,
+            g: self.g * self.a,
+            b: self.b * self.a,
+            a: self.a,
+        }
+    }
+
+    /// Composites `self` as the source over `backdrop`. Both
+    /// colors and the result are straight alpha; blending itself
+    /// happens premultiplied.
+    #[inline]
+    pub fn blend(
+        self,
+        backdrop: Color,
+        mode: BlendMode,
+    ) -> Color {
+        self.premultiply()
+            .blend(backdrop.premultiply(), mode)
+            .unpremultiply()
+    }
+}
+
+/// Compositing operators and blend modes from the W3C
+/// Compositing and Blending spec. The discriminants match the
+/// constants in `shaders/common/blend.slang`.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BlendMode {
+    // Porter-Duff operators:
+    Clear,
+    Source,
+    Destination,
+    #[default]
+    SourceOver,
+    DestinationOver,
+    SourceIn,
+    DestinationIn,
+    SourceOut,
+    DestinationOut,
+    SourceAtop,
+    DestinationAtop,
+    Xor,
+    Plus,
+
+    // Separable blend modes:
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    ColorDodge,
+    ColorBurn,
+    HardLight,
+    SoftLight,
+    Difference,
+    Exclusion,
+
+    // Non-separable blend modes:
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+/// Factor applied to one side of a Porter-Duff operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlendFactor {
+    Zero,
+    One,
+    SourceAlpha,
+    OneMinusSourceAlpha,
+    DestinationAlpha,
+    OneMinusDestinationAlpha,
+}
+
+impl BlendFactor {
+    #[inline]
+    pub fn resolve(
+        self,
+        source_alpha: f32,
+        backdrop_alpha: f32,
+    ) -> f32 {
+        match self {
+            Self::Zero => 0.0,
+            Self::One => 1.0,
+            Self::SourceAlpha => source_alpha,
+            Self::OneMinusSourceAlpha => 1.0 - source_alpha,
+            Self::DestinationAlpha => backdrop_alpha,
+            Self::OneMinusDestinationAlpha => {
+                1.0 - backdrop_alpha
+            }
+        }
+    }
+}
+
+impl BlendMode {
+    /// Source and destination factors of Porter-Duff operators,
+    /// which fixed-function blending can do with premultiplied
+    /// colors. `None` for blend modes that need the backdrop in
+    /// the shader.
+    pub const fn factors(
+        self,
+    ) -> Option<(BlendFactor, BlendFactor)> {
+        use BlendFactor::*;
+
+        let factors = match self {
+            Self::Clear => (Zero, Zero),
+            Self::Source => (One, Zero),
+            Self::Destination => (Zero, One),
+            Self::SourceOver => (One, OneMinusSourceAlpha),
+            Self::DestinationOver => {
+                (OneMinusDestinationAlpha, One)
+            }
+            Self::SourceIn => (DestinationAlpha, Zero),
+            Self::DestinationIn => (Zero, SourceAlpha),
+            Self::SourceOut => (OneMinusDestinationAlpha, Zero),
+            Self::DestinationOut => (Zero, OneMinusSourceAlpha),
+            Self::SourceAtop => {
+                (DestinationAlpha, OneMinusSourceAlpha)
+            }
+            Self::DestinationAtop => {
+                (OneMinusDestinationAlpha, SourceAlpha)
+            }
+            Self::Xor => {
+                (OneMinusDestinationAlpha, OneMinusSourceAlpha)
+            }
+            Self::Plus => (One, One),
+            _ => return None,
+        };
+
+        Some(factors)
+    }
+
+    #[inline]
+    pub const fn is_porter_duff(self) -> bool {
+        self.factors().is_some()
+    }
+
+    #[inline]
+    pub const fn is_separable(self) -> bool {
+        !matches!(
+            self,
+            Self::Hue
+                | Self::Saturation
+                | Self::Color
+                | Self::Luminosity
+        )
+    }
+
+    #[inline]
+    fn porter_duff_factors(
+        self,
+        source_alpha: f32,
+        backdrop_alpha: f32,
+    ) -> Option<(f32, f32)> {
+        let (source, backdrop) = self.factors()?;
+
+        Some((
+            source.resolve(source_alpha, backdrop_alpha),
+            backdrop.resolve(source_alpha, backdrop_alpha),
+        ))
+    }
+
+    /// Blend function `B(Cb, Cs)` on straight RGB.
+    fn blend_straight(
+        self,
+        backdrop: [f32; 3],
+        source: [f32; 3],
+    ) -> [f32; 3] {
+        match self {
+            Self::Hue => set_lum(
+                set_sat(source, sat(backdrop)),
+                lum(backdrop),
+            ),
+            Self::Saturation => set_lum(
+                set_sat(backdrop, sat(source)),
+                lum(backdrop),
+            ),
+            Self::Color => set_lum(source, lum(backdrop)),
+            Self::Luminosity => set_lum(backdrop, lum(source)),
+            _ => [0, 1, 2].map(|index| {
+                self.blend_channel(
+                    backdrop[index],
+                    source[index],
+                )
+            }),
+        }
+    }
+
+    fn blend_channel(self, backdrop: f32, source: f32) -> f32 {
+        let multiply = |b: f32, s: f32| b * s;
+        let screen = |b: f32, s: f32| b + s - b * s;
+        let hard_light = |b: f32, s: f32| {
+            if s <= 0.5 {
+                multiply(b, 2.0 * s)
+            } else {
+                screen(b, 2.0 * s - 1.0)
+            }
+        };
+
+        match self {
+            Self::Multiply => multiply(backdrop, source),
+            Self::Screen => screen(backdrop, source),
+            Self::Overlay => hard_light(source, backdrop),
+            Self::Darken => backdrop.min(source),
+            Self::Lighten => backdrop.max(source),
+            Self::ColorDodge => {
+                if backdrop == 0.0 {
+                    0.0
+                } else if source >= 1.0 {
+                    1.0
+                } else {
+                    (backdrop / (1.0 - source)).min(1.0)
+                }
+            }
+            Self::ColorBurn => {
+                if backdrop >= 1.0 {
+                    1.0
+                } else if source == 0.0 {
+                    0.0
+                } else {
+                    1.0 - ((1.0 - backdrop) / source).min(1.0)
+                }
+            }
+            Self::HardLight => hard_light(backdrop, source),
+            Self::SoftLight => {
+                if source <= 0.5 {
+                    backdrop
+                        - (1.0 - 2.0 * source)
+                            * backdrop
+                            * (1.0 - backdrop)
+                } else {
+                    let d = if backdrop <= 0.25 {
+                        ((16.0 * backdrop - 12.0) * backdrop
+                            + 4.0)
+                            * backdrop
+                    } else {
+                        backdrop.sqrt()
+                    };
+
+                    backdrop
+                        + (2.0 * source - 1.0) * (d - backdrop)
+                }
+            }
+            Self::Difference => (backdrop - source).abs(),
+            Self::Exclusion => {
+                backdrop + source - 2.0 * backdrop * source
+            }
+            // Normal blending for everything else.
+            _ => source,
+        }
+    }
+}
+
+#[inline]
+fn lum([r, g, b]: [f32; 3]) -> f32 {
+    0.3 * r + 0.59 * g + 0.11 * b
+}
+
+fn clip_color(color: [f32; 3]) -> [f32; 3] {
+    let l = lum(color);
+    let min = color[0].min(color[1]).min(color[2]);
+    let max = color[0].max(color[1]).max(color[2]);
+
+    color.map(|channel| {
+        let mut channel = channel;
+
+        if min < 0.0 {
+            channel = l + (channel - l) * l / (l - min);
+        }
+
+        if max > 1.0 {
+            channel = l + (channel - l) * (1.0 - l) / (max - l);
+        }
+
+        channel
+    })
+}
+
+#[inline]
+fn set_lum(color: [f32; 3], l: f32) -> [f32; 3] {
+    let delta = l - lum(color);
+    clip_color(color.map(|channel| channel + delta))
+}
+
+#[inline]
+fn sat([r, g, b]: [f32; 3]) -> f32 {
+    r.max(g).max(b) - r.min(g).min(b)
+}
+
+fn set_sat(color: [f32; 3], saturation: f32) -> [f32; 3] {
+    let min = color[0].min(color[1]).min(color[2]);
+    let max = color[0].max(color[1]).max(color[2]);
+
+    if max <= min {
+        return [0.0; 3];
+    }
+
+    color.map(|channel| {
+        (channel - min) * saturation / (max - min)
+    })
+}
+
+#[test]
+fn test_porter_duff_operators() {
+    let source = Color::RED.with_alpha(0.5);
+    let backdrop = Color::BLUE;
+
+    let over = source.blend(backdrop, BlendMode::SourceOver);
+    assert_eq!(
+        over,
+        Color {
+            r: 0.5,
+            g: 0.0,
+            b: 0.5,
+            a: 1.0
+        }
+    );
+
+    let atop = source.blend(backdrop, BlendMode::SourceAtop);
+    assert_eq!(
+        atop,
+        Color {
+            r: 0.5,
+            g: 0.0,
+            b: 0.5,
+            a: 1.0
+        }
+    );
+
+    let source_in = source
+        .blend(backdrop.with_alpha(0.5), BlendMode::SourceIn);
+    assert_eq!(source_in, Color::RED.with_alpha(0.25));
+
+    let out = source.blend(backdrop, BlendMode::SourceOut);
+    assert_eq!(out, Color::TRANSPARENT);
+
+    let xor =
+        source.blend(backdrop.with_alpha(0.5), BlendMode::Xor);
+    assert!((xor.a - 0.5).abs() < 1e-6);
+    assert!(
+        (xor.r - 0.5).abs() < 1e-6
+            && (xor.b - 0.5).abs() < 1e-6
+    );
+
+    assert_eq!(
+        Color::WHITE.blend(backdrop, BlendMode::Clear),
+        Color::TRANSPARENT
+    );
+    assert_eq!(
+        source.blend(backdrop, BlendMode::DestinationOver),
+        backdrop
+    );
+}
+
+#[test]
+fn test_blend_modes() {
+    let gray = Color::hsl(0.0, 0.0, 0.5);
+    let orange = Color::rgba_u8(255, 128, 0, 255);
+
+    let multiply = orange.blend(gray, BlendMode::Multiply);
+    assert!((multiply.r - 0.5).abs() < 1e-6);
+
+    let screen = orange.blend(gray, BlendMode::Screen);
+    assert!((screen.r - 1.0).abs() < 1e-6);
+    assert!((screen.b - 0.5).abs() < 1e-6);
+
+    assert_eq!(orange.blend(gray, BlendMode::Darken).r, 0.5);
+    assert_eq!(orange.blend(gray, BlendMode::Lighten).r, 1.0);
+    assert_eq!(gray.blend(gray, BlendMode::Difference).r, 0.0);
+    assert_eq!(gray.blend(gray, BlendMode::Exclusion).r, 0.5);
+
+    // Soft light with a 50% gray source leaves the backdrop.
+    let soft = gray.blend(orange, BlendMode::SoftLight);
+    assert!((soft.g - orange.g).abs() < 1e-6);
+
+    // Luminosity of gray onto red keeps the red hue.
+    let luminosity =
+        gray.blend(Color::RED, BlendMode::Luminosity);
+    assert!(luminosity.r > luminosity.g);
+    assert!(
+        (lum([luminosity.r, luminosity.g, luminosity.b]) - 0.5)
+            .abs()
+            < 1e-5
+    );
+
+    let hue = Color::BLUE.blend(Color::RED, BlendMode::Hue);
+    assert!(hue.b > hue.r && hue.b > hue.g);
+
+    // A transparent backdrop shows the source unchanged.
+    for mode in [
+        BlendMode::Multiply,
+        BlendMode::ColorBurn,
+        BlendMode::Hue,
+    ] {
+        let result = orange.blend(Color::TRANSPARENT, mode);
+        assert!((result.g - orange.g).abs() < 1e-6);
+        assert_eq!(result.a, 1.0);
+    }
+}
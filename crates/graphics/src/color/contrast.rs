@@ -0,0 +1,130 @@
+use super::{BlendMode, Color, LinearRgb};
+
+impl Color {
+    /// WCAG 2.x relative luminance, ignoring alpha.
+    #[inline]
+    pub fn relative_luminance(self) -> f32 {
+        let LinearRgb { r, g, b } = self.to_linear_rgb();
+        0.2126 * r + 0.7152 * g + 0.0722 * b
+    }
+
+    /// WCAG 2.x contrast ratio in `1.0..=21.0`; the order of the
+    /// colors doesn't matter. A translucent `self` is composited
+    /// over `background` first, which is taken as opaque.
+    pub fn contrast_ratio(self, background: Color) -> f32 {
+        let background = background.with_alpha(1.0);
+        let foreground =
+            self.blend(background, BlendMode::SourceOver);
+
+        let [lighter, darker] = {
+            let foreground = foreground.relative_luminance();
+            let background = background.relative_luminance();
+            [
+                foreground.max(background),
+                foreground.min(background),
+            ]
+        };
+
+        (lighter + 0.05) / (darker + 0.05)
+    }
+
+    /// APCA lightness contrast (Lc, 0.0.98G-4g) of `self` as text
+    /// on `background`. Positive for dark text on light
+    /// backgrounds, negative for light text on dark ones; body
+    /// text wants a magnitude of about 75 or more.
+    pub fn apca_contrast(self, background: Color) -> f32 {
+        const BLACK_THRESHOLD: f32 = 0.022;
+        const BLACK_CLAMP: f32 = 1.414;
+        const DELTA_Y_MIN: f32 = 0.0005;
+        const SCALE: f32 = 1.14;
+        const LOW_CLIP: f32 = 0.1;
+        const LOW_OFFSET: f32 = 0.027;
+
+        let background = background.with_alpha(1.0);
+        let text =
+            self.blend(background, BlendMode::SourceOver);
+
+        let luminance = |color: Color| {
+            let y = 0.212_672_9
+                * color.r.clamp(0.0, 1.0).powf(2.4)
+                + 0.715_152_2
+                    * color.g.clamp(0.0, 1.0).powf(2.4)
+                + 0.072_175 * color.b.clamp(0.0, 1.0).powf(2.4);
+
+            if y < BLACK_THRESHOLD {
+                y + (BLACK_THRESHOLD - y).powf(BLACK_CLAMP)
+            } else {
+                y
+            }
+        };
+
+        let text = luminance(text);
+        let background = luminance(background);
+
+        if (background - text).abs() < DELTA_Y_MIN {
+            return 0.0;
+        }
+
+        let contrast = if background > text {
+            // Dark text on a light background.
+            let sapc = (background.powf(0.56)
+                - text.powf(0.57))
+                * SCALE;
+
+            if sapc < LOW_CLIP {
+                0.0
+            } else {
+                sapc - LOW_OFFSET
+            }
+        } else {
+            let sapc = (background.powf(0.65)
+                - text.powf(0.62))
+                * SCALE;
+
+            if sapc > -LOW_CLIP {
+                0.0
+            } else {
+                sapc + LOW_OFFSET
+            }
+        };
+
+        contrast * 100.0
+    }
+}
+
+#[test]
+fn test_contrast() {
+    assert!(
+        (Color::BLACK.contrast_ratio(Color::WHITE) - 21.0)
+            .abs()
+            < 1e-3
+    );
+    assert_eq!(
+        Color::WHITE.contrast_ratio(Color::BLACK),
+        Color::BLACK.contrast_ratio(Color::WHITE)
+    );
+    assert_eq!(Color::TEAL.contrast_ratio(Color::TEAL), 1.0);
+
+    // #767676 is the lightest gray passing AA on white.
+    let gray = Color::rgba_u8(0x76, 0x76, 0x76, 255);
+    assert!(
+        (gray.contrast_ratio(Color::WHITE) - 4.54).abs() < 0.01
+    );
+
+    // Half transparent black on white is a mid gray.
+    let translucent = Color::BLACK.with_alpha(0.5);
+    assert!(translucent.contrast_ratio(Color::WHITE) < 5.0);
+
+    // Reference values from the APCA 0.0.98G-4g calculator.
+    let apca = |text: u8, background: u8| {
+        let color =
+            |value| Color::rgba_u8(value, value, value, 255);
+        color(text).apca_contrast(color(background))
+    };
+
+    assert!((apca(0x88, 0xff) - 63.056).abs() < 0.01);
+    assert!((apca(0xff, 0x88) + 68.541).abs() < 0.01);
+    assert!((apca(0x00, 0xff) - 106.04).abs() < 0.01);
+    assert!((apca(0xff, 0x00) + 107.884).abs() < 0.01);
+    assert_eq!(apca(0x80, 0x80), 0.0);
+}
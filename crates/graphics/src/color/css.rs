@@ -0,0 +1,772 @@
+use std::str::FromStr;
+
+use super::{
+    Color, DisplayP3, Hsl, Hsv, Lab, Lch, Oklab, Oklch,
+};
+
+/// Syntax used by [`Color::to_css_string`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum CssFormat {
+    /// `#rrggbb`, or `#rrggbbaa` when translucent.
+    #[default]
+    Hex,
+    /// Named color when one matches exactly, hex otherwise.
+    Named,
+    Rgb,
+    Hsl,
+    Hwb,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    /// `color(srgb r g b)`
+    Srgb,
+    /// `color(display-p3 r g b)`
+    DisplayP3,
+}
+
+const NAMED_COLORS: &[(&str, Color)] = &[
+    ("aliceblue", Color::ALICE_BLUE),
+    ("antiquewhite", Color::ANTIQUE_WHITE),
+    ("aqua", Color::AQUA),
+    ("aquamarine", Color::AQUAMARINE),
+    ("azure", Color::AZURE),
+    ("beige", Color::BEIGE),
+    ("bisque", Color::BISQUE),
+    ("black", Color::BLACK),
+    ("blanchedalmond", Color::BLANCHED_ALMOND),
+    ("blue", Color::BLUE),
+    ("blueviolet", Color::BLUE_VIOLET),
+    ("brown", Color::BROWN),
+    ("burlywood", Color::BURLY_WOOD),
+    ("cadetblue", Color::CADET_BLUE),
+    ("chartreuse", Color::CHARTREUSE),
+    ("chocolate", Color::CHOCOLATE),
+    ("coral", Color::CORAL),
+    ("cornflowerblue", Color::CORNFLOWER_BLUE),
+    ("cornsilk", Color::CORNSILK),
+    ("crimson", Color::CRIMSON),
+    ("cyan", Color::CYAN),
+    ("darkblue", Color::DARK_BLUE),
+    ("darkcyan", Color::DARK_CYAN),
+    ("darkgoldenrod", Color::DARK_GOLDENROD),
+    ("darkgray", Color::DARK_GRAY),
+    ("darkgrey", Color::DARK_GREY),
+    ("darkgreen", Color::DARK_GREEN),
+    ("darkkhaki", Color::DARK_KHAKI),
+    ("darkmagenta", Color::DARK_MAGENTA),
+    ("darkolivegreen", Color::DARK_OLIVE_GREEN),
+    ("darkorange", Color::DARK_ORANGE),
+    ("darkorchid", Color::DARK_ORCHID),
+    ("darkred", Color::DARK_RED),
+    ("darksalmon", Color::DARK_SALMON),
+    ("darkseagreen", Color::DARK_SEA_GREEN),
+    ("darkslateblue", Color::DARK_SLATE_BLUE),
+    ("darkslategray", Color::DARK_SLATE_GRAY),
+    ("darkslategrey", Color::DARK_SLATE_GREY),
+    ("darkturquoise", Color::DARK_TURQUOISE),
+    ("darkviolet", Color::DARK_VIOLET),
+    ("deeppink", Color::DEEP_PINK),
+    ("deepskyblue", Color::DEEP_SKY_BLUE),
+    ("dimgray", Color::DIM_GRAY),
+    ("dimgrey", Color::DIM_GREY),
+    ("dodgerblue", Color::DODGER_BLUE),
+    ("firebrick", Color::FIREBRICK),
+    ("floralwhite", Color::FLORAL_WHITE),
+    ("forestgreen", Color::FOREST_GREEN),
+    ("fuchsia", Color::FUCHSIA),
+    ("gainsboro", Color::GAINSBORO),
+    ("ghostwhite", Color::GHOST_WHITE),
+    ("gold", Color::GOLD),
+    ("goldenrod", Color::GOLDENROD),
+    ("gray", Color::GRAY),
+    ("grey", Color::GREY),
+    ("green", Color::GREEN),
+    ("greenyellow", Color::GREEN_YELLOW),
+    ("honeydew", Color::HONEYDEW),
+    ("hotpink", Color::HOT_PINK),
+    ("indianred", Color::INDIAN_RED),
+    ("indigo", Color::INDIGO),
+    ("ivory", Color::IVORY),
+    ("khaki", Color::KHAKI),
+    ("lavender", Color::LAVENDER),
+    ("lavenderblush", Color::LAVENDER_BLUSH),
+    ("lawngreen", Color::LAWN_GREEN),
+    ("lemonchiffon", Color::LEMON_CHIFFON),
+    ("lightblue", Color::LIGHT_BLUE),
+    ("lightcoral", Color::LIGHT_CORAL),
+    ("lightcyan", Color::LIGHT_CYAN),
+    ("lightgoldenrodyellow", Color::LIGHT_GOLDENROD_YELLOW),
+    ("lightgray", Color::LIGHT_GRAY),
+    ("lightgrey", Color::LIGHT_GREY),
+    ("lightgreen", Color::LIGHT_GREEN),
+    ("lightpink", Color::LIGHT_PINK),
+    ("lightsalmon", Color::LIGHT_SALMON),
+    ("lightseagreen", Color::LIGHT_SEA_GREEN),
+    ("lightskyblue", Color::LIGHT_SKY_BLUE),
+    ("lightslategray", Color::LIGHT_SLATE_GRAY),
+    ("lightslategrey", Color::LIGHT_SLATE_GREY),
+    ("lightsteelblue", Color::LIGHT_STEEL_BLUE),
+    ("lightyellow", Color::LIGHT_YELLOW),
+    ("lime", Color::LIME),
+    ("limegreen", Color::LIME_GREEN),
+    ("linen", Color::LINEN),
+    ("magenta", Color::MAGENTA),
+    ("maroon", Color::MAROON),
+    ("mediumaquamarine", Color::MEDIUM_AQUAMARINE),
+    ("mediumblue", Color::MEDIUM_BLUE),
+    ("mediumorchid", Color::MEDIUM_ORCHID),
+    ("mediumpurple", Color::MEDIUM_PURPLE),
+    ("mediumseagreen", Color::MEDIUM_SEA_GREEN),
+    ("mediumslateblue", Color::MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", Color::MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", Color::MEDIUM_TURQUOISE),
+    ("mediumvioletred", Color::MEDIUM_VIOLET_RED),
+    ("midnightblue", Color::MIDNIGHT_BLUE),
+    ("mintcream", Color::MINT_CREAM),
+    ("mistyrose", Color::MISTY_ROSE),
+    ("moccasin", Color::MOCCASIN),
+    ("navajowhite", Color::NAVAJO_WHITE),
+    ("navy", Color::NAVY),
+    ("oldlace", Color::OLD_LACE),
+    ("olive", Color::OLIVE),
+    ("olivedrab", Color::OLIVE_DRAB),
+    ("orange", Color::ORANGE),
+    ("orangered", Color::ORANGE_RED),
+    ("orchid", Color::ORCHID),
+    ("palegoldenrod", Color::PALE_GOLDENROD),
+    ("palegreen", Color::PALE_GREEN),
+    ("paleturquoise", Color::PALE_TURQUOISE),
+    ("palevioletred", Color::PALE_VIOLET_RED),
+    ("papayawhip", Color::PAPAYA_WHIP),
+    ("peachpuff", Color::PEACH_PUFF),
+    ("peru", Color::PERU),
+    ("pink", Color::PINK),
+    ("plum", Color::PLUM),
+    ("powderblue", Color::POWDER_BLUE),
+    ("purple", Color::PURPLE),
+    ("rebeccapurple", Color::REBECCA_PURPLE),
+    ("red", Color::RED),
+    ("rosybrown", Color::ROSY_BROWN),
+    ("royalblue", Color::ROYAL_BLUE),
+    ("saddlebrown", Color::SADDLE_BROWN),
+    ("salmon", Color::SALMON),
+    ("sandybrown", Color::SANDY_BROWN),
+    ("seagreen", Color::SEA_GREEN),
+    ("seashell", Color::SEA_SHELL),
+    ("sienna", Color::SIENNA),
+    ("silver", Color::SILVER),
+    ("skyblue", Color::SKY_BLUE),
+    ("slateblue", Color::SLATE_BLUE),
+    ("slategray", Color::SLATE_GRAY),
+    ("slategrey", Color::SLATE_GREY),
+    ("snow", Color::SNOW),
+    ("springgreen", Color::SPRING_GREEN),
+    ("steelblue", Color::STEEL_BLUE),
+    ("tan", Color::TAN),
+    ("teal", Color::TEAL),
+    ("thistle", Color::THISTLE),
+    ("tomato", Color::TOMATO),
+    ("transparent", Color::TRANSPARENT),
+    ("turquoise", Color::TURQUOISE),
+    ("violet", Color::VIOLET),
+    ("wheat", Color::WHEAT),
+    ("white", Color::WHITE),
+    ("whitesmoke", Color::WHITE_SMOKE),
+    ("yellow", Color::YELLOW),
+    ("yellowgreen", Color::YELLOW_GREEN),
+];
+
+impl Color {
+    /// Looks up a CSS named color, ignoring ASCII case.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NAMED_COLORS
+            .iter()
+            .find(|(candidate, _)| {
+                candidate.eq_ignore_ascii_case(name)
+            })
+            .map(|&(_, color)| color)
+    }
+
+    /// CSS name of the color, if it is exactly a named color.
+    pub fn name(self) -> Option<&'static str> {
+        NAMED_COLORS
+            .iter()
+            .find(|(_, color)| *color == self)
+            .map(|&(name, _)| name)
+    }
+
+    pub fn to_css_string(self, format: CssFormat) -> String {
+        let alpha = if self.a < 1.0 {
+            format!(" / {}", number(self.a, 3))
+        } else {
+            String::new()
+        };
+
+        match format {
+            CssFormat::Hex => {
+                let [r, g, b, a] =
+                    [self.r, self.g, self.b, self.a].map(
+                        |value| {
+                            (value.clamp(0.0, 1.0) * 255.0)
+                                .round()
+                                as u8
+                        },
+                    );
+
+                if a == 255 {
+                    format!("#{r:02x}{g:02x}{b:02x}")
+                } else {
+                    format!("#{r:02x}{g:02x}{b:02x}{a:02x}")
+                }
+            }
+            CssFormat::Named => match self.name() {
+                Some(name) => name.to_owned(),
+                None => self.to_css_string(CssFormat::Hex),
+            },
+            CssFormat::Rgb => format!(
+                "rgb({} {} {}{alpha})",
+                number(self.r * 255.0, 2),
+                number(self.g * 255.0, 2),
+                number(self.b * 255.0, 2),
+            ),
+            CssFormat::Hsl => {
+                let Hsl { h, s, l } = self.to_hsl();
+
+                format!(
+                    "hsl({} {}% {}%{alpha})",
+                    number(h, 2),
+                    number(s * 100.0, 2),
+                    number(l * 100.0, 2),
+                )
+            }
+            CssFormat::Hwb => {
+                let Hsv { h, s, v } = self.to_hsv();
+
+                format!(
+                    "hwb({} {}% {}%{alpha})",
+                    number(h, 2),
+                    number((1.0 - s) * v * 100.0, 2),
+                    number((1.0 - v) * 100.0, 2),
+                )
+            }
+            CssFormat::Lab => {
+                let Lab { l, a, b } = self.to_lab();
+
+                format!(
+                    "lab({} {} {}{alpha})",
+                    number(l, 2),
+                    number(a, 2),
+                    number(b, 2),
+                )
+            }
+            CssFormat::Lch => {
+                let Lch { l, c, h } = self.to_lch();
+
+                format!(
+                    "lch({} {} {}{alpha})",
+                    number(l, 2),
+                    number(c, 2),
+                    number(h, 2),
+                )
+            }
+            CssFormat::Oklab => {
+                let Oklab { l, a, b } = self.to_oklab();
+
+                format!(
+                    "oklab({} {} {}{alpha})",
+                    number(l, 4),
+                    number(a, 4),
+                    number(b, 4),
+                )
+            }
+            CssFormat::Oklch => {
+                let Oklch { l, c, h } = self.to_oklch();
+
+                format!(
+                    "oklch({} {} {}{alpha})",
+                    number(l, 4),
+                    number(c, 4),
+                    number(h, 2),
+                )
+            }
+            CssFormat::Srgb => format!(
+                "color(srgb {} {} {}{alpha})",
+                number(self.r, 4),
+                number(self.g, 4),
+                number(self.b, 4),
+            ),
+            CssFormat::DisplayP3 => {
+                let DisplayP3 { r, g, b } =
+                    self.to_display_p3();
+
+                format!(
+                    "color(display-p3 {} {} {}{alpha})",
+                    number(r, 4),
+                    number(g, 4),
+                    number(b, 4),
+                )
+            }
+        }
+    }
+}
+
+/// Parses CSS Color 4 syntax: hex colors, named colors and the
+/// `rgb()`, `hsl()`, `hwb()`, `lab()`, `lch()`, `oklab()`,
+/// `oklch()` and `color()` functions.
+impl FromStr for Color {
+    type Err = logging::Error<'static>;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        parse(source.trim()).ok_or_else(|| {
+            logging::ErrorKind::InvalidCssColor(
+                source.to_owned(),
+            )
+            .into_error()
+        })
+    }
+}
+
+fn parse(source: &str) -> Option<Color> {
+    if let Some(hex) = source.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    let Some((name, arguments)) = source.split_once('(') else {
+        return Color::from_name(source);
+    };
+
+    let name = name.trim().to_ascii_lowercase();
+    let arguments = arguments.strip_suffix(')')?.trim();
+
+    if name == "color" {
+        return parse_color_function(arguments);
+    }
+
+    let Arguments {
+        channels: [first, second, third],
+        alpha,
+        legacy,
+    } = Arguments::split(arguments)?;
+
+    let legacy_allowed = matches!(
+        name.as_str(),
+        "rgb" | "rgba" | "hsl" | "hsla"
+    );
+
+    if legacy && !legacy_allowed {
+        return None;
+    }
+
+    let color = match name.as_str() {
+        "rgb" | "rgba" => {
+            let [r, g, b] =
+                [first, second, third].map(|token| {
+                    Component::parse(token).map(|value| {
+                        value.resolve(255.0) / 255.0
+                    })
+                });
+
+            Color {
+                r: r?.clamp(0.0, 1.0),
+                g: g?.clamp(0.0, 1.0),
+                b: b?.clamp(0.0, 1.0),
+                a: 1.0,
+            }
+        }
+        "hsl" | "hsla" => Color::hsl(
+            parse_hue(first)?,
+            (Component::parse(second)?.resolve(100.0) / 100.0)
+                .max(0.0),
+            Component::parse(third)?.resolve(100.0) / 100.0,
+        ),
+        "hwb" => hwb(
+            parse_hue(first)?,
+            Component::parse(second)?.resolve(100.0) / 100.0,
+            Component::parse(third)?.resolve(100.0) / 100.0,
+        ),
+        "lab" => Color::lab(
+            Component::parse(first)?.resolve(100.0).max(0.0),
+            Component::parse(second)?.resolve(125.0),
+            Component::parse(third)?.resolve(125.0),
+        ),
+        "lch" => Color::lch(
+            Component::parse(first)?.resolve(100.0).max(0.0),
+            Component::parse(second)?.resolve(150.0).max(0.0),
+            parse_hue(third)?,
+        ),
+        "oklab" => Color::oklab(
+            Component::parse(first)?.resolve(1.0).max(0.0),
+            Component::parse(second)?.resolve(0.4),
+            Component::parse(third)?.resolve(0.4),
+        ),
+        "oklch" => Color::oklch(
+            Component::parse(first)?.resolve(1.0).max(0.0),
+            Component::parse(second)?.resolve(0.4).max(0.0),
+            parse_hue(third)?,
+        ),
+        _ => return None,
+    };
+
+    with_alpha(color, alpha)
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |index: usize| {
+        u8::from_str_radix(&hex[index..index + 1], 16).ok()
+    };
+    let pair = |index: usize| {
+        u8::from_str_radix(&hex[index..index + 2], 16).ok()
+    };
+
+    match hex.len() {
+        3 | 4 => {
+            let alpha =
+                if hex.len() == 4 { digit(3)? } else { 15 };
+
+            Some(Color::rgba_u8(
+                digit(0)? * 17,
+                digit(1)? * 17,
+                digit(2)? * 17,
+                alpha * 17,
+            ))
+        }
+        6 | 8 => {
+            let alpha =
+                if hex.len() == 8 { pair(6)? } else { 255 };
+
+            Some(Color::rgba_u8(
+                pair(0)?,
+                pair(2)?,
+                pair(4)?,
+                alpha,
+            ))
+        }
+        _ => None,
+    }
+}
+
+/// `color(<space> c1 c2 c3 [/ alpha])`
+fn parse_color_function(arguments: &str) -> Option<Color> {
+    let (space, channels) =
+        arguments.split_once(char::is_whitespace)?;
+
+    let Arguments {
+        channels,
+        alpha,
+        legacy: false,
+    } = Arguments::split(channels)?
+    else {
+        return None;
+    };
+
+    let [x, y, z] = channels.map(|token| {
+        Component::parse(token).map(|value| value.resolve(1.0))
+    });
+    let (x, y, z) = (x?, y?, z?);
+
+    let color = match space.to_ascii_lowercase().as_str() {
+        "srgb" => Color {
+            r: x,
+            g: y,
+            b: z,
+            a: 1.0,
+        },
+        "srgb-linear" => Color::linear_rgb(x, y, z),
+        "display-p3" => Color::display_p3(x, y, z),
+        "xyz" | "xyz-d65" => Color::xyz(x, y, z),
+        _ => return None,
+    };
+
+    with_alpha(color, alpha)
+}
+
+fn with_alpha(
+    color: Color,
+    alpha: Option<&str>,
+) -> Option<Color> {
+    let alpha = match alpha {
+        Some(token) => Component::parse(token)?.resolve(1.0),
+        None => 1.0,
+    };
+
+    Some(color.with_alpha(alpha.clamp(0.0, 1.0)))
+}
+
+fn hwb(hue: f32, whiteness: f32, blackness: f32) -> Color {
+    let whiteness = whiteness.max(0.0);
+    let blackness = blackness.max(0.0);
+    let sum = whiteness + blackness;
+
+    if sum >= 1.0 {
+        let gray = whiteness / sum;
+
+        return Color {
+            r: gray,
+            g: gray,
+            b: gray,
+            a: 1.0,
+        };
+    }
+
+    let v = 1.0 - blackness;
+    Color::hsv(hue, 1.0 - whiteness / v, v)
+}
+
+struct Arguments<'a> {
+    channels: [&'a str; 3],
+    alpha: Option<&'a str>,
+    /// Comma separated, as in `rgba(255, 0, 0, 0.5)`.
+    legacy: bool,
+}
+
+impl<'a> Arguments<'a> {
+    fn split(arguments: &'a str) -> Option<Self> {
+        if arguments.contains(',') {
+            let mut parts = arguments.split(',').map(str::trim);
+            let channels =
+                [parts.next()?, parts.next()?, parts.next()?];
+            let alpha = parts.next();
+
+            if parts.next().is_some()
+                || channels.iter().chain(&alpha).any(|part| {
+                    part.is_empty()
+                        || part.eq_ignore_ascii_case("none")
+                })
+            {
+                return None;
+            }
+
+            return Some(Self {
+                channels,
+                alpha,
+                legacy: true,
+            });
+        }
+
+        let (channels, alpha) = match arguments.split_once('/')
+        {
+            Some((channels, alpha)) => {
+                (channels, Some(alpha.trim()))
+            }
+            None => (arguments, None),
+        };
+
+        let mut tokens = channels.split_whitespace();
+        let channels =
+            [tokens.next()?, tokens.next()?, tokens.next()?];
+
+        if tokens.next().is_some()
+            || alpha.is_some_and(|alpha| {
+                alpha.is_empty()
+                    || alpha.contains(char::is_whitespace)
+            })
+        {
+            return None;
+        }
+
+        Some(Self {
+            channels,
+            alpha,
+            legacy: false,
+        })
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Component {
+    Number(f32),
+    /// Fraction, `50%` is stored as `0.5`.
+    Percentage(f32),
+    None,
+}
+
+impl Component {
+    fn parse(token: &str) -> Option<Self> {
+        if token.eq_ignore_ascii_case("none") {
+            return Some(Self::None);
+        }
+
+        match token.strip_suffix('%') {
+            Some(percentage) => Some(Self::Percentage(
+                parse_number(percentage)? / 100.0,
+            )),
+            None => Some(Self::Number(parse_number(token)?)),
+        }
+    }
+
+    /// Percentages are relative to `reference`, which is what
+    /// `100%` stands for in the channel.
+    #[inline]
+    fn resolve(self, reference: f32) -> f32 {
+        match self {
+            Self::Number(value) => value,
+            Self::Percentage(fraction) => fraction * reference,
+            Self::None => 0.0,
+        }
+    }
+}
+
+fn parse_number(token: &str) -> Option<f32> {
+    token.parse::<f32>().ok().filter(|value| value.is_finite())
+}
+
+/// Hue in degrees, accepting `deg`, `grad`, `rad` and `turn`.
+fn parse_hue(token: &str) -> Option<f32> {
+    if token.eq_ignore_ascii_case("none") {
+        return Some(0.0);
+    }
+
+    let token = token.to_ascii_lowercase();
+
+    let degrees = if let Some(value) = token.strip_suffix("deg")
+    {
+        parse_number(value)?
+    } else if let Some(value) = token.strip_suffix("grad") {
+        parse_number(value)? * 0.9
+    } else if let Some(value) = token.strip_suffix("rad") {
+        parse_number(value)?.to_degrees()
+    } else if let Some(value) = token.strip_suffix("turn") {
+        parse_number(value)? * 360.0
+    } else {
+        parse_number(&token)?
+    };
+
+    Some(degrees)
+}
+
+/// Shortest decimal form with at most `decimals` digits.
+fn number(value: f32, decimals: usize) -> String {
+    let text = format!("{value:.decimals$}");
+    let text = if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        &text
+    };
+
+    match text {
+        "-0" => "0".to_owned(),
+        text => text.to_owned(),
+    }
+}
+
+#[test]
+fn test_parse_css_colors() {
+    let parse = |source: &str| source.parse::<Color>().unwrap();
+    let red = Color::RED;
+
+    for source in [
+        "red",
+        "RED",
+        "#f00",
+        "#F00F",
+        "#ff0000",
+        "#ff0000ff",
+        "rgb(255 0 0)",
+        "rgb(255, 0, 0)",
+        "rgba(100%, 0%, 0%, 1)",
+        "rgb(255 none 0 / 100%)",
+        "hsl(0 100% 50%)",
+        "hsl(0deg, 100%, 50%)",
+        "hsla(1turn 100 50 / 1)",
+        "hwb(0 0% 0%)",
+        "color(srgb 1 0 0)",
+        "color(srgb-linear 100% 0 0)",
+        "lab(54.29 80.8 69.89)",
+        "oklch(0.62796 0.25768 29.23)",
+        "oklab(62.796% 0.22486 0.12585)",
+    ] {
+        let color = parse(source);
+        let error = (color.r - red.r)
+            .abs()
+            .max((color.g - red.g).abs())
+            .max((color.b - red.b).abs());
+
+        assert!(error < 2e-3, "{source}: {color:?}");
+        assert_eq!(color.a, 1.0, "{source}");
+    }
+
+    assert_eq!(parse("#0000"), Color::TRANSPARENT);
+    assert_eq!(parse("rgb(0 0 0 / 50%)").a, 0.5);
+    assert_eq!(parse(" rebeccapurple "), Color::REBECCA_PURPLE);
+    assert_eq!(parse("hwb(90 60% 60%)").r, 0.5);
+
+    for invalid in [
+        "",
+        "#ff",
+        "#gggggg",
+        "redd",
+        "rgb(255 0)",
+        "rgb(255 0 0",
+        "rgb(255, 0 0)",
+        "rgb(255, none, 0)",
+        "oklch(0.5, 0.1, 20)",
+        "color(display-p4 1 0 0)",
+    ] {
+        assert!(invalid.parse::<Color>().is_err(), "{invalid}");
+    }
+}
+
+#[test]
+fn test_css_string_round_trips() {
+    let colors = [
+        Color::CORNFLOWER_BLUE,
+        Color::DARK_OLIVE_GREEN.with_alpha(0.5),
+        Color::rgba_u8(18, 52, 86, 120),
+        Color::WHITE,
+        Color::BLACK,
+    ];
+
+    for color in colors {
+        for format in [
+            CssFormat::Hex,
+            CssFormat::Named,
+            CssFormat::Rgb,
+            CssFormat::Hsl,
+            CssFormat::Hwb,
+            CssFormat::Lab,
+            CssFormat::Lch,
+            CssFormat::Oklab,
+            CssFormat::Oklch,
+            CssFormat::Srgb,
+            CssFormat::DisplayP3,
+        ] {
+            let css = color.to_css_string(format);
+            let parsed = css.parse::<Color>().unwrap();
+            let error = (parsed.r - color.r)
+                .abs()
+                .max((parsed.g - color.g).abs())
+                .max((parsed.b - color.b).abs())
+                .max((parsed.a - color.a).abs());
+
+            assert!(
+                error < 3e-3,
+                "{css}: {parsed:?} != {color:?}"
+            );
+        }
+    }
+
+    assert_eq!(
+        Color::RED.to_css_string(CssFormat::Named),
+        "red"
+    );
+    assert_eq!(
+        Color::RED.to_css_string(CssFormat::Rgb),
+        "rgb(255 0 0)"
+    );
+    assert_eq!(
+        Color::RED
+            .with_alpha(0.5)
+            .to_css_string(CssFormat::Hsl),
+        "hsl(0 100% 50% / 0.5)"
+    );
+    assert_eq!(
+        Color::rgba_u8(18, 52, 86, 120)
+            .to_css_string(CssFormat::Hex),
+        "#12345678"
+    );
+}
@@ -0,0 +1,163 @@
+use super::{Color, Lab, Oklab};
+
+impl Color {
+    /// Euclidean distance in Oklab.
+    #[inline]
+    pub fn delta_e_ok(self, other: Color) -> f32 {
+        let Oklab { l, a, b } = self.to_oklab();
+        let other = other.to_oklab();
+
+        let (dl, da, db) =
+            (l - other.l, a - other.a, b - other.b);
+        (dl * dl + da * da + db * db).sqrt()
+    }
+
+    /// CIEDE2000 color difference in (D50) Lab, as in CSS
+    /// Color 4. Around 2.3 is a just noticeable difference.
+    #[inline]
+    pub fn delta_e_2000(self, other: Color) -> f32 {
+        ciede2000(self.to_lab(), other.to_lab())
+    }
+}
+
+fn ciede2000(reference: Lab, sample: Lab) -> f32 {
+    use std::f32::consts::PI;
+
+    let pow7 = |value: f32| value.powi(7);
+    let hue = |b: f32, a: f32| {
+        if a == 0.0 && b == 0.0 {
+            0.0
+        } else {
+            b.atan2(a).rem_euclid(2.0 * PI)
+        }
+    };
+
+    let chroma_1 = reference.a.hypot(reference.b);
+    let chroma_2 = sample.a.hypot(sample.b);
+    let chroma_mean = (chroma_1 + chroma_2) * 0.5;
+
+    let g = 0.5
+        * (1.0
+            - (pow7(chroma_mean)
+                / (pow7(chroma_mean) + pow7(25.0)))
+            .sqrt());
+
+    let a_1 = reference.a * (1.0 + g);
+    let a_2 = sample.a * (1.0 + g);
+
+    let c_1 = a_1.hypot(reference.b);
+    let c_2 = a_2.hypot(sample.b);
+    let h_1 = hue(reference.b, a_1);
+    let h_2 = hue(sample.b, a_2);
+
+    let delta_l = sample.l - reference.l;
+    let delta_c = c_2 - c_1;
+
+    let delta_h = if c_1 * c_2 == 0.0 {
+        0.0
+    } else if (h_2 - h_1).abs() <= PI {
+        h_2 - h_1
+    } else if h_2 <= h_1 {
+        h_2 - h_1 + 2.0 * PI
+    } else {
+        h_2 - h_1 - 2.0 * PI
+    };
+    let delta_hue =
+        2.0 * (c_1 * c_2).sqrt() * (delta_h * 0.5).sin();
+
+    let l_mean = (reference.l + sample.l) * 0.5;
+    let c_mean = (c_1 + c_2) * 0.5;
+
+    let h_mean = if c_1 * c_2 == 0.0 {
+        h_1 + h_2
+    } else if (h_1 - h_2).abs() <= PI {
+        (h_1 + h_2) * 0.5
+    } else if h_1 + h_2 < 2.0 * PI {
+        (h_1 + h_2 + 2.0 * PI) * 0.5
+    } else {
+        (h_1 + h_2 - 2.0 * PI) * 0.5
+    };
+
+    let t = 1.0 - 0.17 * (h_mean - 30f32.to_radians()).cos()
+        + 0.24 * (2.0 * h_mean).cos()
+        + 0.32 * (3.0 * h_mean + 6f32.to_radians()).cos()
+        - 0.20 * (4.0 * h_mean - 63f32.to_radians()).cos();
+
+    let rotation = 30f32.to_radians()
+        * (-((h_mean.to_degrees() - 275.0) / 25.0).powi(2))
+            .exp();
+    let r_c = 2.0
+        * (pow7(c_mean) / (pow7(c_mean) + pow7(25.0))).sqrt();
+    let r_t = -r_c * (2.0 * rotation).sin();
+
+    let l_offset = (l_mean - 50.0).powi(2);
+    let s_l = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+    let s_c = 1.0 + 0.045 * c_mean;
+    let s_h = 1.0 + 0.015 * c_mean * t;
+
+    let l = delta_l / s_l;
+    let c = delta_c / s_c;
+    let h = delta_hue / s_h;
+
+    (l * l + c * c + h * h + r_t * c * h).sqrt()
+}
+
+#[test]
+fn test_color_differences() {
+    // Pairs from Sharma, Wu and Dalal's CIEDE2000 test data.
+    let pairs = [
+        (
+            [50.0, 2.6772, -79.7751],
+            [50.0, 0.0, -82.7485],
+            2.0425,
+        ),
+        ([50.0, -1.3802, -84.2814], [50.0, 0.0, -82.7485], 1.0),
+        ([50.0, 2.5, 0.0], [50.0, 0.0, -2.5], 4.3065),
+        ([50.0, 2.5, 0.0], [73.0, 25.0, -18.0], 27.1492),
+        (
+            [60.2574, -34.0099, 36.2677],
+            [60.4626, -34.1751, 39.4387],
+            1.2644,
+        ),
+        (
+            [22.7233, 20.0904, -46.694],
+            [23.0331, 14.973, -42.5619],
+            2.0373,
+        ),
+        (
+            [90.8027, -2.0831, 1.441],
+            [91.1528, -1.6435, 0.0447],
+            1.4441,
+        ),
+        (
+            [2.0776, 0.0795, -1.135],
+            [0.9033, -0.0636, -0.5514],
+            0.9082,
+        ),
+    ];
+
+    for ([l, a, b], [l2, a2, b2], expected) in pairs {
+        let delta = ciede2000(
+            Lab { l, a, b },
+            Lab {
+                l: l2,
+                a: a2,
+                b: b2,
+            },
+        );
+        assert!(
+            (delta - expected).abs() < 1e-3,
+            "{delta} {expected}"
+        );
+    }
+
+    assert_eq!(Color::CORAL.delta_e_2000(Color::CORAL), 0.0);
+    assert!(
+        (Color::BLACK.delta_e_2000(Color::WHITE) - 100.0).abs()
+            < 0.1
+    );
+    assert!(
+        (Color::BLACK.delta_e_ok(Color::WHITE) - 1.0).abs()
+            < 1e-3
+    );
+}
@@ -0,0 +1,173 @@
+use super::{Color, DisplayP3, Oklch};
+
+/// RGB gamut a color has to be displayable in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Gamut {
+    #[default]
+    Srgb,
+    DisplayP3,
+}
+
+impl Gamut {
+    /// Rounding slack allowed on channel values.
+    const EPSILON: f32 = 1e-4;
+
+    /// Channels of `color` in this gamut's RGB space.
+    #[inline]
+    fn channels(self, color: Color) -> [f32; 3] {
+        match self {
+            Self::Srgb => [color.r, color.g, color.b],
+            Self::DisplayP3 => {
+                let DisplayP3 { r, g, b } =
+                    color.to_display_p3();
+                [r, g, b]
+            }
+        }
+    }
+
+    #[inline]
+    fn contains(self, color: Color) -> bool {
+        self.channels(color).iter().all(|channel| {
+            (-Self::EPSILON..=1.0 + Self::EPSILON)
+                .contains(channel)
+        })
+    }
+
+    /// Clamps each channel in this gamut's RGB space.
+    #[inline]
+    fn clip(self, color: Color) -> Color {
+        let [r, g, b] = self
+            .channels(color)
+            .map(|channel| channel.clamp(0.0, 1.0));
+
+        let clipped = match self {
+            Self::Srgb => Color { r, g, b, a: 1.0 },
+            Self::DisplayP3 => Color::display_p3(r, g, b),
+        };
+
+        clipped.with_alpha(color.a)
+    }
+}
+
+impl Color {
+    #[inline]
+    pub fn in_gamut(self, gamut: Gamut) -> bool {
+        gamut.contains(self)
+    }
+
+    /// Maps the color into `gamut` with the CSS Color 4
+    /// algorithm: chroma is reduced in OKLCH at constant
+    /// lightness and hue until clipping the result changes it by
+    /// less than a just noticeable difference. Colors already in
+    /// gamut are returned unchanged.
+    pub fn to_gamut(self, gamut: Gamut) -> Color {
+        // Just noticeable difference in deltaE OK.
+        const JND: f32 = 0.02;
+        const EPSILON: f32 = 1e-4;
+
+        if gamut.contains(self) {
+            return self;
+        }
+
+        let origin = self.to_oklch();
+
+        if origin.l >= 1.0 {
+            return Color::WHITE.with_alpha(self.a);
+        }
+
+        if origin.l <= 0.0 {
+            return Color::BLACK.with_alpha(self.a);
+        }
+
+        let with_chroma = |c: f32| {
+            Color::from(Oklch { c, ..origin })
+                .with_alpha(self.a)
+        };
+
+        let mut clipped = gamut.clip(self);
+
+        if clipped.delta_e_ok(self) < JND {
+            return clipped;
+        }
+
+        let mut min = 0.0;
+        let mut max = origin.c;
+        let mut min_in_gamut = true;
+
+        while max - min > EPSILON {
+            let chroma = (min + max) * 0.5;
+            let current = with_chroma(chroma);
+
+            if min_in_gamut && gamut.contains(current) {
+                min = chroma;
+                continue;
+            }
+
+            clipped = gamut.clip(current);
+            let error = clipped.delta_e_ok(current);
+
+            if error < JND {
+                if JND - error < EPSILON {
+                    return clipped;
+                }
+
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+
+        clipped
+    }
+}
+
+#[test]
+fn test_gamut_queries() {
+    assert!(Color::REBECCA_PURPLE.in_gamut(Gamut::Srgb));
+    assert!(Color::REBECCA_PURPLE.in_gamut(Gamut::DisplayP3));
+
+    let p3_green = Color::display_p3(0.0, 1.0, 0.0);
+    assert!(p3_green.in_gamut(Gamut::DisplayP3));
+    assert!(!p3_green.in_gamut(Gamut::Srgb));
+
+    let vivid = Color::oklch(0.7, 0.4, 150.0);
+    assert!(!vivid.in_gamut(Gamut::Srgb));
+    assert!(!vivid.in_gamut(Gamut::DisplayP3));
+}
+
+#[test]
+fn test_gamut_mapping_preserves_hue() {
+    for hue in [0.0, 29.0, 90.0, 150.0, 210.0, 264.0, 330.0] {
+        let origin =
+            Color::oklch(0.7, 0.4, hue).with_alpha(0.5);
+        let srgb = origin.to_gamut(Gamut::Srgb);
+        let p3 = origin.to_gamut(Gamut::DisplayP3);
+
+        assert!(srgb.in_gamut(Gamut::Srgb));
+        assert!(p3.in_gamut(Gamut::DisplayP3));
+        assert_eq!(srgb.a, 0.5);
+
+        let original = origin.to_oklch();
+        let mapped = srgb.to_oklch();
+        let wide = p3.to_oklch();
+
+        // Chroma is reduced at constant lightness and hue; the
+        // final clip moves the color by less than the JND.
+        let target = Color::from(Oklch {
+            c: mapped.c,
+            ..original
+        })
+        .with_alpha(0.5);
+
+        assert!(mapped.c < original.c);
+        assert!(srgb.delta_e_ok(target) <= 0.021, "{hue}");
+        assert!(wide.c >= mapped.c - 1e-3, "{hue}");
+    }
+
+    assert_eq!(
+        Color::oklch(1.2, 0.1, 30.0).to_gamut(Gamut::Srgb),
+        Color::WHITE
+    );
+    assert_eq!(Color::GOLD.to_gamut(Gamut::Srgb), Color::GOLD);
+}
@@ -0,0 +1,128 @@
+use super::{Color, Gamut, Oklch};
+
+impl Color {
+    /// Same lightness and chroma at `degrees` around the OKLCH
+    /// hue circle, mapped into sRGB. Alpha is kept.
+    #[inline]
+    pub fn rotate_hue(self, degrees: f32) -> Color {
+        let Oklch { l, c, h } = self.to_oklch();
+
+        Color::oklch(l, c, (h + degrees).rem_euclid(360.0))
+            .with_alpha(self.a)
+            .to_gamut(Gamut::Srgb)
+    }
+
+    /// `self` and the color opposite to it in OKLCH.
+    #[inline]
+    pub fn complementary(self) -> [Color; 2] {
+        [self, self.rotate_hue(180.0)]
+    }
+
+    /// Like [`Color::complementary`], with the complement split
+    /// into its two neighbors `angle` degrees away.
+    #[inline]
+    pub fn split_complementary(self, angle: f32) -> [Color; 3] {
+        [
+            self,
+            self.rotate_hue(180.0 - angle),
+            self.rotate_hue(180.0 + angle),
+        ]
+    }
+
+    /// Three hues evenly spaced around OKLCH, starting at `self`.
+    #[inline]
+    pub fn triadic(self) -> [Color; 3] {
+        [self, self.rotate_hue(120.0), self.rotate_hue(240.0)]
+    }
+
+    /// Neighbors `angle` degrees to either side, with `self` in
+    /// the middle.
+    #[inline]
+    pub fn analogous(self, angle: f32) -> [Color; 3] {
+        [self.rotate_hue(-angle), self, self.rotate_hue(angle)]
+    }
+
+    /// `N` steps from `self` towards white, lightest last, at
+    /// the same OKLCH hue. Chroma fades with the distance
+    /// covered.
+    pub fn tints<const N: usize>(self) -> [Color; N] {
+        let Oklch { l, c, h } = self.to_oklch();
+
+        std::array::from_fn(|index| {
+            let t = (index + 1) as f32 / (N + 1) as f32;
+
+            Color::oklch(l + (1.0 - l) * t, c * (1.0 - t), h)
+                .with_alpha(self.a)
+                .to_gamut(Gamut::Srgb)
+        })
+    }
+
+    /// `N` steps from `self` towards black, darkest last, at the
+    /// same OKLCH hue.
+    pub fn shades<const N: usize>(self) -> [Color; N] {
+        let Oklch { l, c, h } = self.to_oklch();
+
+        std::array::from_fn(|index| {
+            let t = (index + 1) as f32 / (N + 1) as f32;
+
+            Color::oklch(l * (1.0 - t), c * (1.0 - t), h)
+                .with_alpha(self.a)
+                .to_gamut(Gamut::Srgb)
+        })
+    }
+}
+
+#[test]
+fn test_harmonies() {
+    let hue_distance = |left: Color, right: Color| {
+        let delta = (left.to_oklch().h - right.to_oklch().h)
+            .rem_euclid(360.0);
+        delta.min(360.0 - delta)
+    };
+
+    let base = Color::rgba_u8(59, 130, 246, 255);
+    let [same, complement] = base.complementary();
+
+    assert_eq!(same, base);
+    assert!(complement.in_gamut(Gamut::Srgb));
+    // Gamut mapping keeps hue, but its final clip may move it
+    // by up to a just noticeable difference.
+    assert!(
+        (hue_distance(base, complement) - 180.0).abs() < 6.0
+    );
+
+    let [_, second, third] = base.triadic();
+    assert!((hue_distance(base, second) - 120.0).abs() < 6.0);
+    assert!((hue_distance(second, third) - 120.0).abs() < 6.0);
+
+    let [left, middle, right] =
+        base.with_alpha(0.5).analogous(30.0);
+    assert_eq!(middle, base.with_alpha(0.5));
+    assert_eq!(left.a, 0.5);
+    assert!((hue_distance(left, right) - 60.0).abs() < 6.0);
+
+    let [_, near, far] = base.split_complementary(30.0);
+    assert!((hue_distance(near, far) - 60.0).abs() < 6.0);
+
+    let tints = base.tints::<4>();
+    let shades = base.shades::<4>();
+    let lightness = |color: Color| color.to_oklch().l;
+
+    let lighter = |pair: &[Color]| {
+        lightness(pair[0]) < lightness(pair[1])
+    };
+    let darker = |pair: &[Color]| {
+        lightness(pair[0]) > lightness(pair[1])
+    };
+
+    assert!(tints.windows(2).all(lighter));
+    assert!(shades.windows(2).all(darker));
+    assert!(lightness(tints[0]) > lightness(base));
+    assert!(lightness(tints[3]) < 1.0);
+    assert!(lightness(shades[3]) > 0.0);
+    assert!(
+        shades
+            .iter()
+            .all(|shade| hue_distance(*shade, base) < 6.0)
+    );
+}
@@ -0,0 +1,269 @@
+use super::{Color, Hsl, LinearRgb, Oklab, Oklch};
+
+/// Path hues take between two colors, as in CSS Color 4.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum HueInterpolation {
+    #[default]
+    Shorter,
+    Longer,
+    Increasing,
+    Decreasing,
+}
+
+impl HueInterpolation {
+    /// Signed hue change in degrees from `from` to `to`.
+    fn delta(self, from: f32, to: f32) -> f32 {
+        let delta = (to - from).rem_euclid(360.0);
+
+        match self {
+            Self::Shorter if delta > 180.0 => delta - 360.0,
+            Self::Shorter => delta,
+            Self::Longer if delta == 0.0 => 360.0,
+            Self::Longer if delta < 180.0 => delta - 360.0,
+            Self::Longer => delta,
+            Self::Increasing => delta,
+            Self::Decreasing if delta == 0.0 => 0.0,
+            Self::Decreasing => delta - 360.0,
+        }
+    }
+}
+
+/// Color space colors are mixed in, for [`Color::mix`] and
+/// gradients. Colors are always interpolated with premultiplied
+/// alpha; hues of the polar spaces aren't premultiplied.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ColorInterpolation {
+    /// Gamma-encoded sRGB, the CSS default for legacy colors.
+    #[default]
+    Srgb,
+    LinearRgb,
+    Oklab,
+    Oklch(HueInterpolation),
+    Hsl(HueInterpolation),
+}
+
+impl ColorInterpolation {
+    /// Chroma or saturation below which the hue is powerless.
+    const ACHROMATIC: f32 = 1e-4;
+
+    /// Premultiplied components in the interpolation space:
+    /// `[l, c, h, alpha]` for OKLCH and `[h, s, l, alpha]` for
+    /// HSL, other spaces in their usual order.
+    pub fn to_components(self, color: Color) -> [f32; 4] {
+        let [x, y, z] = match self {
+            Self::Srgb => [color.r, color.g, color.b],
+            Self::LinearRgb => {
+                let LinearRgb { r, g, b } =
+                    color.to_linear_rgb();
+                [r, g, b]
+            }
+            Self::Oklab => {
+                let Oklab { l, a, b } = color.to_oklab();
+                [l, a, b]
+            }
+            Self::Oklch(_) => {
+                let Oklch { l, c, h } = color.to_oklch();
+                return [l * color.a, c * color.a, h, color.a];
+            }
+            Self::Hsl(_) => {
+                let Hsl { h, s, l } = color.to_hsl();
+                return [h, s * color.a, l * color.a, color.a];
+            }
+        };
+
+        [x * color.a, y * color.a, z * color.a, color.a]
+    }
+
+    /// Inverse of [`ColorInterpolation::to_components`].
+    pub fn from_components(
+        self,
+        [x, y, z, a]: [f32; 4],
+    ) -> Color {
+        if a <= 0.0 {
+            return Color::TRANSPARENT;
+        }
+
+        let color = match self {
+            Self::Srgb => Color {
+                r: x / a,
+                g: y / a,
+                b: z / a,
+                a,
+            },
+            Self::LinearRgb => {
+                Color::linear_rgb(x / a, y / a, z / a)
+            }
+            Self::Oklab => Color::oklab(x / a, y / a, z / a),
+            Self::Oklch(_) => {
+                Color::oklch(x / a, y / a, z.rem_euclid(360.0))
+            }
+            Self::Hsl(_) => {
+                Color::hsl(x.rem_euclid(360.0), y / a, z / a)
+            }
+        };
+
+        color.with_alpha(a)
+    }
+
+    /// Same space with another hue interpolation method. Spaces
+    /// without hue are returned unchanged.
+    #[inline]
+    pub const fn with_hue(
+        self,
+        method: HueInterpolation,
+    ) -> Self {
+        match self {
+            Self::Oklch(_) => Self::Oklch(method),
+            Self::Hsl(_) => Self::Hsl(method),
+            space => space,
+        }
+    }
+
+    /// Interpolates premultiplied components, `t` of `0.0` being
+    /// `from`.
+    pub fn interpolate(
+        self,
+        from: Color,
+        to: Color,
+        t: f32,
+    ) -> Color {
+        let mut components =
+            [self.to_components(from), self.to_components(to)];
+        self.unwrap_hues(&mut components);
+
+        let [from, to] = components;
+
+        self.from_components(
+            [0, 1, 2, 3]
+                .map(|i| from[i] + (to[i] - from[i]) * t),
+        )
+    }
+
+    /// Index of the hue component and how it interpolates.
+    #[inline]
+    const fn hue(self) -> Option<(usize, HueInterpolation)> {
+        match self {
+            Self::Oklch(method) => Some((2, method)),
+            Self::Hsl(method) => Some((0, method)),
+            _ => None,
+        }
+    }
+
+    /// Rewrites the hues of consecutive components so that
+    /// interpolating them linearly follows the hue
+    /// interpolation method. Powerless hues of achromatic colors
+    /// take the hue of their neighbor first.
+    pub(crate) fn unwrap_hues(
+        self,
+        components: &mut [[f32; 4]],
+    ) {
+        let Some((hue, method)) = self.hue() else {
+            return;
+        };
+
+        // Chroma and saturation are both the second component.
+        let powerless = |[_, chroma, _, a]: [f32; 4]| {
+            a <= 0.0 || chroma / a < Self::ACHROMATIC
+        };
+
+        let Some(first) = components
+            .iter()
+            .find(|&&component| !powerless(component))
+            .map(|component| component[hue])
+        else {
+            return;
+        };
+
+        let mut previous: Option<f32> = None;
+
+        for component in components.iter_mut() {
+            if powerless(*component) {
+                component[hue] = previous.unwrap_or(first);
+            } else if let Some(previous) = previous {
+                component[hue] = previous
+                    + method.delta(previous, component[hue]);
+            }
+
+            previous = Some(component[hue]);
+        }
+    }
+}
+
+impl Color {
+    /// Mixes like CSS `color-mix()`: `t` of `0.0` is `self` and
+    /// `1.0` is `other`.
+    #[inline]
+    pub fn mix(
+        self,
+        other: Color,
+        t: f32,
+        space: ColorInterpolation,
+    ) -> Color {
+        space.interpolate(self, other, t)
+    }
+}
+
+#[test]
+fn test_mix() {
+    let assert_close = |left: Color, right: Color| {
+        assert!(
+            left.delta_e_ok(right) < 1e-3,
+            "{left:?} {right:?}"
+        );
+        assert!((left.a - right.a).abs() < 1e-5);
+    };
+
+    // color-mix(in srgb, rgb(255 0 0 / 0.5), blue)
+    let mixed = Color::RED.with_alpha(0.5).mix(
+        Color::BLUE,
+        0.5,
+        ColorInterpolation::Srgb,
+    );
+    assert!((mixed.r - 1.0 / 3.0).abs() < 1e-6);
+    assert!((mixed.b - 2.0 / 3.0).abs() < 1e-6);
+    assert_eq!(mixed.a, 0.75);
+
+    assert_close(
+        Color::RED.mix(
+            Color::BLUE,
+            0.0,
+            ColorInterpolation::Oklab,
+        ),
+        Color::RED,
+    );
+
+    // Halfway between hues 10 and 350.
+    let hue = |method| {
+        let hue = Color::hsl(10.0, 1.0, 0.5)
+            .mix(
+                Color::hsl(350.0, 1.0, 0.5),
+                0.5,
+                ColorInterpolation::Hsl(method),
+            )
+            .to_hsl()
+            .h;
+
+        // Distance from red on the hue circle.
+        180.0 - (hue - 180.0).abs()
+    };
+
+    assert!(hue(HueInterpolation::Shorter) < 1e-3);
+    assert!(hue(HueInterpolation::Longer) > 179.999);
+    assert!(hue(HueInterpolation::Increasing) > 179.999);
+    assert!(hue(HueInterpolation::Decreasing) < 1e-3);
+
+    // White has no hue, so the mix keeps blue's.
+    let oklch =
+        ColorInterpolation::Oklch(HueInterpolation::Shorter);
+    let blue = Color::BLUE.to_oklch();
+    let tint =
+        Color::WHITE.mix(Color::BLUE, 0.5, oklch).to_oklch();
+    assert!((tint.h - blue.h).abs() < 0.1);
+    assert!((tint.c - blue.c * 0.5).abs() < 1e-3);
+
+    // Fading to transparent keeps the color.
+    assert_close(
+        Color::CORAL.mix(Color::TRANSPARENT, 0.5, oklch),
+        Color::CORAL.with_alpha(0.5),
+    );
+}
@@ -0,0 +1,343 @@
+use std::ops;
+
+use super::{Color, PremultipliedColor};
+
+impl Color {
+    #[inline]
+    pub const fn from_array([r, g, b, a]: [f32; 4]) -> Self {
+        Self { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn to_array(self) -> [f32; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Channels clamped and rounded to 8 bits.
+    #[inline]
+    pub fn to_rgba_u8(self) -> [u8; 4] {
+        self.to_array().map(|channel| {
+            (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+        })
+    }
+
+    /// `0xRRGGBBAA`.
+    #[inline]
+    pub const fn from_rgba_u32(value: u32) -> Self {
+        let [r, g, b, a] = value.to_be_bytes();
+        Self::rgba_u8(r, g, b, a)
+    }
+
+    #[inline]
+    pub fn to_rgba_u32(self) -> u32 {
+        u32::from_be_bytes(self.to_rgba_u8())
+    }
+
+    /// `0xAARRGGBB`, as used by most image codecs and Windows.
+    #[inline]
+    pub const fn from_argb_u32(value: u32) -> Self {
+        let [a, r, g, b] = value.to_be_bytes();
+        Self::rgba_u8(r, g, b, a)
+    }
+
+    #[inline]
+    pub fn to_argb_u32(self) -> u32 {
+        let [r, g, b, a] = self.to_rgba_u8();
+        u32::from_be_bytes([a, r, g, b])
+    }
+
+    /// `0xBBGGRRAA`, the order of `B8G8R8A8` formats read as a
+    /// big-endian integer.
+    #[inline]
+    pub const fn from_bgra_u32(value: u32) -> Self {
+        let [b, g, r, a] = value.to_be_bytes();
+        Self::rgba_u8(r, g, b, a)
+    }
+
+    #[inline]
+    pub fn to_bgra_u32(self) -> u32 {
+        let [r, g, b, a] = self.to_rgba_u8();
+        u32::from_be_bytes([b, g, r, a])
+    }
+
+    /// IEEE 754 half floats, for `R16G16B16A16_SFLOAT` buffers.
+    /// Values are rounded to nearest, ties to even.
+    #[inline]
+    pub fn to_f16_bits(self) -> [u16; 4] {
+        self.to_array().map(f32_to_f16)
+    }
+
+    #[inline]
+    pub fn from_f16_bits(bits: [u16; 4]) -> Self {
+        Self::from_array(bits.map(f16_to_f32))
+    }
+}
+
+fn f32_to_f16(value: f32) -> u16 {
+    let bits = value.to_bits();
+    let sign = ((bits >> 16) & 0x8000) as u16;
+    let exponent = ((bits >> 23) & 0xff) as i32;
+    let mantissa = bits & 0x7f_ffff;
+
+    if exponent == 0xff {
+        // Keeps NaN a (quiet) NaN.
+        let nan = if mantissa == 0 { 0 } else { 0x200 };
+        return sign | 0x7c00 | nan;
+    }
+
+    let exponent = exponent - 127 + 15;
+
+    if exponent >= 0x1f {
+        return sign | 0x7c00;
+    }
+
+    // Drops `shift` bits of `mantissa`, rounding to even.
+    let round = |mantissa: u32, shift: u32| {
+        let half = mantissa >> shift;
+        let remainder = mantissa & ((1 << shift) - 1);
+        let halfway = 1 << (shift - 1);
+
+        if remainder > halfway
+            || (remainder == halfway && half & 1 == 1)
+        {
+            half + 1
+        } else {
+            half
+        }
+    };
+
+    if exponent <= 0 {
+        if exponent < -10 {
+            return sign;
+        }
+
+        let subnormal =
+            round(mantissa | 0x80_0000, (14 - exponent) as u32);
+        return sign | subnormal as u16;
+    }
+
+    // A carry out of the mantissa correctly bumps the exponent,
+    // up to infinity.
+    let normal = ((exponent as u32) << 23) | mantissa;
+    sign | round(normal, 13) as u16
+}
+
+fn f16_to_f32(half: u16) -> f32 {
+    let sign = ((half & 0x8000) as u32) << 16;
+    let exponent = ((half >> 10) & 0x1f) as u32;
+    let mantissa = (half & 0x3ff) as u32;
+
+    let bits = match exponent {
+        0 => {
+            // Zero or subnormal: mantissa * 2^-24.
+            let value = mantissa as f32 * 5.960_464_5e-8;
+            return f32::from_bits(sign | value.to_bits());
+        }
+        0x1f => sign | 0x7f80_0000 | (mantissa << 13),
+        _ => {
+            sign | ((exponent + 127 - 15) << 23)
+                | (mantissa << 13)
+        }
+    };
+
+    f32::from_bits(bits)
+}
+
+impl From<[u8; 4]> for Color {
+    #[inline]
+    fn from([r, g, b, a]: [u8; 4]) -> Self {
+        Self::rgba_u8(r, g, b, a)
+    }
+}
+
+impl From<Color> for [u8; 4] {
+    #[inline]
+    fn from(color: Color) -> Self {
+        color.to_rgba_u8()
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    #[inline]
+    fn from(array: [f32; 4]) -> Self {
+        Self::from_array(array)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    #[inline]
+    fn from(color: Color) -> Self {
+        color.to_array()
+    }
+}
+
+impl From<PremultipliedColor> for [f32; 4] {
+    #[inline]
+    fn from(color: PremultipliedColor) -> Self {
+        [color.r, color.g, color.b, color.a]
+    }
+}
+
+/// Component-wise, alpha included. Sums of straight colors are
+/// only meaningful for opaque ones; use [`PremultipliedColor`]
+/// otherwise.
+impl ops::Add for Color {
+    type Output = Color;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            r: self.r + rhs.r,
+            g: self.g + rhs.g,
+            b: self.b + rhs.b,
+            a: self.a + rhs.a,
+        }
+    }
+}
+
+impl ops::Sub for Color {
+    type Output = Color;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            r: self.r - rhs.r,
+            g: self.g - rhs.g,
+            b: self.b - rhs.b,
+            a: self.a - rhs.a,
+        }
+    }
+}
+
+/// Modulates one color by another, as a tint does.
+impl ops::Mul for Color {
+    type Output = Color;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self {
+            r: self.r * rhs.r,
+            g: self.g * rhs.g,
+            b: self.b * rhs.b,
+            a: self.a * rhs.a,
+        }
+    }
+}
+
+impl ops::Mul<f32> for Color {
+    type Output = Color;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Self::Output {
+        Self {
+            r: self.r * rhs,
+            g: self.g * rhs,
+            b: self.b * rhs,
+            a: self.a * rhs,
+        }
+    }
+}
+
+impl ops::Div<f32> for Color {
+    type Output = Color;
+
+    #[inline]
+    fn div(self, rhs: f32) -> Self::Output {
+        Self {
+            r: self.r / rhs,
+            g: self.g / rhs,
+            b: self.b / rhs,
+            a: self.a / rhs,
+        }
+    }
+}
+
+impl ops::AddAssign for Color {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl ops::SubAssign for Color {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl ops::MulAssign<f32> for Color {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32) {
+        *self = *self * rhs;
+    }
+}
+
+#[test]
+fn test_packed_colors() {
+    let color = Color::rgba_u8(0x12, 0x34, 0x56, 0x78);
+
+    assert_eq!(color.to_rgba_u32(), 0x1234_5678);
+    assert_eq!(color.to_argb_u32(), 0x7812_3456);
+    assert_eq!(color.to_bgra_u32(), 0x5634_1278);
+    assert_eq!(Color::from_rgba_u32(0x1234_5678), color);
+    assert_eq!(Color::from_argb_u32(0x7812_3456), color);
+    assert_eq!(Color::from_bgra_u32(0x5634_1278), color);
+
+    assert_eq!(
+        <[u8; 4]>::from(color),
+        [0x12, 0x34, 0x56, 0x78]
+    );
+    assert_eq!(Color::from([0x12u8, 0x34, 0x56, 0x78]), color);
+    assert_eq!(Color::from(color.to_array()), color);
+    assert_eq!(
+        Color::WHITE.with_alpha(1.5).to_rgba_u32(),
+        u32::MAX
+    );
+
+    assert_eq!(Color::WHITE.to_f16_bits(), [0x3c00; 4]);
+    assert_eq!(
+        Color::from_array([0.5, -2.0, 65504.0, 1e6])
+            .to_f16_bits(),
+        [0x3800, 0xc000, 0x7bff, 0x7c00]
+    );
+    // Smallest subnormal, and a tie rounding to even.
+    assert_eq!(f32_to_f16(2f32.powi(-24)), 0x0001);
+    assert_eq!(f32_to_f16(2f32.powi(-26)), 0x0000);
+    assert_eq!(f32_to_f16(1.0 + 2f32.powi(-11)), 0x3c00);
+    assert_eq!(f32_to_f16(1.0 + 3.0 * 2f32.powi(-11)), 0x3c02);
+    assert!(f16_to_f32(f32_to_f16(f32::NAN)).is_nan());
+
+    for half in
+        (0..=u16::MAX).filter(|half| half & 0x7c00 != 0x7c00)
+    {
+        assert_eq!(f32_to_f16(f16_to_f32(half)), half);
+    }
+
+    let color =
+        Color::from_f16_bits([0x3555, 0x0001, 0x8000, 0x3c00]);
+    assert!((color.r - 1.0 / 3.0).abs() < 1e-3);
+    assert_eq!(color.g, 2f32.powi(-24));
+    assert!(color.b == 0.0 && color.b.is_sign_negative());
+
+    let mut sum = Color::RED * 0.5 + Color::BLUE * 0.5;
+    assert_eq!(
+        sum,
+        Color {
+            r: 0.5,
+            g: 0.0,
+            b: 0.5,
+            a: 1.0
+        }
+    );
+    sum -= Color::BLUE * 0.5;
+    assert_eq!(sum / 0.5, Color::RED);
+    assert_eq!(Color::WHITE * Color::TEAL, Color::TEAL);
+
+    let premultiplied =
+        Color::RED.with_alpha(0.5).premultiply();
+    assert_eq!(
+        <[f32; 4]>::from(premultiplied + premultiplied),
+        [1.0, 0.0, 0.0, 1.0]
+    );
+}
@@ -0,0 +1,724 @@
+use super::Color;
+
+/// sRGB with the transfer function removed.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinearRgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+/// Gamma-encoded Display P3, sharing the sRGB transfer
+/// function.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DisplayP3 {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+/// CIE XYZ relative to the D65 white point, white has `y = 1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+/// CIE Lab relative to D50 as in CSS, `l` is in `0..=100`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// Polar form of [`Lab`], `h` is in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Lch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+/// Oklab, `l` is in `0..=1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklab {
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+/// Polar form of [`Oklab`], `h` is in degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklch {
+    pub l: f32,
+    pub c: f32,
+    pub h: f32,
+}
+
+/// `h` is in degrees, `s` and `l` are in `0..=1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsl {
+    pub h: f32,
+    pub s: f32,
+    pub l: f32,
+}
+
+/// `h` is in degrees, `s` and `v` are in `0..=1`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsv {
+    pub h: f32,
+    pub s: f32,
+    pub v: f32,
+}
+
+pub type Hsb = Hsv;
+
+type Mat = [[f32; 3]; 3];
+
+// Matrices from the CSS Color 4 sample code.
+const LINEAR_SRGB_TO_XYZ: Mat = [
+    [0.412_390_8, 0.357_584_33, 0.180_480_8],
+    [0.212_639, 0.715_168_7, 0.072_192_32],
+    [0.019_330_818, 0.119_194_78, 0.950_532_14],
+];
+
+const XYZ_TO_LINEAR_SRGB: Mat = [
+    [3.240_97, -1.537_383_2, -0.498_610_76],
+    [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+    [0.055_630_08, -0.203_976_96, 1.056_971_5],
+];
+
+const LINEAR_P3_TO_XYZ: Mat = [
+    [0.486_570_95, 0.265_667_7, 0.198_217_29],
+    [0.228_974_56, 0.691_738_5, 0.079_286_91],
+    [0.0, 0.045_113_38, 1.043_944_4],
+];
+
+const XYZ_TO_LINEAR_P3: Mat = [
+    [2.493_497, -0.931_383_6, -0.402_710_78],
+    [-0.829_489, 1.762_664_1, 0.023_624_686],
+    [0.035_845_83, -0.076_172_39, 0.956_884_5],
+];
+
+/// Bradford chromatic adaptation.
+const D65_TO_D50: Mat = [
+    [1.047_929_8, 0.022_946_87, -0.050_192_266],
+    [0.029_627_81, 0.990_434_4, -0.017_073_8],
+    [-0.009_243_041, 0.015_055_191, 0.751_874_3],
+];
+
+const D50_TO_D65: Mat = [
+    [0.955_473_4, -0.023_098_455, 0.063_259_244],
+    [-0.028_369_71, 1.009_995_4, 0.021_041_442],
+    [0.012_314_015, -0.020_507_65, 1.330_365_9],
+];
+
+const D50_WHITE: [f32; 3] = [0.964_295_7, 1.0, 0.825_104_6];
+
+const LINEAR_SRGB_TO_LMS: Mat = [
+    [0.412_221_46, 0.536_332_55, 0.051_445_995],
+    [0.211_903_5, 0.680_699_5, 0.107_396_96],
+    [0.088_302_46, 0.281_718_85, 0.629_978_7],
+];
+
+const LMS_TO_OKLAB: Mat = [
+    [0.210_454_26, 0.793_617_8, -0.004_072_047],
+    [1.977_998_5, -2.428_592_2, 0.450_593_7],
+    [0.025_904_037, 0.782_771_77, -0.808_675_77],
+];
+
+const OKLAB_TO_LMS: Mat = [
+    [1.0, 0.396_337_78, 0.215_803_76],
+    [1.0, -0.105_561_346, -0.063_854_17],
+    [1.0, -0.089_484_18, -1.291_485_5],
+];
+
+const LMS_TO_LINEAR_SRGB: Mat = [
+    [4.076_741_7, -3.307_711_6, 0.230_969_94],
+    [-1.268_438, 2.609_757_4, -0.341_319_38],
+    [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+];
+
+#[inline]
+fn transform(matrix: &Mat, [x, y, z]: [f32; 3]) -> [f32; 3] {
+    matrix.map(|row| row[0] * x + row[1] * y + row[2] * z)
+}
+
+/// sRGB transfer function, extended to negative values by
+/// symmetry so out-of-gamut colors survive round trips.
+#[inline]
+pub(crate) fn srgb_to_linear(value: f32) -> f32 {
+    let abs = value.abs();
+
+    if abs <= 0.04045 {
+        value / 12.92
+    } else {
+        ((abs + 0.055) / 1.055).powf(2.4).copysign(value)
+    }
+}
+
+#[inline]
+pub(crate) fn linear_to_srgb(value: f32) -> f32 {
+    let abs = value.abs();
+
+    if abs <= 0.003_130_8 {
+        value * 12.92
+    } else {
+        (1.055 * abs.powf(2.4f32.recip()) - 0.055)
+            .copysign(value)
+    }
+}
+
+/// Polar coordinates with the hue in degrees `0..360`.
+#[inline]
+fn to_polar(a: f32, b: f32) -> (f32, f32) {
+    let hue = b.atan2(a).to_degrees().rem_euclid(360.0);
+    (a.hypot(b), hue)
+}
+
+#[inline]
+fn from_polar(chroma: f32, hue: f32) -> (f32, f32) {
+    let (sin, cos) = hue.to_radians().sin_cos();
+    (chroma * cos, chroma * sin)
+}
+
+/// Hue in degrees and chroma of an RGB triple, shared by HSL and
+/// HSV. Achromatic colors get a hue of zero.
+fn hue_chroma(Color { r, g, b, .. }: Color) -> (f32, f32, f32) {
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    let hue = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        (g - b) / chroma
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+
+    ((hue * 60.0).rem_euclid(360.0), chroma, max)
+}
+
+/// RGB from hue, chroma and the offset added to every channel.
+fn from_hue_chroma(
+    hue: f32,
+    chroma: f32,
+    offset: f32,
+) -> Color {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color {
+        r: r + offset,
+        g: g + offset,
+        b: b + offset,
+        a: 1.0,
+    }
+}
+
+impl Color {
+    #[inline]
+    pub const fn with_alpha(self, a: f32) -> Self {
+        Self { a, ..self }
+    }
+
+    #[inline]
+    pub fn linear_rgb(r: f32, g: f32, b: f32) -> Self {
+        LinearRgb { r, g, b }.into()
+    }
+
+    #[inline]
+    pub fn display_p3(r: f32, g: f32, b: f32) -> Self {
+        DisplayP3 { r, g, b }.into()
+    }
+
+    #[inline]
+    pub fn xyz(x: f32, y: f32, z: f32) -> Self {
+        Xyz { x, y, z }.into()
+    }
+
+    #[inline]
+    pub fn lab(l: f32, a: f32, b: f32) -> Self {
+        Lab { l, a, b }.into()
+    }
+
+    /// `h` is in degrees.
+    #[inline]
+    pub fn lch(l: f32, c: f32, h: f32) -> Self {
+        Lch { l, c, h }.into()
+    }
+
+    #[inline]
+    pub fn oklab(l: f32, a: f32, b: f32) -> Self {
+        Oklab { l, a, b }.into()
+    }
+
+    /// `h` is in degrees.
+    #[inline]
+    pub fn oklch(l: f32, c: f32, h: f32) -> Self {
+        Oklch { l, c, h }.into()
+    }
+
+    /// `h` is in degrees.
+    #[inline]
+    pub fn hsl(h: f32, s: f32, l: f32) -> Self {
+        Hsl { h, s, l }.into()
+    }
+
+    /// `h` is in degrees.
+    #[inline]
+    pub fn hsv(h: f32, s: f32, v: f32) -> Self {
+        Hsv { h, s, v }.into()
+    }
+
+    #[inline]
+    pub fn to_linear_rgb(self) -> LinearRgb {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_display_p3(self) -> DisplayP3 {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_xyz(self) -> Xyz {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_lab(self) -> Lab {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_lch(self) -> Lch {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_oklab(self) -> Oklab {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_oklch(self) -> Oklch {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_hsl(self) -> Hsl {
+        self.into()
+    }
+
+    #[inline]
+    pub fn to_hsv(self) -> Hsv {
+        self.into()
+    }
+}
+
+// Conversions into `Color` produce opaque colors; use
+// `Color::with_alpha` to carry alpha over.
+
+impl From<Color> for LinearRgb {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Self {
+            r: srgb_to_linear(color.r),
+            g: srgb_to_linear(color.g),
+            b: srgb_to_linear(color.b),
+        }
+    }
+}
+
+impl From<LinearRgb> for Color {
+    #[inline]
+    fn from(LinearRgb { r, g, b }: LinearRgb) -> Self {
+        Self {
+            r: linear_to_srgb(r),
+            g: linear_to_srgb(g),
+            b: linear_to_srgb(b),
+            a: 1.0,
+        }
+    }
+}
+
+impl From<LinearRgb> for Xyz {
+    #[inline]
+    fn from(LinearRgb { r, g, b }: LinearRgb) -> Self {
+        let [x, y, z] =
+            transform(&LINEAR_SRGB_TO_XYZ, [r, g, b]);
+        Self { x, y, z }
+    }
+}
+
+impl From<Xyz> for LinearRgb {
+    #[inline]
+    fn from(Xyz { x, y, z }: Xyz) -> Self {
+        let [r, g, b] =
+            transform(&XYZ_TO_LINEAR_SRGB, [x, y, z]);
+        Self { r, g, b }
+    }
+}
+
+impl From<Color> for Xyz {
+    #[inline]
+    fn from(color: Color) -> Self {
+        LinearRgb::from(color).into()
+    }
+}
+
+impl From<Xyz> for Color {
+    #[inline]
+    fn from(xyz: Xyz) -> Self {
+        LinearRgb::from(xyz).into()
+    }
+}
+
+impl From<Xyz> for DisplayP3 {
+    #[inline]
+    fn from(Xyz { x, y, z }: Xyz) -> Self {
+        let [r, g, b] = transform(&XYZ_TO_LINEAR_P3, [x, y, z])
+            .map(linear_to_srgb);
+
+        Self { r, g, b }
+    }
+}
+
+impl From<DisplayP3> for Xyz {
+    #[inline]
+    fn from(DisplayP3 { r, g, b }: DisplayP3) -> Self {
+        let [x, y, z] = transform(
+            &LINEAR_P3_TO_XYZ,
+            [r, g, b].map(srgb_to_linear),
+        );
+
+        Self { x, y, z }
+    }
+}
+
+impl From<Color> for DisplayP3 {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl From<DisplayP3> for Color {
+    #[inline]
+    fn from(p3: DisplayP3) -> Self {
+        Xyz::from(p3).into()
+    }
+}
+
+impl From<Xyz> for Lab {
+    fn from(Xyz { x, y, z }: Xyz) -> Self {
+        const EPSILON: f32 = 216.0 / 24389.0;
+        const KAPPA: f32 = 24389.0 / 27.0;
+
+        let d50 = transform(&D65_TO_D50, [x, y, z]);
+        let [fx, fy, fz] = [0, 1, 2].map(|index| {
+            let value = d50[index] / D50_WHITE[index];
+
+            if value > EPSILON {
+                value.cbrt()
+            } else {
+                (KAPPA * value + 16.0) / 116.0
+            }
+        });
+
+        Self {
+            l: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+}
+
+impl From<Lab> for Xyz {
+    fn from(Lab { l, a, b }: Lab) -> Self {
+        const EPSILON: f32 = 216.0 / 24389.0;
+        const KAPPA: f32 = 24389.0 / 27.0;
+
+        let fy = (l + 16.0) / 116.0;
+        let fx = a / 500.0 + fy;
+        let fz = fy - b / 200.0;
+
+        let cube_or_linear = |f: f32| {
+            let cube = f * f * f;
+
+            if cube > EPSILON {
+                cube
+            } else {
+                (116.0 * f - 16.0) / KAPPA
+            }
+        };
+
+        let y = if l > KAPPA * EPSILON {
+            fy * fy * fy
+        } else {
+            l / KAPPA
+        };
+
+        let d50 = [
+            cube_or_linear(fx) * D50_WHITE[0],
+            y * D50_WHITE[1],
+            cube_or_linear(fz) * D50_WHITE[2],
+        ];
+        let [x, y, z] = transform(&D50_TO_D65, d50);
+
+        Self { x, y, z }
+    }
+}
+
+impl From<Color> for Lab {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Xyz::from(color).into()
+    }
+}
+
+impl From<Lab> for Color {
+    #[inline]
+    fn from(lab: Lab) -> Self {
+        Xyz::from(lab).into()
+    }
+}
+
+impl From<Lab> for Lch {
+    #[inline]
+    fn from(Lab { l, a, b }: Lab) -> Self {
+        let (c, h) = to_polar(a, b);
+        Self { l, c, h }
+    }
+}
+
+impl From<Lch> for Lab {
+    #[inline]
+    fn from(Lch { l, c, h }: Lch) -> Self {
+        let (a, b) = from_polar(c, h);
+        Self { l, a, b }
+    }
+}
+
+impl From<Color> for Lch {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Lab::from(color).into()
+    }
+}
+
+impl From<Lch> for Color {
+    #[inline]
+    fn from(lch: Lch) -> Self {
+        Lab::from(lch).into()
+    }
+}
+
+impl From<LinearRgb> for Oklab {
+    fn from(LinearRgb { r, g, b }: LinearRgb) -> Self {
+        let lms = transform(&LINEAR_SRGB_TO_LMS, [r, g, b]);
+        let [l, a, b] =
+            transform(&LMS_TO_OKLAB, lms.map(f32::cbrt));
+
+        Self { l, a, b }
+    }
+}
+
+impl From<Oklab> for LinearRgb {
+    fn from(Oklab { l, a, b }: Oklab) -> Self {
+        let lms = transform(&OKLAB_TO_LMS, [l, a, b]);
+        let [r, g, b] = transform(
+            &LMS_TO_LINEAR_SRGB,
+            lms.map(|value| value * value * value),
+        );
+
+        Self { r, g, b }
+    }
+}
+
+impl From<Color> for Oklab {
+    #[inline]
+    fn from(color: Color) -> Self {
+        LinearRgb::from(color).into()
+    }
+}
+
+impl From<Oklab> for Color {
+    #[inline]
+    fn from(oklab: Oklab) -> Self {
+        LinearRgb::from(oklab).into()
+    }
+}
+
+impl From<Oklab> for Oklch {
+    #[inline]
+    fn from(Oklab { l, a, b }: Oklab) -> Self {
+        let (c, h) = to_polar(a, b);
+        Self { l, c, h }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    #[inline]
+    fn from(Oklch { l, c, h }: Oklch) -> Self {
+        let (a, b) = from_polar(c, h);
+        Self { l, a, b }
+    }
+}
+
+impl From<Color> for Oklch {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Oklab::from(color).into()
+    }
+}
+
+impl From<Oklch> for Color {
+    #[inline]
+    fn from(oklch: Oklch) -> Self {
+        Oklab::from(oklch).into()
+    }
+}
+
+impl From<Color> for Hsl {
+    fn from(color: Color) -> Self {
+        let (h, chroma, max) = hue_chroma(color);
+        let l = max - chroma * 0.5;
+
+        let s = if l <= 0.0 || l >= 1.0 {
+            0.0
+        } else {
+            (max - l) / l.min(1.0 - l)
+        };
+
+        Self { h, s, l }
+    }
+}
+
+impl From<Hsl> for Color {
+    #[inline]
+    fn from(Hsl { h, s, l }: Hsl) -> Self {
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(h, chroma, l - chroma * 0.5)
+    }
+}
+
+impl From<Color> for Hsv {
+    #[inline]
+    fn from(color: Color) -> Self {
+        let (h, chroma, v) = hue_chroma(color);
+        let s = if v == 0.0 { 0.0 } else { chroma / v };
+
+        Self { h, s, v }
+    }
+}
+
+impl From<Hsv> for Color {
+    #[inline]
+    fn from(Hsv { h, s, v }: Hsv) -> Self {
+        let chroma = v * s;
+        from_hue_chroma(h, chroma, v - chroma)
+    }
+}
+
+#[cfg(test)]
+fn assert_close(a: [f32; 3], b: [f32; 3], tolerance: f32) {
+    let close = a
+        .iter()
+        .zip(b.iter())
+        .all(|(a, b)| (a - b).abs() <= tolerance);
+
+    assert!(close, "{a:?} != {b:?}");
+}
+
+#[test]
+fn test_color_space_reference_values() {
+    let white = Color::WHITE;
+
+    let Oklab { l, a, b } = white.to_oklab();
+    assert_close([l, a, b], [1.0, 0.0, 0.0], 1e-4);
+
+    let Lab { l, a, b } = white.to_lab();
+    assert_close([l, a, b], [100.0, 0.0, 0.0], 1e-2);
+
+    let Xyz { x, y, z } = white.to_xyz();
+    assert_close([x, y, z], [0.950_456, 1.0, 1.089_058], 1e-4);
+
+    // CSS Color 4: lab(54.29 80.8 69.89) is sRGB red.
+    let Lab { l, a, b } = Color::RED.to_lab();
+    assert_close([l, a, b], [54.29, 80.8, 69.89], 0.05);
+
+    let Oklch { l, c, h } = Color::RED.to_oklch();
+    assert_close([l, c, h], [0.627_96, 0.257_68, 29.23], 1e-2);
+
+    let Hsl { h, s, l } = Color::RED.to_hsl();
+    assert_close([h, s, l], [0.0, 1.0, 0.5], 1e-6);
+
+    let Hsv { h, s, v } = Color::TEAL.to_hsv();
+    assert_close([h, s, v], [180.0, 1.0, 128.0 / 255.0], 1e-6);
+
+    let Color { r, g, b, .. } = Color::hsl(240.0, 1.0, 0.25);
+    assert_close([r, g, b], [0.0, 0.0, 0.5], 1e-6);
+}
+
+#[test]
+fn test_color_space_round_trips() {
+    let colors = [
+        Color::BLACK,
+        Color::WHITE,
+        Color::REBECCA_PURPLE,
+        Color::CORNFLOWER_BLUE,
+        Color::GOLD,
+        Color::DARK_SLATE_GRAY,
+        Color::rgba_u8(3, 250, 17, 255),
+        Color::rgba_u8(128, 128, 128, 255),
+    ];
+
+    let channels = |color: Color| [color.r, color.g, color.b];
+
+    for color in colors {
+        let expected = channels(color);
+
+        for round_trip in [
+            Color::from(color.to_linear_rgb()),
+            Color::from(color.to_xyz()),
+            Color::from(color.to_display_p3()),
+            Color::from(color.to_lab()),
+            Color::from(color.to_lch()),
+            Color::from(color.to_oklab()),
+            Color::from(color.to_oklch()),
+            Color::from(color.to_hsl()),
+            Color::from(color.to_hsv()),
+        ] {
+            assert_close(channels(round_trip), expected, 1e-3);
+        }
+    }
+
+    // Out-of-gamut values survive linear round trips.
+    let wide = Color {
+        r: 1.2,
+        g: -0.1,
+        b: 0.5,
+        a: 1.0,
+    };
+    assert_close(
+        channels(Color::from(wide.to_oklab())),
+        channels(wide),
+        1e-3,
+    );
+}
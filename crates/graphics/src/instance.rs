@@ -1,4 +1,4 @@
-use ash::{khr, vk};
+use ash::{ext, khr, vk};
 use winit::raw_window_handle::{
     HasDisplayHandle, HasWindowHandle, RawDisplayHandle,
     RawWindowHandle,
@@ -7,13 +7,18 @@ use winit::raw_window_handle::{
 #[cfg(target_family = "windows")]
 use ash::khr::win32_surface;
 
-use crate::allocation_callbacks::ALLOCATION_CALLBACKS;
+use crate::{
+    SurfacePreference,
+    allocation_callbacks::ALLOCATION_CALLBACKS,
+    surface_format::SurfaceFormat,
+};
 
 pub struct Instance {
     entry: ash::Entry,
     instance: ash::Instance,
     #[cfg(target_family = "windows")]
     win32_instance: win32_surface::Instance,
+    swapchain_colorspace: bool,
 }
 
 const APP_VERSION: u32 = vk::make_api_version(0, 0, 1, 0);
@@ -90,6 +95,63 @@ impl Instance {
             })?
             .as_raw();
 
+        let mut extensions = match display_handle {
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            RawDisplayHandle::Xlib(_) => EXTENSIONS_XLIB,
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            RawDisplayHandle::Xcb(_) => EXTENSIONS_XCB,
+            #[cfg(any(
+                target_os = "linux",
+                target_os = "dragonfly",
+                target_os = "freebsd",
+                target_os = "netbsd",
+                target_os = "openbsd"
+            ))]
+            RawDisplayHandle::Wayland(_) => EXTENSIONS_WAYLAND,
+            #[cfg(target_family = "windows")]
+            RawDisplayHandle::Windows(_) => EXTENSIONS_WIN32,
+            _ => {
+                return logging::ErrorKind::UnsupportedWindow
+                    .into_result();
+            }
+        }
+        .to_vec();
+
+        // Display P3 and extended sRGB swapchains, see
+        // `SurfaceFormat::select`.
+        let swapchain_colorspace = unsafe {
+            entry.enumerate_instance_extension_properties(None)
+        }
+        .map_err(|err| {
+            logging::ErrorKind::VulkanError {
+                function_name: "enumerate_instance_extension_properties",
+                vk_code: err.as_raw(),
+            }
+            .into_error()
+        })?
+        .iter()
+        .any(|properties| {
+            properties.extension_name_as_c_str()
+                == Ok(ext::swapchain_colorspace::NAME)
+        });
+
+        if swapchain_colorspace {
+            extensions
+                .push(ext::swapchain_colorspace::NAME.as_ptr());
+        }
+
         let instance = unsafe {
             entry
                 .create_instance(
@@ -107,45 +169,7 @@ impl Instance {
                                 .engine_name(ENGINE_NAME),
                         )
                         .enabled_layer_names(LAYER_NAMES)
-                        .enabled_extension_names(
-                            match display_handle {
-                                #[cfg(any(
-                                    target_os = "linux",
-                                    target_os = "dragonfly",
-                                    target_os = "freebsd",
-                                    target_os = "netbsd",
-                                    target_os = "openbsd"
-                                ))]
-                                RawDisplayHandle::Xlib(
-                                    _
-                                ) => EXTENSIONS_XLIB,
-                                #[cfg(any(
-                                    target_os = "linux",
-                                    target_os = "dragonfly",
-                                    target_os = "freebsd",
-                                    target_os = "netbsd",
-                                    target_os = "openbsd"
-                                ))]
-                                RawDisplayHandle::Xcb(
-                                    _
-                                ) => EXTENSIONS_XCB,
-                                #[cfg(any(
-                                    target_os = "linux",
-                                    target_os = "dragonfly",
-                                    target_os = "freebsd",
-                                    target_os = "netbsd",
-                                    target_os = "openbsd"
-                                ))]
-                                RawDisplayHandle::Wayland(
-                                    _
-                                ) => EXTENSIONS_WAYLAND,
-                                #[cfg(target_family = "windows")]
-                                RawDisplayHandle::Windows(
-                                    _
-                                ) => EXTENSIONS_WIN32,
-                                _ => return logging::ErrorKind::UnsupportedWindow.into_result()
-                            },
-                        ),
+                        .enabled_extension_names(&extensions),
                     ALLOCATION_CALLBACKS,
                 )
                 .map_err(|err| {
@@ -193,6 +217,7 @@ impl Instance {
                     entry,
                     instance,
                     win32_instance,
+                    swapchain_colorspace,
                 }
             }),
             _ => logging::ErrorKind::UnsupportedWindow
@@ -206,12 +231,11 @@ impl Instance {
         handle: &H,
         width: u32,
         height: u32,
+        preference: SurfacePreference,
     ) -> logging::Result<crate::UiRenderer>
     where
         H: HasDisplayHandle + HasWindowHandle,
     {
-        const PREFERRED_FORMAT: vk::Format =
-            vk::Format::B8G8R8A8_UNORM;
         let surface_khr = self.create_surface_khr(handle)?;
 
         let surface_loader = khr::surface::Instance::new(
@@ -261,8 +285,7 @@ impl Instance {
             device.get_device_queue(queue_family_index, 0)
         };
 
-        let surface_format = unsafe {
-
+        let surface_formats = unsafe {
             surface_loader
                 .get_physical_device_surface_formats(
                     physical_device,
@@ -275,16 +298,13 @@ impl Instance {
                     }
                     .into_error()
                 })?
-            }
-            .into_iter()
-            .find(|format| {
-                format.format == PREFERRED_FORMAT
-            })
+        };
 
-            .ok_or(
-                logging::ErrorKind::UnsupportedSurfaceFormat
-                    .into_error(),
-            )?;
+        let surface_format = SurfaceFormat::select(
+            &surface_formats,
+            preference,
+            self.swapchain_colorspace,
+        )?;
 
         let surface_capabilities = unsafe {
             surface_loader
@@ -486,7 +506,7 @@ impl Instance {
             swapchain_loader,
             surface_khr,
             swapchain_khr,
-            PREFERRED_FORMAT,
+            surface_format,
             device,
             present_queue,
             command_pool,
@@ -1,21 +1,48 @@
 mod allocation_callbacks;
+mod blend_state;
 mod border_radius;
 mod charset;
 mod color;
 mod instance;
 mod mat3;
+mod output_encoding;
+mod paint;
+mod palette;
+mod path;
+mod rect;
 mod size;
+mod spirv;
+mod surface_format;
 mod ui_renderer;
+pub mod uniforms;
 mod uv;
 mod vec2;
 mod vk_object_store;
 
 pub use border_radius::BorderRadius;
 pub use charset::{Charset, CharsetIter};
-pub use color::Color;
+pub use color::{
+    BlendFactor, BlendMode, Color, ColorInterpolation,
+    CssFormat, DisplayP3, Gamut, Hsb, Hsl, Hsv,
+    HueInterpolation, Lab, Lch, LinearRgb, Oklab, Oklch,
+    PremultipliedColor, Xyz,
+};
 pub use instance::Instance;
 pub use mat3::Mat3;
+pub use output_encoding::OutputEncoding;
+pub use paint::{
+    Gradient, GradientShape, GradientStop, Paint, SpreadMode,
+};
+pub use palette::{Palette, Swatch, SwatchGroup};
+pub use path::{
+    Arc, CurveFitter, FillRule, FitOptions, LineCap, LineJoin,
+    NearestPoint, Path, PathElement, PathHit, PathSegment,
+    PathSegments, StrokeStyle, fit_curve, simplify_polyline,
+};
+pub use rect::Rect;
 pub use size::Size;
+pub use surface_format::SurfacePreference;
 pub use ui_renderer::UiRenderer;
+pub use uniforms::PaintUniform;
 pub use uv::Uv;
 pub use vec2::Vec2;
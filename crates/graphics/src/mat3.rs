@@ -15,6 +15,19 @@ impl Mat3 {
         [0.0, 0.0, 1.0],
     ]);
 
+    /// Rows padded to four floats, as a `float3x3` is laid out
+    /// in uniform buffers.
+    #[inline]
+    pub(crate) const fn padded_rows(self) -> [[f32; 4]; 3] {
+        let Self(m) = self;
+
+        [
+            [m[0][0], m[0][1], m[0][2], 0.0],
+            [m[1][0], m[1][1], m[1][2], 0.0],
+            [m[2][0], m[2][1], m[2][2], 1.0],
+        ]
+    }
+
     #[inline]
     pub fn scale_f32(factor: f32) -> Self {
         Self([
@@ -236,12 +249,6 @@ impl std::ops::Mul for Mat3 {
 impl From<Mat3> for [[f32; 4]; 3] {
     #[inline]
     fn from(val: Mat3) -> Self {
-        let Mat3(m) = val;
-
-        [
-            [m[0][0], m[0][1], m[0][2], 0.0],
-            [m[1][0], m[1][1], m[1][2], 0.0],
-            [m[2][0], m[2][1], m[2][2], 1.0],
-        ]
+        val.padded_rows()
     }
 }
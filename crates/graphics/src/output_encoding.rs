@@ -0,0 +1,101 @@
+use crate::{Color, DisplayP3, LinearRgb};
+
+/// How colors have to be encoded for the swapchain. Shaders
+/// receive it as specialization constant 0 (see
+/// `shaders/common/output.slang`). The discriminants match the
+/// `OUTPUT_*` constants there.
+#[repr(u32)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OutputEncoding {
+    /// sRGB primaries and transfer function written by the
+    /// shader into a UNORM target.
+    #[default]
+    Srgb,
+    /// sRGB primaries with linear values. The target is an
+    /// `*_SRGB` format that encodes on store.
+    LinearSrgb,
+    /// Display P3 primaries with the sRGB transfer function.
+    DisplayP3,
+    /// Linear values with sRGB primaries that may go outside
+    /// `0.0..=1.0` (scRGB), for FP16 targets.
+    ExtendedLinearSrgb,
+}
+
+impl OutputEncoding {
+    /// Whether colors outside sRGB survive the encoding.
+    #[inline]
+    pub const fn is_wide_gamut(self) -> bool {
+        matches!(
+            self,
+            Self::DisplayP3 | Self::ExtendedLinearSrgb
+        )
+    }
+
+    /// Encodes a color the way the shaders write it, for clear
+    /// values and other data going straight to the target. The
+    /// result is premultiplied.
+    pub fn encode(self, color: Color) -> [f32; 4] {
+        let [r, g, b] = match self {
+            Self::Srgb => [color.r, color.g, color.b]
+                .map(|channel| channel.clamp(0.0, 1.0)),
+            Self::LinearSrgb => {
+                let LinearRgb { r, g, b } =
+                    color.to_linear_rgb();
+                [r, g, b].map(|channel| channel.clamp(0.0, 1.0))
+            }
+            Self::DisplayP3 => {
+                let DisplayP3 { r, g, b } =
+                    color.to_display_p3();
+                [r, g, b].map(|channel| channel.clamp(0.0, 1.0))
+            }
+            Self::ExtendedLinearSrgb => {
+                let LinearRgb { r, g, b } =
+                    color.to_linear_rgb();
+                [r, g, b]
+            }
+        };
+
+        let a = color.a.clamp(0.0, 1.0);
+
+        [r * a, g * a, b * a, a]
+    }
+}
+
+#[test]
+fn test_output_encoding() {
+    let assert_close = |left: [f32; 4], right: [f32; 4]| {
+        for (left, right) in left.into_iter().zip(right) {
+            assert!(
+                (left - right).abs() < 1e-4,
+                "{left} {right}"
+            );
+        }
+    };
+
+    let gray = Color::rgba_u8(128, 128, 128, 255);
+    let half = gray.r;
+
+    assert_close(
+        OutputEncoding::Srgb.encode(gray.with_alpha(0.5)),
+        [half * 0.5, half * 0.5, half * 0.5, 0.5],
+    );
+    assert_close(
+        OutputEncoding::LinearSrgb.encode(gray),
+        [0.2158605, 0.2158605, 0.2158605, 1.0],
+    );
+
+    // sRGB red sits inside P3 and stays in gamut there.
+    let [r, g, b, _] =
+        OutputEncoding::DisplayP3.encode(Color::RED);
+    assert!((r - 0.9175).abs() < 1e-3);
+    assert!((g - 0.2003).abs() < 1e-3);
+    assert!((b - 0.1386).abs() < 1e-3);
+
+    // Colors outside sRGB go negative in extended sRGB.
+    let vivid = Color::display_p3(0.0, 1.0, 0.0);
+    let [r, ..] =
+        OutputEncoding::ExtendedLinearSrgb.encode(vivid);
+    assert!(r < 0.0);
+    assert!(!OutputEncoding::Srgb.is_wide_gamut());
+    assert!(OutputEncoding::DisplayP3.is_wide_gamut());
+}
@@ -0,0 +1,603 @@
+use std::f32::consts::TAU;
+
+use crate::{
+    Color, ColorInterpolation, HueInterpolation, Vec2,
+    uniforms::PaintUniform,
+};
+
+/// How offsets outside `0..=1` are mapped back onto the stops.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SpreadMode {
+    /// Extends the first and last stop colors.
+    #[default]
+    Pad,
+    Repeat,
+    Reflect,
+}
+
+impl SpreadMode {
+    #[inline]
+    pub fn apply(self, offset: f32) -> f32 {
+        match self {
+            Self::Pad => offset.clamp(0.0, 1.0),
+            Self::Repeat => offset.rem_euclid(1.0),
+            Self::Reflect => {
+                1.0 - (offset.rem_euclid(2.0) - 1.0).abs()
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct GradientStop {
+    pub offset: f32,
+    pub color: Color,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GradientShape {
+    /// Offset 0 at `start` and 1 at `end`, constant along lines
+    /// perpendicular to them.
+    Linear { start: Vec2, end: Vec2 },
+    /// Two-point conical gradient from the focal circle (offset
+    /// 0) to the outer circle (offset 1), as in the HTML canvas.
+    /// A plain radial gradient has its focal point at the
+    /// center and a zero focal radius.
+    Radial {
+        center: Vec2,
+        radius: f32,
+        focal: Vec2,
+        focal_radius: f32,
+    },
+    /// Sweeps clockwise on screen starting at `start_angle`,
+    /// measured in radians from the positive x-axis.
+    Conic { center: Vec2, start_angle: f32 },
+}
+
+impl GradientShape {
+    /// Unspread gradient offset at `point`, `None` where a
+    /// two-point conical gradient is undefined.
+    pub fn offset_at(&self, point: Vec2) -> Option<f32> {
+        match *self {
+            Self::Linear { start, end } => {
+                let direction = end - start;
+                let length_sq = direction.length_sq();
+
+                (length_sq > 0.0).then(|| {
+                    (point - start).dot(direction) / length_sq
+                })
+            }
+            Self::Radial {
+                center,
+                radius,
+                focal,
+                focal_radius,
+            } => two_point_conical(
+                point,
+                focal,
+                focal_radius,
+                center,
+                radius,
+            ),
+            Self::Conic {
+                center,
+                start_angle,
+            } => {
+                let angle =
+                    (point - center).atan2() - start_angle;
+                Some((angle / TAU).rem_euclid(1.0))
+            }
+        }
+    }
+}
+
+/// Largest `t` for which `point` lies on the circle
+/// interpolated between the start and end circles, with a
+/// non-negative radius.
+fn two_point_conical(
+    point: Vec2,
+    start_center: Vec2,
+    start_radius: f32,
+    end_center: Vec2,
+    end_radius: f32,
+) -> Option<f32> {
+    let center_delta = end_center - start_center;
+    let point_delta = point - start_center;
+    let radius_delta = end_radius - start_radius;
+
+    // |p - c(t)| = r(t) expands to a t^2 - 2 b t + c = 0.
+    let a =
+        center_delta.length_sq() - radius_delta * radius_delta;
+    let b = point_delta.dot(center_delta)
+        + start_radius * radius_delta;
+    let c =
+        point_delta.length_sq() - start_radius * start_radius;
+
+    let valid = |t: f32| start_radius + radius_delta * t >= 0.0;
+
+    if a.abs() <= f32::EPSILON {
+        return (b != 0.0)
+            .then(|| c / (2.0 * b))
+            .filter(|&t| valid(t));
+    }
+
+    let discriminant = b * b - a * c;
+
+    if discriminant < 0.0 {
+        return None;
+    }
+
+    let root = discriminant.sqrt();
+    let (t0, t1) = ((b + root) / a, (b - root) / a);
+
+    [t0.max(t1), t0.min(t1)].into_iter().find(|&t| valid(t))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Gradient {
+    pub shape: GradientShape,
+    stops: Vec<GradientStop>,
+    pub spread: SpreadMode,
+    pub interpolation: ColorInterpolation,
+}
+
+impl Gradient {
+    #[inline]
+    pub fn new(shape: GradientShape) -> Self {
+        Self {
+            shape,
+            stops: Vec::new(),
+            spread: SpreadMode::default(),
+            interpolation: ColorInterpolation::default(),
+        }
+    }
+
+    #[inline]
+    pub fn linear(start: Vec2, end: Vec2) -> Self {
+        Self::new(GradientShape::Linear { start, end })
+    }
+
+    #[inline]
+    pub fn radial(center: Vec2, radius: f32) -> Self {
+        Self::focal(center, radius, center, 0.0)
+    }
+
+    #[inline]
+    pub fn focal(
+        center: Vec2,
+        radius: f32,
+        focal: Vec2,
+        focal_radius: f32,
+    ) -> Self {
+        Self::new(GradientShape::Radial {
+            center,
+            radius,
+            focal,
+            focal_radius,
+        })
+    }
+
+    #[inline]
+    pub fn conic(center: Vec2, start_angle: f32) -> Self {
+        Self::new(GradientShape::Conic {
+            center,
+            start_angle,
+        })
+    }
+
+    /// Adds a stop, keeping stops sorted by offset. Stops with
+    /// equal offsets keep their insertion order and produce a
+    /// hard edge. A `NaN` offset is taken as `0.0`.
+    pub fn with_stop(
+        mut self,
+        offset: f32,
+        color: Color,
+    ) -> Self {
+        let offset = if offset.is_nan() { 0.0 } else { offset };
+        let index = self
+            .stops
+            .partition_point(|stop| stop.offset <= offset);
+
+        self.stops
+            .insert(index, GradientStop { offset, color });
+        self
+    }
+
+    #[inline]
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    #[inline]
+    pub fn with_interpolation(
+        mut self,
+        interpolation: ColorInterpolation,
+    ) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    #[inline]
+    pub fn stops(&self) -> &[GradientStop] {
+        &self.stops
+    }
+
+    /// Color at an unspread gradient offset.
+    #[inline]
+    pub fn sample(&self, offset: f32) -> Color {
+        self.interpolate(self.spread.apply(offset))
+    }
+
+    /// Color at `offset`, with a `NaN` offset taken as `0.0`.
+    fn interpolate(&self, offset: f32) -> Color {
+        let (first, last) =
+            match (self.stops.first(), self.stops.last()) {
+                (Some(first), Some(last)) => (first, last),
+                _ => return Color::TRANSPARENT,
+            };
+
+        let offset = if offset.is_nan() { 0.0 } else { offset };
+
+        if offset < first.offset {
+            return first.color;
+        }
+
+        // At least `1`, so that there is a previous stop even
+        // if the comparison fails on the first one.
+        let index = self
+            .stops
+            .partition_point(|stop| stop.offset <= offset)
+            .clamp(1, self.stops.len());
+
+        let Some(next) = self.stops.get(index) else {
+            return last.color;
+        };
+
+        let previous = &self.stops[index - 1];
+        let t = (offset - previous.offset)
+            / (next.offset - previous.offset);
+
+        self.interpolation.interpolate(
+            previous.color,
+            next.color,
+            t,
+        )
+    }
+
+    #[inline]
+    pub fn color_at(&self, point: Vec2) -> Color {
+        match self.shape.offset_at(point) {
+            Some(offset) => self.sample(offset),
+            None => Color::TRANSPARENT,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Paint {
+    Solid(Color),
+    Gradient(Gradient),
+}
+
+impl Paint {
+    /// CPU reference for the fill shaders. `point` is in the
+    /// local space of the filled shape.
+    #[inline]
+    pub fn color_at(&self, point: Vec2) -> Color {
+        match self {
+            Self::Solid(color) => *color,
+            Self::Gradient(gradient) => {
+                gradient.color_at(point)
+            }
+        }
+    }
+
+    #[inline]
+    pub fn uniform(&self) -> PaintUniform {
+        PaintUniform::new(self)
+    }
+}
+
+impl From<Color> for Paint {
+    #[inline]
+    fn from(color: Color) -> Self {
+        Self::Solid(color)
+    }
+}
+
+impl From<Gradient> for Paint {
+    #[inline]
+    fn from(gradient: Gradient) -> Self {
+        Self::Gradient(gradient)
+    }
+}
+
+impl PaintUniform {
+    pub const MAX_STOPS: usize = 16;
+
+    pub const KIND_LINEAR: u32 = 0;
+    pub const KIND_RADIAL: u32 = 1;
+    pub const KIND_CONIC: u32 = 2;
+
+    pub const INTERPOLATION_SRGB: u32 = 0;
+    pub const INTERPOLATION_LINEAR_RGB: u32 = 1;
+    pub const INTERPOLATION_OKLAB: u32 = 2;
+    pub const INTERPOLATION_OKLCH: u32 = 3;
+    pub const INTERPOLATION_HSL: u32 = 4;
+
+    /// Gradients with more than [`PaintUniform::MAX_STOPS`]
+    /// stops are resampled evenly.
+    pub fn new(paint: &Paint) -> Self {
+        let mut uniform = Self {
+            kind: Self::KIND_LINEAR,
+            ..Self::default()
+        };
+
+        let gradient = match paint {
+            Paint::Solid(color) => {
+                uniform.colors[0] = ColorInterpolation::Srgb
+                    .to_components(*color);
+                uniform.stop_count = 1;
+                return uniform;
+            }
+            Paint::Gradient(gradient) => gradient,
+        };
+
+        let (kind, geometry) = match gradient.shape {
+            GradientShape::Linear { start, end } => (
+                Self::KIND_LINEAR,
+                [[start.x, start.y, end.x, end.y], [0.0; 4]],
+            ),
+            GradientShape::Radial {
+                center,
+                radius,
+                focal,
+                focal_radius,
+            } => (
+                Self::KIND_RADIAL,
+                [
+                    [focal.x, focal.y, center.x, center.y],
+                    [focal_radius, radius, 0.0, 0.0],
+                ],
+            ),
+            GradientShape::Conic {
+                center,
+                start_angle,
+            } => (
+                Self::KIND_CONIC,
+                [
+                    [center.x, center.y, start_angle, 0.0],
+                    [0.0; 4],
+                ],
+            ),
+        };
+
+        uniform.kind = kind;
+        uniform.geometry = geometry;
+        uniform.spread = gradient.spread as u32;
+        uniform.interpolation = match gradient.interpolation {
+            ColorInterpolation::Srgb => {
+                Self::INTERPOLATION_SRGB
+            }
+            ColorInterpolation::LinearRgb => {
+                Self::INTERPOLATION_LINEAR_RGB
+            }
+            ColorInterpolation::Oklab => {
+                Self::INTERPOLATION_OKLAB
+            }
+            ColorInterpolation::Oklch(_) => {
+                Self::INTERPOLATION_OKLCH
+            }
+            ColorInterpolation::Hsl(_) => {
+                Self::INTERPOLATION_HSL
+            }
+        };
+
+        let interpolation = gradient.interpolation;
+        let stops = gradient.stops();
+
+        let mut push =
+            |index: usize, offset: f32, color: Color| {
+                uniform.colors[index] =
+                    interpolation.to_components(color);
+                uniform.offsets[index / 4][index % 4] = offset;
+            };
+
+        if stops.len() <= Self::MAX_STOPS {
+            for (index, stop) in stops.iter().enumerate() {
+                push(index, stop.offset, stop.color);
+            }
+        } else {
+            let first = stops[0].offset;
+            let last = stops[stops.len() - 1].offset;
+            let step =
+                (last - first) / (Self::MAX_STOPS - 1) as f32;
+
+            for index in 0..Self::MAX_STOPS {
+                let offset = first + step * index as f32;
+                push(
+                    index,
+                    offset,
+                    gradient.interpolate(offset),
+                );
+            }
+        }
+
+        let stop_count = stops.len().min(Self::MAX_STOPS);
+
+        // Hues are unwrapped so the shader can interpolate them
+        // linearly. Resampled stops already follow the hue path
+        // and are close enough to take the shorter way.
+        let unwrap = if stops.len() > Self::MAX_STOPS {
+            interpolation.with_hue(HueInterpolation::Shorter)
+        } else {
+            interpolation
+        };
+
+        unwrap.unwrap_hues(&mut uniform.colors[..stop_count]);
+
+        uniform.stop_count = stop_count as u32;
+        uniform
+    }
+}
+
+#[test]
+fn test_gradient_spread_and_interpolation() {
+    let gradient =
+        Gradient::linear(Vec2::ZERO, Vec2::new(10.0, 0.0))
+            .with_stop(0.0, Color::BLACK)
+            .with_stop(1.0, Color::WHITE);
+
+    assert_eq!(
+        gradient.color_at(Vec2::new(-5.0, 3.0)),
+        Color::BLACK
+    );
+    assert_eq!(
+        gradient.color_at(Vec2::new(15.0, 0.0)),
+        Color::WHITE
+    );
+    assert!(
+        (gradient.color_at(Vec2::new(5.0, 9.0)).r - 0.5).abs()
+            < 1e-6
+    );
+
+    let repeat =
+        gradient.clone().with_spread(SpreadMode::Repeat);
+    assert!(
+        (repeat.color_at(Vec2::new(12.5, 0.0)).r - 0.25).abs()
+            < 1e-6
+    );
+
+    let reflect =
+        gradient.clone().with_spread(SpreadMode::Reflect);
+    assert!(
+        (reflect.color_at(Vec2::new(12.5, 0.0)).r - 0.75).abs()
+            < 1e-6
+    );
+
+    // Linear light is brighter halfway than gamma-encoded sRGB.
+    let linear = gradient
+        .clone()
+        .with_interpolation(ColorInterpolation::LinearRgb)
+        .sample(0.5);
+    assert!((linear.r - 0.735_357).abs() < 1e-4);
+
+    let oklab = gradient
+        .with_interpolation(ColorInterpolation::Oklab)
+        .sample(0.5);
+    assert!(
+        (oklab.r - Color::oklab(0.5, 0.0, 0.0).r).abs() < 1e-4
+    );
+
+    // Premultiplied: fading to transparent keeps the hue.
+    let fade =
+        Gradient::linear(Vec2::ZERO, Vec2::new(1.0, 0.0))
+            .with_stop(0.0, Color::RED)
+            .with_stop(1.0, Color::TRANSPARENT)
+            .sample(0.5);
+    assert_eq!(fade, Color::RED.with_alpha(0.5));
+
+    // Equal offsets produce a hard edge.
+    let hard =
+        Gradient::linear(Vec2::ZERO, Vec2::new(1.0, 0.0))
+            .with_stop(0.5, Color::RED)
+            .with_stop(0.5, Color::BLUE);
+    assert_eq!(hard.sample(0.49), Color::RED);
+    assert_eq!(hard.sample(0.51), Color::BLUE);
+
+    // `NaN` offsets sample and sort as `0.0`.
+    assert_eq!(hard.sample(f32::NAN), Color::RED);
+    let nan = Gradient::linear(Vec2::ZERO, Vec2::new(1.0, 0.0))
+        .with_stop(1.0, Color::WHITE)
+        .with_stop(f32::NAN, Color::BLACK);
+    assert_eq!(nan.stops()[0].offset, 0.0);
+    assert_eq!(nan.interpolate(f32::NAN), Color::BLACK);
+}
+
+#[test]
+fn test_gradient_shapes() {
+    let radial = GradientShape::Radial {
+        center: Vec2::ZERO,
+        radius: 10.0,
+        focal: Vec2::ZERO,
+        focal_radius: 0.0,
+    };
+    let offset = radial.offset_at(Vec2::new(3.0, 4.0)).unwrap();
+    assert!((offset - 0.5).abs() < 1e-6);
+
+    // Focal point shifted towards +x: distances are measured
+    // from the focal point to the outer circle.
+    let focal = GradientShape::Radial {
+        center: Vec2::ZERO,
+        radius: 10.0,
+        focal: Vec2::new(5.0, 0.0),
+        focal_radius: 0.0,
+    };
+    assert!(
+        focal.offset_at(Vec2::new(5.0, 0.0)).unwrap().abs()
+            < 1e-6
+    );
+    let right = focal.offset_at(Vec2::new(7.5, 0.0)).unwrap();
+    let left = focal.offset_at(Vec2::new(-2.5, 0.0)).unwrap();
+    assert!((right - 0.5).abs() < 1e-5);
+    assert!((left - 0.5).abs() < 1e-5);
+
+    // Focal circle outside the end circle leaves a cone where
+    // the gradient is undefined.
+    let cone = GradientShape::Radial {
+        center: Vec2::ZERO,
+        radius: 1.0,
+        focal: Vec2::new(10.0, 0.0),
+        focal_radius: 0.0,
+    };
+    assert!(cone.offset_at(Vec2::new(10.0, 5.0)).is_none());
+
+    let conic = GradientShape::Conic {
+        center: Vec2::ZERO,
+        start_angle: 0.0,
+    };
+    let quarter = conic.offset_at(Vec2::new(0.0, 1.0)).unwrap();
+    assert!((quarter - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn test_paint_uniform_layout() {
+    // std140 layout of the Paint block.
+    assert_eq!(std::mem::size_of::<PaintUniform>(), 368);
+
+    let stops = (0..40).fold(
+        Gradient::linear(Vec2::ZERO, Vec2::new(1.0, 0.0)),
+        |gradient, index| {
+            let value = index as f32 / 39.0;
+            gradient
+                .with_stop(value, Color::hsv(0.0, 0.0, value))
+        },
+    );
+
+    let uniform = Paint::from(stops).uniform();
+    assert_eq!(uniform.stop_count, 16);
+    assert_eq!(uniform.offsets[3][3], 1.0);
+    assert!((uniform.colors[15][0] - 1.0).abs() < 1e-6);
+
+    // The longer way from red to blue passes through green;
+    // the unwrapped hues say so.
+    let longer =
+        Gradient::linear(Vec2::ZERO, Vec2::new(1.0, 0.0))
+            .with_stop(0.0, Color::RED)
+            .with_stop(1.0, Color::BLUE)
+            .with_interpolation(ColorInterpolation::Oklch(
+                HueInterpolation::Longer,
+            ));
+    let uniform = Paint::from(longer.clone()).uniform();
+    let red = Color::RED.to_oklch().h;
+
+    assert_eq!(uniform.interpolation, 3);
+    assert!(uniform.colors[1][2] - red > 180.0);
+    assert!(longer.sample(0.5).g > longer.sample(0.5).r);
+
+    let solid =
+        Paint::Solid(Color::RED.with_alpha(0.5)).uniform();
+    assert_eq!(solid.stop_count, 1);
+    assert_eq!(solid.colors[0], [0.5, 0.0, 0.0, 0.5]);
+}
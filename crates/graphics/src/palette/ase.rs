@@ -0,0 +1,349 @@
+use super::{Palette, Swatch, SwatchGroup, invalid};
+use crate::Color;
+
+const SIGNATURE: &[u8; 4] = b"ASEF";
+
+const GROUP_START: u16 = 0xc001;
+const GROUP_END: u16 = 0xc002;
+const COLOR_ENTRY: u16 = 0x0001;
+
+/// Color type written for every swatch.
+const NORMAL: u16 = 2;
+
+impl Palette {
+    /// Reads an Adobe swatch exchange file. RGB, gray, CMYK and
+    /// Lab entries are supported; CMYK is converted naively since
+    /// no color profile is known. The format has no palette name,
+    /// so [`Palette::name`] stays empty.
+    pub fn from_ase(bytes: &[u8]) -> logging::Result<Self> {
+        let mut reader = Reader(bytes);
+
+        if reader.take(4)? != SIGNATURE {
+            return invalid("missing `ASEF` signature");
+        }
+
+        let major = reader.u16()?;
+        let _minor = reader.u16()?;
+
+        if major != 1 {
+            return invalid(format!(
+                "unsupported ASE version {major}"
+            ));
+        }
+
+        let block_count = reader.u32()?;
+        let mut palette = Palette::default();
+        let mut in_group = false;
+
+        for _ in 0..block_count {
+            let kind = reader.u16()?;
+            let length = reader.u32()? as usize;
+            let mut block = Reader(reader.take(length)?);
+
+            match kind {
+                GROUP_START => {
+                    palette
+                        .groups
+                        .push(SwatchGroup::new(block.name()?));
+                    in_group = true;
+                }
+                GROUP_END => in_group = false,
+                COLOR_ENTRY => {
+                    let swatch = block.swatch()?;
+
+                    if in_group {
+                        palette.current_swatches().push(swatch);
+                    } else {
+                        palette.swatches.push(swatch);
+                    }
+                }
+                // Unknown blocks are skipped by their length.
+                _ => {}
+            }
+        }
+
+        Ok(palette)
+    }
+
+    /// Writes an Adobe swatch exchange file with RGB entries.
+    /// Alpha, which the format lacks, is appended to entries that
+    /// aren't opaque; readers skip it as the block length covers
+    /// it. Names take at most 65535 UTF-16 units, including their
+    /// terminator.
+    pub fn to_ase(&self) -> logging::Result<Vec<u8>> {
+        let mut writer = Writer::default();
+        let block_count = self.swatches.len()
+            + self
+                .groups
+                .iter()
+                .map(|group| group.swatches.len() + 2)
+                .sum::<usize>();
+
+        writer.bytes.extend_from_slice(SIGNATURE);
+        writer.u16(1);
+        writer.u16(0);
+        writer.u32(block_count as u32);
+
+        for swatch in &self.swatches {
+            writer.swatch(swatch)?;
+        }
+
+        for group in &self.groups {
+            writer.block(GROUP_START, |writer| {
+                writer.name(&group.name)
+            })?;
+
+            for swatch in &group.swatches {
+                writer.swatch(swatch)?;
+            }
+
+            writer.block(GROUP_END, |_| Ok(()))?;
+        }
+
+        Ok(writer.bytes)
+    }
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    #[inline]
+    fn take(
+        &mut self,
+        count: usize,
+    ) -> logging::Result<&'a [u8]> {
+        if self.0.len() < count {
+            return invalid("unexpected end of ASE data");
+        }
+
+        let (head, tail) = self.0.split_at(count);
+        self.0 = tail;
+
+        Ok(head)
+    }
+
+    #[inline]
+    fn u16(&mut self) -> logging::Result<u16> {
+        let bytes = self.take(2)?;
+        Ok(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    #[inline]
+    fn u32(&mut self) -> logging::Result<u32> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([
+            bytes[0], bytes[1], bytes[2], bytes[3],
+        ]))
+    }
+
+    #[inline]
+    fn f32(&mut self) -> logging::Result<f32> {
+        self.u32().map(f32::from_bits)
+    }
+
+    /// Length-prefixed, null-terminated UTF-16BE.
+    fn name(&mut self) -> logging::Result<String> {
+        let length = self.u16()? as usize;
+        let mut units = Vec::with_capacity(length);
+
+        for _ in 0..length {
+            units.push(self.u16()?);
+        }
+
+        if units.last() == Some(&0) {
+            units.pop();
+        }
+
+        String::from_utf16(&units).or_else(|_| {
+            invalid("swatch name is not valid UTF-16")
+        })
+    }
+
+    fn swatch(&mut self) -> logging::Result<Swatch> {
+        let name = self.name()?;
+        let model = self.take(4)?;
+
+        let mut color = match model {
+            b"RGB " => Color {
+                r: self.f32()?,
+                g: self.f32()?,
+                b: self.f32()?,
+                a: 1.0,
+            },
+            b"Gray" => {
+                let value = self.f32()?;
+                Color {
+                    r: value,
+                    g: value,
+                    b: value,
+                    a: 1.0,
+                }
+            }
+            b"CMYK" => {
+                let [c, m, y, k] = [
+                    self.f32()?,
+                    self.f32()?,
+                    self.f32()?,
+                    self.f32()?,
+                ];
+                Color {
+                    r: (1.0 - c) * (1.0 - k),
+                    g: (1.0 - m) * (1.0 - k),
+                    b: (1.0 - y) * (1.0 - k),
+                    a: 1.0,
+                }
+            }
+            b"LAB " => {
+                let l = self.f32()? * 100.0;
+                Color::lab(l, self.f32()?, self.f32()?)
+            }
+            model => {
+                return invalid(format!(
+                    "unsupported color model `{}`",
+                    String::from_utf8_lossy(model)
+                ));
+            }
+        };
+
+        let _kind = self.u16()?;
+
+        if self.0.len() >= 4 {
+            color.a = self.f32()?;
+        }
+
+        Ok(Swatch { name, color })
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    bytes: Vec<u8>,
+}
+
+impl Writer {
+    #[inline]
+    fn u16(&mut self, value: u16) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    #[inline]
+    fn u32(&mut self, value: u32) {
+        self.bytes.extend_from_slice(&value.to_be_bytes());
+    }
+
+    #[inline]
+    fn f32(&mut self, value: f32) {
+        self.u32(value.to_bits());
+    }
+
+    fn name(&mut self, name: &str) -> logging::Result<()> {
+        let units = name
+            .encode_utf16()
+            .chain(Some(0))
+            .collect::<Vec<_>>();
+
+        let Ok(length) = u16::try_from(units.len()) else {
+            return invalid(format!(
+                "name of {} UTF-16 units is too long for ASE",
+                units.len() - 1
+            ));
+        };
+
+        self.u16(length);
+
+        for unit in units {
+            self.u16(unit);
+        }
+
+        Ok(())
+    }
+
+    /// Writes a block, patching its length afterwards.
+    fn block(
+        &mut self,
+        kind: u16,
+        body: impl FnOnce(&mut Self) -> logging::Result<()>,
+    ) -> logging::Result<()> {
+        self.u16(kind);
+        self.u32(0);
+
+        let start = self.bytes.len();
+        body(self)?;
+
+        let length =
+            ((self.bytes.len() - start) as u32).to_be_bytes();
+        self.bytes[start - 4..start].copy_from_slice(&length);
+
+        Ok(())
+    }
+
+    fn swatch(
+        &mut self,
+        Swatch { name, color }: &Swatch,
+    ) -> logging::Result<()> {
+        self.block(COLOR_ENTRY, |writer| {
+            writer.name(name)?;
+            writer.bytes.extend_from_slice(b"RGB ");
+            writer.f32(color.r);
+            writer.f32(color.g);
+            writer.f32(color.b);
+            writer.u16(NORMAL);
+
+            if color.a < 1.0 {
+                writer.f32(color.a);
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[test]
+fn test_ase_round_trip() {
+    let palette = super::sample_palette();
+    let bytes = palette.to_ase().unwrap();
+
+    assert_eq!(&bytes[..4], b"ASEF");
+    // Four swatches plus start and end of two groups.
+    assert_eq!(&bytes[8..12], &8u32.to_be_bytes());
+
+    let read = Palette::from_ase(&bytes).unwrap();
+
+    assert!(read.name.is_empty());
+    assert_eq!(
+        read,
+        Palette {
+            name: String::new(),
+            ..palette
+        }
+    );
+
+    // Adobe's own entries: a gray swatch outside of any group.
+    let mut gray = bytes[..8].to_vec();
+    gray.extend_from_slice(&1u32.to_be_bytes());
+    gray.extend_from_slice(&[0x00, 0x01, 0, 0, 0, 16]);
+    gray.extend_from_slice(&[0, 2, 0, b'G', 0, 0]);
+    gray.extend_from_slice(b"Gray");
+    gray.extend_from_slice(&0.5f32.to_be_bytes());
+    gray.extend_from_slice(&0u16.to_be_bytes());
+
+    let gray = Palette::from_ase(&gray).unwrap();
+
+    assert_eq!(gray.swatches[0].name, "G");
+    assert_eq!(gray.swatches[0].color.g, 0.5);
+    assert_eq!(gray.swatches[0].color.a, 1.0);
+
+    assert!(Palette::from_ase(b"ASEF\0\x01").is_err());
+    assert!(
+        Palette::from_ase(&bytes[..bytes.len() - 3]).is_err()
+    );
+
+    // The longest name fits its length with the terminator.
+    let named = |length| {
+        Palette::new("")
+            .with_swatch("x".repeat(length), Color::BLACK)
+            .to_ase()
+    };
+    assert!(named(u16::MAX as usize - 1).is_ok());
+    assert!(named(u16::MAX as usize).is_err());
+}
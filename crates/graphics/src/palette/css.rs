@@ -0,0 +1,259 @@
+use std::fmt::Write;
+
+use super::{Palette, Swatch, SwatchGroup};
+use crate::{Color, CssFormat};
+
+const PALETTE: &str = "Palette:";
+const GROUP: &str = "Group:";
+
+impl Palette {
+    /// Reads `--name: <color>;` custom properties from a style
+    /// sheet. `/* Palette: */` and `/* Group: */` comments name
+    /// the palette and start groups. Properties whose values
+    /// aren't colors are skipped.
+    pub fn from_css(source: &str) -> Self {
+        let mut palette = Palette::default();
+        let mut rest = source;
+
+        while let Some(index) = rest.find(['/', '-']) {
+            rest = &rest[index..];
+
+            if let Some(comment) = rest.strip_prefix("/*") {
+                let (text, tail) = comment
+                    .split_once("*/")
+                    .unwrap_or((comment, ""));
+                let text = text.trim();
+
+                if let Some(name) = text.strip_prefix(PALETTE) {
+                    palette.name = name.trim().to_owned();
+                } else if let Some(name) =
+                    text.strip_prefix(GROUP)
+                {
+                    palette
+                        .groups
+                        .push(SwatchGroup::new(name.trim()));
+                }
+
+                rest = tail;
+            } else if let Some(property) =
+                rest.strip_prefix("--")
+            {
+                let (name, tail) = unescape_ident(property);
+                rest = tail;
+
+                let Some(value) =
+                    rest.trim_start().strip_prefix(':')
+                else {
+                    continue;
+                };
+
+                let end = value
+                    .find([';', '}'])
+                    .unwrap_or(value.len());
+                rest = &value[end..];
+
+                if let Ok(color) =
+                    value[..end].trim().parse::<Color>()
+                {
+                    palette
+                        .current_swatches()
+                        .push(Swatch::new(name, color));
+                }
+            } else {
+                rest = &rest[1..];
+            }
+        }
+
+        palette
+    }
+
+    /// Writes the swatches as custom properties on `:root`.
+    /// Colors that fit 8 bits per channel are written as hex,
+    /// others as `color(srgb ...)`.
+    pub fn to_css(&self) -> String {
+        let mut output = String::new();
+
+        if !self.name.is_empty() {
+            let _ = writeln!(
+                output,
+                "/* {PALETTE} {} */",
+                comment_text(&self.name)
+            );
+        }
+
+        output.push_str(":root {\n");
+
+        let write_swatches =
+            |output: &mut String, swatches: &[Swatch]| {
+                for Swatch { name, color } in swatches {
+                    let _ = writeln!(
+                        output,
+                        "  --{}: {};",
+                        escape_ident(name),
+                        css_value(*color)
+                    );
+                }
+            };
+
+        write_swatches(&mut output, &self.swatches);
+
+        for group in &self.groups {
+            if !output.ends_with("{\n") {
+                output.push('\n');
+            }
+
+            let _ = writeln!(
+                output,
+                "  /* {GROUP} {} */",
+                comment_text(&group.name)
+            );
+            write_swatches(&mut output, &group.swatches);
+        }
+
+        output.push_str("}\n");
+        output
+    }
+}
+
+fn css_value(color: Color) -> String {
+    let [r, g, b, a] = [color.r, color.g, color.b, color.a]
+        .map(|value| {
+            (value.clamp(0.0, 1.0) * 255.0).round() as u8
+        });
+
+    if Color::rgba_u8(r, g, b, a) == color {
+        color.to_css_string(CssFormat::Hex)
+    } else {
+        color.to_css_string(CssFormat::Srgb)
+    }
+}
+
+#[inline]
+fn comment_text(text: &str) -> String {
+    text.replace("*/", "* /")
+}
+
+/// Escapes `name` so every character survives as part of a CSS
+/// identifier.
+fn escape_ident(name: &str) -> String {
+    let mut output = String::with_capacity(name.len());
+
+    for char in name.chars() {
+        match char {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => {
+                output.push(char)
+            }
+            char if !char.is_ascii() => output.push(char),
+            char if char.is_ascii_graphic() || char == ' ' => {
+                output.push('\\');
+                output.push(char);
+            }
+            // Control characters need the hex form.
+            char => {
+                let _ = write!(output, "\\{:x} ", char as u32);
+            }
+        }
+    }
+
+    output
+}
+
+/// Reads an identifier, resolving escapes. Returns the name and
+/// the rest of `source`.
+fn unescape_ident(source: &str) -> (String, &str) {
+    let mut name = String::new();
+    let mut chars = source.char_indices().peekable();
+
+    while let Some(&(index, char)) = chars.peek() {
+        match char {
+            '\\' => {
+                chars.next();
+
+                let mut code = String::new();
+
+                while let Some(&(_, digit)) = chars.peek() {
+                    if code.len() == 6
+                        || !digit.is_ascii_hexdigit()
+                    {
+                        break;
+                    }
+
+                    code.push(digit);
+                    chars.next();
+                }
+
+                if code.is_empty() {
+                    if let Some((_, char)) = chars.next() {
+                        name.push(char);
+                    }
+                } else {
+                    // One whitespace ends a hex escape.
+                    chars.next_if(|(_, char)| {
+                        char.is_whitespace()
+                    });
+
+                    name.extend(
+                        u32::from_str_radix(&code, 16)
+                            .ok()
+                            .and_then(char::from_u32),
+                    );
+                }
+            }
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '-' | '_' => {
+                name.push(char);
+                chars.next();
+            }
+            char if !char.is_ascii() => {
+                name.push(char);
+                chars.next();
+            }
+            _ => return (name, &source[index..]),
+        }
+    }
+
+    (name, "")
+}
+
+#[test]
+fn test_css_round_trip() {
+    let palette = super::sample_palette();
+    let source = palette.to_css();
+
+    assert!(
+        source.starts_with("/* Palette: Brand */\n:root {\n")
+    );
+    assert!(source.contains("  --Ink: #111827;\n"));
+    assert!(source.contains("  --Scrim: #00000080;\n"));
+    assert!(source.contains("  --Sky\\ Blue: #38bdf8;\n"));
+    assert!(source.contains("  --Rosé\\ 50\\%: #f43f5e7f;\n"));
+    assert_eq!(Palette::from_css(&source), palette);
+
+    let precise = Palette::new("")
+        .with_swatch(
+            "tab\there",
+            Color::display_p3(0.2, 0.4, 0.6),
+        )
+        .with_swatch("7", Color::WHITE.with_alpha(0.25));
+    let source = precise.to_css();
+
+    assert!(source.contains("--tab\\9 here: color(srgb "));
+
+    let read = Palette::from_css(&source);
+
+    assert_eq!(read.swatches[0].name, "tab\there");
+    assert_eq!(read.swatches[1], precise.swatches[1]);
+    assert!(
+        read.swatches[0]
+            .color
+            .delta_e_ok(precise.swatches[0].color)
+            < 1e-3
+    );
+
+    // Other declarations are left alone.
+    let sheet = ".button { --gap: 4px; --accent: rebeccapurple; \
+        color: var(--accent); }";
+    let read = Palette::from_css(sheet);
+
+    assert_eq!(read.swatches.len(), 1);
+    assert_eq!(read.get("accent"), Some(Color::REBECCA_PURPLE));
+}
@@ -0,0 +1,185 @@
+use std::fmt::Write;
+
+use super::{Palette, Swatch, SwatchGroup, invalid};
+use crate::Color;
+
+const HEADER: &str = "GIMP Palette";
+const GROUP: &str = "Group:";
+
+impl Palette {
+    /// Reads a GIMP palette. `Channels: RGBA` files (as written
+    /// by Aseprite) carry alpha, and `# Group:` comments start a
+    /// group.
+    pub fn from_gpl(source: &str) -> logging::Result<Self> {
+        let mut lines = source.lines().map(str::trim);
+
+        if lines.next() != Some(HEADER) {
+            return invalid("missing `GIMP Palette` header");
+        }
+
+        let mut palette = Palette::default();
+        let mut channels = 3;
+
+        for line in lines {
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(comment) = line.strip_prefix('#') {
+                if let Some(name) =
+                    comment.trim_start().strip_prefix(GROUP)
+                {
+                    palette
+                        .groups
+                        .push(SwatchGroup::new(name.trim()));
+                }
+
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix("Name:") {
+                palette.name = name.trim().to_owned();
+                continue;
+            }
+
+            if line.starts_with("Columns:") {
+                continue;
+            }
+
+            if let Some(value) = line.strip_prefix("Channels:")
+            {
+                channels = match value.trim() {
+                    "RGB" => 3,
+                    "RGBA" => 4,
+                    value => {
+                        return invalid(format!(
+                            "unsupported channels `{value}`"
+                        ));
+                    }
+                };
+                continue;
+            }
+
+            let mut components = [255u8; 4];
+            let mut rest = line;
+
+            for component in &mut components[..channels] {
+                let (value, tail) = rest
+                    .split_once(char::is_whitespace)
+                    .unwrap_or((rest, ""));
+
+                *component = match value.parse() {
+                    Ok(value) => value,
+                    Err(_) => {
+                        return invalid(format!(
+                            "invalid color line `{line}`"
+                        ));
+                    }
+                };
+                rest = tail.trim_start();
+            }
+
+            let [r, g, b, a] = components;
+
+            palette.current_swatches().push(Swatch::new(
+                rest.trim_end(),
+                Color::rgba_u8(r, g, b, a),
+            ));
+        }
+
+        Ok(palette)
+    }
+
+    /// Writes a GIMP palette. Channels are 8-bit; alpha is only
+    /// written (with `Channels: RGBA`) when some swatch needs it.
+    pub fn to_gpl(&self) -> String {
+        let has_alpha = self
+            .iter()
+            .any(|swatch| to_u8(swatch.color.a) < 255);
+
+        let mut output = format!("{HEADER}\n");
+
+        if !self.name.is_empty() {
+            let _ = writeln!(
+                output,
+                "Name: {}",
+                single_line(&self.name)
+            );
+        }
+
+        if has_alpha {
+            output.push_str("Channels: RGBA\n");
+        }
+
+        output.push_str("#\n");
+
+        let write_swatches =
+            |output: &mut String, swatches: &[Swatch]| {
+                for Swatch { name, color } in swatches {
+                    let [r, g, b, a] =
+                        [color.r, color.g, color.b, color.a]
+                            .map(to_u8);
+
+                    let _ = write!(output, "{r:3} {g:3} {b:3}");
+
+                    if has_alpha {
+                        let _ = write!(output, " {a:3}");
+                    }
+
+                    let _ = writeln!(
+                        output,
+                        "\t{}",
+                        single_line(name)
+                    );
+                }
+            };
+
+        write_swatches(&mut output, &self.swatches);
+
+        for group in &self.groups {
+            let _ = writeln!(
+                output,
+                "# {GROUP} {}",
+                single_line(&group.name)
+            );
+            write_swatches(&mut output, &group.swatches);
+        }
+
+        output
+    }
+}
+
+#[inline]
+fn to_u8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+#[inline]
+fn single_line(text: &str) -> String {
+    text.replace(['\r', '\n'], " ")
+}
+
+#[test]
+fn test_gpl_round_trip() {
+    let palette = super::sample_palette();
+    let source = palette.to_gpl();
+
+    assert!(source.starts_with("GIMP Palette\nName: Brand\n"));
+    assert!(source.contains("\n 17  24  39 255\tInk\n"));
+    assert!(source.contains("\n# Group: Accent colors\n"));
+    assert_eq!(Palette::from_gpl(&source).unwrap(), palette);
+
+    let gimp = "GIMP Palette\nName: Plain\nColumns: 4\n#\n\
+        255 0 0\tRed\n  0 128 255 Deep   Sky\n";
+    let plain = Palette::from_gpl(gimp).unwrap();
+
+    assert_eq!(plain.name, "Plain");
+    assert_eq!(plain.get("Red"), Some(Color::RED));
+    assert_eq!(plain.swatches[1].name, "Deep   Sky");
+    assert!(!plain.to_gpl().contains("Channels"));
+
+    assert!(Palette::from_gpl("Name: Missing header").is_err());
+    assert!(
+        Palette::from_gpl("GIMP Palette\n1 2\tBad").is_err()
+    );
+}
@@ -0,0 +1,139 @@
+mod ase;
+mod css;
+mod gpl;
+
+use crate::Color;
+
+/// Named color.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Swatch {
+    pub name: String,
+    pub color: Color,
+}
+
+impl Swatch {
+    #[inline]
+    pub fn new(name: impl Into<String>, color: Color) -> Self {
+        Self {
+            name: name.into(),
+            color,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SwatchGroup {
+    pub name: String,
+    pub swatches: Vec<Swatch>,
+}
+
+impl SwatchGroup {
+    #[inline]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            swatches: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_swatch(
+        mut self,
+        name: impl Into<String>,
+        color: Color,
+    ) -> Self {
+        self.swatches.push(Swatch::new(name, color));
+        self
+    }
+}
+
+/// Swatches shared with design tools. Reads and writes GIMP
+/// (`.gpl`), Adobe swatch exchange (`.ase`) and CSS custom
+/// property files; names, groups and alpha survive a round trip
+/// through each of them.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Palette {
+    pub name: String,
+    /// Swatches outside of any group.
+    pub swatches: Vec<Swatch>,
+    pub groups: Vec<SwatchGroup>,
+}
+
+impl Palette {
+    #[inline]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            ..Default::default()
+        }
+    }
+
+    #[inline]
+    pub fn with_swatch(
+        mut self,
+        name: impl Into<String>,
+        color: Color,
+    ) -> Self {
+        self.swatches.push(Swatch::new(name, color));
+        self
+    }
+
+    #[inline]
+    pub fn with_group(mut self, group: SwatchGroup) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    /// All swatches, ungrouped ones first.
+    pub fn iter(&self) -> impl Iterator<Item = &Swatch> {
+        self.swatches.iter().chain(
+            self.groups
+                .iter()
+                .flat_map(|group| group.swatches.iter()),
+        )
+    }
+
+    /// First swatch called `name`, in any group.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.iter()
+            .find(|swatch| swatch.name == name)
+            .map(|swatch| swatch.color)
+    }
+
+    /// Where readers add swatches: the last group if there is
+    /// one.
+    #[inline]
+    fn current_swatches(&mut self) -> &mut Vec<Swatch> {
+        match self.groups.last_mut() {
+            Some(group) => &mut group.swatches,
+            None => &mut self.swatches,
+        }
+    }
+}
+
+#[inline]
+fn invalid<T>(
+    message: impl Into<String>,
+) -> logging::Result<T> {
+    logging::ErrorKind::InvalidPalette(message.into())
+        .into_result()
+}
+
+#[cfg(test)]
+fn sample_palette() -> Palette {
+    Palette::new("Brand")
+        .with_swatch("Ink", Color::rgba_u8(17, 24, 39, 255))
+        .with_swatch("Scrim", Color::rgba_u8(0, 0, 0, 128))
+        .with_group(
+            SwatchGroup::new("Accent colors")
+                .with_swatch(
+                    "Sky Blue",
+                    Color::rgba_u8(56, 189, 248, 255),
+                )
+                .with_swatch(
+                    "Rosé 50%",
+                    Color::rgba_u8(244, 63, 94, 127),
+                ),
+        )
+        .with_group(SwatchGroup::new("Empty"))
+}
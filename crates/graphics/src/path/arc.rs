@@ -0,0 +1,388 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::Vec2;
+
+use super::Path;
+
+/// Elliptical arc in center parameterization.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Arc {
+    pub center: Vec2,
+    pub radii: Vec2,
+    /// Rotation of the ellipse's x-axis in radians.
+    pub x_axis_rotation: f32,
+    pub start_angle: f32,
+    pub sweep_angle: f32,
+}
+
+impl Arc {
+    /// Converts SVG endpoint parameterization to center
+    /// parameterization, following the SVG 1.1 implementation
+    /// notes (F.6.5 and F.6.6). Returns `None` when the arc
+    /// degenerates to a straight line or to nothing.
+    pub fn from_svg(
+        from: Vec2,
+        radii: Vec2,
+        x_axis_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Vec2,
+    ) -> Option<Self> {
+        if from == to {
+            return None;
+        }
+
+        let mut rx = radii.x.abs();
+        let mut ry = radii.y.abs();
+
+        if rx == 0.0 || ry == 0.0 {
+            return None;
+        }
+
+        let (sin, cos) = x_axis_rotation.sin_cos();
+        let half = (from - to) * 0.5;
+        let x1 = cos * half.x + sin * half.y;
+        let y1 = -sin * half.x + cos * half.y;
+
+        // Scale radii up when they can't span the endpoints.
+        let lambda =
+            (x1 * x1) / (rx * rx) + (y1 * y1) / (ry * ry);
+
+        if lambda > 1.0 {
+            let scale = lambda.sqrt();
+            rx *= scale;
+            ry *= scale;
+        }
+
+        let rx_sq = rx * rx;
+        let ry_sq = ry * ry;
+        let denominator = rx_sq * y1 * y1 + ry_sq * x1 * x1;
+        let numerator = rx_sq * ry_sq - denominator;
+
+        let mut coefficient =
+            (numerator / denominator).max(0.0).sqrt();
+
+        if large_arc == sweep {
+            coefficient = -coefficient;
+        }
+
+        let cx1 = coefficient * rx * y1 / ry;
+        let cy1 = -coefficient * ry * x1 / rx;
+
+        let midpoint = from.lerp(to, 0.5);
+        let center = Vec2::new(
+            cos * cx1 - sin * cy1 + midpoint.x,
+            sin * cx1 + cos * cy1 + midpoint.y,
+        );
+
+        let u = Vec2::new((x1 - cx1) / rx, (y1 - cy1) / ry);
+        let v = Vec2::new((-x1 - cx1) / rx, (-y1 - cy1) / ry);
+
+        let start_angle = u.atan2();
+        let mut sweep_angle = u.cross(v).atan2(u.dot(v));
+
+        if !sweep && sweep_angle > 0.0 {
+            sweep_angle -= TAU;
+        } else if sweep && sweep_angle < 0.0 {
+            sweep_angle += TAU;
+        }
+
+        Some(Self {
+            center,
+            radii: Vec2::new(rx, ry),
+            x_axis_rotation,
+            start_angle,
+            sweep_angle,
+        })
+    }
+
+    #[inline]
+    pub fn point_at(&self, angle: f32) -> Vec2 {
+        let (sin, cos) = angle.sin_cos();
+        self.center
+            + self.rotate(Vec2::new(
+                self.radii.x * cos,
+                self.radii.y * sin,
+            ))
+    }
+
+    #[inline]
+    pub fn start(&self) -> Vec2 {
+        self.point_at(self.start_angle)
+    }
+
+    #[inline]
+    pub fn end(&self) -> Vec2 {
+        self.point_at(self.start_angle + self.sweep_angle)
+    }
+
+    #[inline]
+    fn rotate(&self, vector: Vec2) -> Vec2 {
+        let (sin, cos) = self.x_axis_rotation.sin_cos();
+        Vec2::new(
+            cos * vector.x - sin * vector.y,
+            sin * vector.x + cos * vector.y,
+        )
+    }
+
+    #[inline]
+    fn tangent_at(&self, angle: f32) -> Vec2 {
+        let (sin, cos) = angle.sin_cos();
+        self.rotate(Vec2::new(
+            -self.radii.x * sin,
+            self.radii.y * cos,
+        ))
+    }
+
+    /// Appends the arc to `path` as cubic Beziers spanning at
+    /// most a quarter turn each. The caller is responsible for
+    /// the pen already being at [`Arc::start`].
+    pub fn append_cubics(&self, path: &mut Path) {
+        let count = (self.sweep_angle.abs() / FRAC_PI_2)
+            .ceil()
+            .max(1.0);
+        let step = self.sweep_angle / count;
+        let alpha = 4.0 / 3.0 * (step / 4.0).tan();
+
+        let mut angle = self.start_angle;
+        let mut point = self.point_at(angle);
+
+        for _ in 0..count as usize {
+            let next_angle = angle + step;
+            let next_point = self.point_at(next_angle);
+
+            path.cubic_to(
+                point + self.tangent_at(angle) * alpha,
+                next_point
+                    - self.tangent_at(next_angle) * alpha,
+                next_point,
+            );
+
+            angle = next_angle;
+            point = next_point;
+        }
+    }
+}
+
+impl Path {
+    /// SVG `A` command. Zero radii produce a straight line and
+    /// radii too small to reach `to` are scaled up uniformly.
+    /// `x_axis_rotation` is in radians.
+    pub fn arc_to(
+        &mut self,
+        radii: Vec2,
+        x_axis_rotation: f32,
+        large_arc: bool,
+        sweep: bool,
+        to: Vec2,
+    ) -> &mut Self {
+        let from = self.current_point().unwrap_or(Vec2::ZERO);
+
+        match Arc::from_svg(
+            from,
+            radii,
+            x_axis_rotation,
+            large_arc,
+            sweep,
+            to,
+        ) {
+            Some(arc) => arc.append_cubics(self),
+            None if from != to => {
+                self.line_to(to);
+            }
+            None => (),
+        }
+
+        self
+    }
+
+    /// Appends an arc given by center and angles. Connects to
+    /// the arc's start with a line, or starts a new subpath when
+    /// the path is empty.
+    pub fn arc(&mut self, arc: Arc) -> &mut Self {
+        let start = arc.start();
+
+        match self.current_point() {
+            Some(current) if current != start => {
+                self.line_to(start);
+            }
+            Some(_) => (),
+            None => {
+                self.move_to(start);
+            }
+        }
+
+        arc.append_cubics(self);
+        self
+    }
+
+    /// Rational quadratic Bezier (conic section) with the given
+    /// control point weight, approximated with quadratic
+    /// Beziers to within `tolerance`.
+    pub fn conic_to(
+        &mut self,
+        control: Vec2,
+        to: Vec2,
+        weight: f32,
+        tolerance: f32,
+    ) -> &mut Self {
+        const MAX_SUBDIVISIONS: u32 = 5;
+
+        let from = self.current_point().unwrap_or(Vec2::ZERO);
+
+        if !(weight.is_finite() && weight > 0.0) {
+            self.line_to(control).line_to(to);
+            return self;
+        }
+
+        // Error estimate of replacing the conic with a quadratic
+        // that shares its control point. It shrinks by a factor
+        // of four with every subdivision.
+        let a = weight - 1.0;
+        let k = a / (4.0 * (2.0 + a));
+        let mut error =
+            ((from - control * 2.0 + to) * k).length();
+
+        let mut level = 0;
+
+        while error > tolerance && level < MAX_SUBDIVISIONS {
+            error *= 0.25;
+            level += 1;
+        }
+
+        append_conic(self, from, control, to, weight, level);
+        self
+    }
+}
+
+fn append_conic(
+    path: &mut Path,
+    from: Vec2,
+    control: Vec2,
+    to: Vec2,
+    weight: f32,
+    level: u32,
+) {
+    if level == 0 {
+        path.quad_to(control, to);
+        return;
+    }
+
+    // Split at t = 0.5 in homogeneous coordinates.
+    let scale = (1.0 + weight).recip();
+    let left_control = (from + control * weight) * scale;
+    let right_control = (control * weight + to) * scale;
+    let middle =
+        (from + control * (2.0 * weight) + to) * (scale * 0.5);
+    let half_weight = (0.5 + weight * 0.5).sqrt();
+
+    append_conic(
+        path,
+        from,
+        left_control,
+        middle,
+        half_weight,
+        level - 1,
+    );
+    append_conic(
+        path,
+        middle,
+        right_control,
+        to,
+        half_weight,
+        level - 1,
+    );
+}
+
+#[test]
+fn test_svg_arc_flags() {
+    use std::f32::consts::PI;
+
+    let from = Vec2::new(0.0, 0.0);
+    let to = Vec2::new(2.0, 0.0);
+
+    let arc = Arc::from_svg(
+        from,
+        Vec2::splat(1.0),
+        0.0,
+        false,
+        true,
+        to,
+    )
+    .unwrap();
+
+    assert!(arc.center.distance(Vec2::new(1.0, 0.0)) < 1e-5);
+    assert!((arc.sweep_angle - PI).abs() < 1e-5);
+
+    // Radii too small: scaled up to span the endpoints.
+    let arc = Arc::from_svg(
+        from,
+        Vec2::splat(0.25),
+        0.0,
+        false,
+        false,
+        to,
+    )
+    .unwrap();
+
+    assert!((arc.radii.x - 1.0).abs() < 1e-5);
+    assert!((arc.sweep_angle + PI).abs() < 1e-5);
+
+    // Large arc of a bigger circle goes the long way round.
+    let large = Arc::from_svg(
+        from,
+        Vec2::splat(2.0),
+        0.0,
+        true,
+        true,
+        to,
+    )
+    .unwrap();
+    let small = Arc::from_svg(
+        from,
+        Vec2::splat(2.0),
+        0.0,
+        false,
+        true,
+        to,
+    )
+    .unwrap();
+
+    assert!(large.sweep_angle > PI && large.sweep_angle < TAU);
+    assert!(small.sweep_angle > 0.0 && small.sweep_angle < PI);
+    assert!(large.end().distance(to) < 1e-5);
+    assert!(small.end().distance(to) < 1e-5);
+
+    let mut path = Path::new();
+    path.move_to(from).arc_to(
+        Vec2::splat(1.0),
+        0.0,
+        false,
+        true,
+        to,
+    );
+
+    assert_eq!(path.elements().len(), 3);
+    assert!(path.current_point().unwrap().distance(to) < 1e-5);
+}
+
+#[test]
+fn test_conic_matches_circle() {
+    let mut path = Path::new();
+
+    // A quarter circle is a conic with weight cos(45 deg).
+    path.move_to(Vec2::new(1.0, 0.0)).conic_to(
+        Vec2::new(1.0, 1.0),
+        Vec2::new(0.0, 1.0),
+        std::f32::consts::FRAC_1_SQRT_2,
+        1e-4,
+    );
+
+    for segment in path.segments() {
+        for step in 0..=8 {
+            let radius =
+                segment.eval(step as f32 / 8.0).length();
+            assert!((radius - 1.0).abs() < 1e-3);
+        }
+    }
+}
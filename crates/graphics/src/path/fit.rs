@@ -0,0 +1,573 @@
+use std::f32::consts::FRAC_PI_3;
+
+use crate::Vec2;
+
+use super::{Path, PathSegment};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitOptions {
+    /// Maximum distance between an input sample and the
+    /// fitted curve.
+    pub tolerance: f32,
+    /// Turning angle in radians above which a sample becomes a
+    /// sharp joint instead of being smoothed over.
+    pub corner_angle: f32,
+}
+
+impl Default for FitOptions {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            tolerance: 1.0,
+            corner_angle: FRAC_PI_3,
+        }
+    }
+}
+
+/// Ramer–Douglas–Peucker polyline simplification.
+pub fn simplify_polyline(
+    points: &[Vec2],
+    tolerance: f32,
+) -> Vec<Vec2> {
+    simplify_indices(points, tolerance)
+        .into_iter()
+        .map(|index| points[index])
+        .collect()
+}
+
+/// Indices of the points kept by Ramer–Douglas–Peucker, in
+/// ascending order.
+fn simplify_indices(
+    points: &[Vec2],
+    tolerance: f32,
+) -> Vec<usize> {
+    if points.len() <= 2 {
+        return (0..points.len()).collect();
+    }
+
+    let mut keep = vec![false; points.len()];
+    let mut stack = vec![(0, points.len() - 1)];
+
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+
+    while let Some((first, last)) = stack.pop() {
+        let chord =
+            PathSegment::Line(points[first], points[last]);
+
+        let farthest = (first + 1..last)
+            .map(|index| {
+                let (_, nearest) = chord.nearest(points[index]);
+                (index, nearest.distance(points[index]))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        if let Some((index, _)) = farthest
+            .filter(|&(_, distance)| distance > tolerance)
+        {
+            keep[index] = true;
+            stack.push((first, index));
+            stack.push((index, last));
+        }
+    }
+
+    keep.iter()
+        .enumerate()
+        .filter_map(|(index, keep)| keep.then_some(index))
+        .collect()
+}
+
+/// Fits cubic Beziers through noisy samples using Schneider's
+/// least-squares algorithm ("An Algorithm for Automatically
+/// Fitting Digitized Curves", Graphics Gems, 1990).
+pub fn fit_curve(points: &[Vec2], options: FitOptions) -> Path {
+    let points = dedup(points);
+    let mut path = Path::new();
+
+    if let Some(&first) = points.first() {
+        path.move_to(first);
+    }
+
+    for (_, segment) in fit_segments(&points, options, None) {
+        append_segment(&mut path, segment);
+    }
+
+    path
+}
+
+/// Incremental variant of [`fit_curve`] for input arriving while
+/// the user draws. Settled curves are committed so only the
+/// trailing samples are refitted on each new point.
+#[derive(Clone, Debug, Default)]
+pub struct CurveFitter {
+    options: FitOptions,
+    committed: Path,
+    pending: Vec<Vec2>,
+    pending_tangent: Option<Vec2>,
+}
+
+impl CurveFitter {
+    #[inline]
+    pub fn new(options: FitOptions) -> Self {
+        Self {
+            options,
+            committed: Path::new(),
+            pending: Vec::new(),
+            pending_tangent: None,
+        }
+    }
+
+    pub fn push(&mut self, point: Vec2) {
+        if self.pending.last() == Some(&point) {
+            return;
+        }
+
+        if self.committed.is_empty() && self.pending.is_empty()
+        {
+            self.committed.move_to(point);
+        }
+
+        self.pending.push(point);
+
+        let segments = fit_segments(
+            &self.pending,
+            self.options,
+            self.pending_tangent,
+        );
+
+        // Everything but the last curve is unlikely to change
+        // with more input, so settle it.
+        let Some(((last_start, _), settled)) =
+            segments.split_last()
+        else {
+            return;
+        };
+
+        let Some(&(_, previous)) = settled.last() else {
+            return;
+        };
+
+        for &(_, segment) in settled {
+            append_segment(&mut self.committed, segment);
+        }
+
+        // Keep the joint smooth unless it was a corner.
+        self.pending_tangent = match previous {
+            PathSegment::Cubic(_, _, control, end)
+                if !is_corner(
+                    &self.pending,
+                    *last_start,
+                    self.options,
+                ) =>
+            {
+                Some((end - control).normalize_or_zero())
+            }
+            _ => None,
+        };
+
+        self.pending.drain(..*last_start);
+    }
+
+    /// Committed curves followed by the current fit of the
+    /// pending samples.
+    pub fn path(&self) -> Path {
+        let mut path = self.committed.clone();
+
+        for (_, segment) in fit_segments(
+            &self.pending,
+            self.options,
+            self.pending_tangent,
+        ) {
+            append_segment(&mut path, segment);
+        }
+
+        path
+    }
+
+    #[inline]
+    pub fn finish(self) -> Path {
+        self.path()
+    }
+}
+
+fn append_segment(path: &mut Path, segment: PathSegment) {
+    if let PathSegment::Cubic(_, control1, control2, end) =
+        segment
+    {
+        path.cubic_to(control1, control2, end);
+    }
+}
+
+fn dedup(points: &[Vec2]) -> Vec<Vec2> {
+    let mut result = points.to_vec();
+    result.dedup();
+    result
+}
+
+/// `true` when the polyline turns by more than the corner
+/// angle at `index`, measured on the simplified polyline so
+/// jitter doesn't register as corners.
+fn is_corner(
+    points: &[Vec2],
+    index: usize,
+    options: FitOptions,
+) -> bool {
+    corner_indices(points, options).contains(&index)
+}
+
+fn corner_indices(
+    points: &[Vec2],
+    options: FitOptions,
+) -> Vec<usize> {
+    let kept = simplify_indices(points, options.tolerance);
+
+    kept.windows(3)
+        .filter_map(|window| {
+            let incoming =
+                points[window[1]] - points[window[0]];
+            let outgoing =
+                points[window[2]] - points[window[1]];
+            let angle = incoming
+                .cross(outgoing)
+                .atan2(incoming.dot(outgoing))
+                .abs();
+
+            (angle > options.corner_angle).then_some(window[1])
+        })
+        .collect()
+}
+
+/// Fits the samples, splitting at corners. Returns each cubic
+/// with the index of its first sample.
+fn fit_segments(
+    points: &[Vec2],
+    options: FitOptions,
+    start_tangent: Option<Vec2>,
+) -> Vec<(usize, PathSegment)> {
+    let mut segments = Vec::new();
+
+    if points.len() < 2 {
+        return segments;
+    }
+
+    let mut boundaries = vec![0];
+    boundaries.extend(corner_indices(points, options));
+    boundaries.push(points.len() - 1);
+
+    for window in boundaries.windows(2) {
+        let (first, last) = (window[0], window[1]);
+        let run = &points[first..=last];
+
+        let tangent_start = match start_tangent {
+            Some(tangent) if first == 0 => tangent,
+            _ => (run[1] - run[0]).normalize_or_zero(),
+        };
+        let tangent_end = (run[run.len() - 2]
+            - run[run.len() - 1])
+            .normalize_or_zero();
+
+        fit_cubic(
+            run,
+            first,
+            tangent_start,
+            tangent_end,
+            options.tolerance * options.tolerance,
+            &mut segments,
+        );
+    }
+
+    segments
+}
+
+fn fit_cubic(
+    points: &[Vec2],
+    offset: usize,
+    tangent_start: Vec2,
+    tangent_end: Vec2,
+    error_sq: f32,
+    output: &mut Vec<(usize, PathSegment)>,
+) {
+    const MAX_REPARAMETERIZATIONS: usize = 4;
+
+    let first = points[0];
+    let last = points[points.len() - 1];
+
+    if points.len() == 2 {
+        let distance = first.distance(last) / 3.0;
+
+        output.push((
+            offset,
+            PathSegment::Cubic(
+                first,
+                first + tangent_start * distance,
+                last + tangent_end * distance,
+                last,
+            ),
+        ));
+        return;
+    }
+
+    let mut parameters = chord_length_parameters(points);
+    let mut curve = generate_bezier(
+        points,
+        &parameters,
+        tangent_start,
+        tangent_end,
+    );
+    let (mut max_error, mut split) =
+        measure_error(points, &parameters, &curve);
+
+    if max_error < error_sq {
+        output.push((offset, curve));
+        return;
+    }
+
+    // Close enough that improving the parameterization may be
+    // sufficient.
+    if max_error < error_sq * 4.0 {
+        for _ in 0..MAX_REPARAMETERIZATIONS {
+            reparameterize(points, &mut parameters, &curve);
+            curve = generate_bezier(
+                points,
+                &parameters,
+                tangent_start,
+                tangent_end,
+            );
+            (max_error, split) =
+                measure_error(points, &parameters, &curve);
+
+            if max_error < error_sq {
+                output.push((offset, curve));
+                return;
+            }
+        }
+    }
+
+    let mut tangent_center = (points[split - 1]
+        - points[split + 1])
+        .normalize_or_zero();
+
+    if tangent_center == Vec2::ZERO {
+        tangent_center = (points[split - 1] - points[split])
+            .normalize_or_zero();
+    }
+
+    fit_cubic(
+        &points[..=split],
+        offset,
+        tangent_start,
+        tangent_center,
+        error_sq,
+        output,
+    );
+    fit_cubic(
+        &points[split..],
+        offset + split,
+        -tangent_center,
+        tangent_end,
+        error_sq,
+        output,
+    );
+}
+
+fn chord_length_parameters(points: &[Vec2]) -> Vec<f32> {
+    let mut parameters = Vec::with_capacity(points.len());
+    let mut length = 0.0;
+
+    parameters.push(0.0);
+
+    for window in points.windows(2) {
+        length += window[0].distance(window[1]);
+        parameters.push(length);
+    }
+
+    if length > 0.0 {
+        for parameter in parameters.iter_mut() {
+            *parameter /= length;
+        }
+    }
+
+    parameters
+}
+
+fn generate_bezier(
+    points: &[Vec2],
+    parameters: &[f32],
+    tangent_start: Vec2,
+    tangent_end: Vec2,
+) -> PathSegment {
+    let first = points[0];
+    let last = points[points.len() - 1];
+
+    let (mut c00, mut c01, mut c11) = (0.0, 0.0, 0.0);
+    let (mut x0, mut x1) = (0.0, 0.0);
+
+    for (&point, &u) in points.iter().zip(parameters) {
+        let mu = 1.0 - u;
+        let b0 = mu * mu * mu;
+        let b1 = 3.0 * u * mu * mu;
+        let b2 = 3.0 * u * u * mu;
+        let b3 = u * u * u;
+
+        let a0 = tangent_start * b1;
+        let a1 = tangent_end * b2;
+
+        c00 += a0.dot(a0);
+        c01 += a0.dot(a1);
+        c11 += a1.dot(a1);
+
+        let residual =
+            point - (first * (b0 + b1) + last * (b2 + b3));
+
+        x0 += a0.dot(residual);
+        x1 += a1.dot(residual);
+    }
+
+    let segment_length = first.distance(last);
+    let epsilon = 1e-6 * segment_length;
+    let determinant = c00 * c11 - c01 * c01;
+
+    let (mut alpha_start, mut alpha_end) =
+        if determinant.abs() > f32::EPSILON {
+            (
+                (x0 * c11 - x1 * c01) / determinant,
+                (c00 * x1 - c01 * x0) / determinant,
+            )
+        } else {
+            (0.0, 0.0)
+        };
+
+    // Wu/Barsky heuristic for degenerate solutions.
+    if alpha_start < epsilon || alpha_end < epsilon {
+        alpha_start = segment_length / 3.0;
+        alpha_end = alpha_start;
+    }
+
+    PathSegment::Cubic(
+        first,
+        first + tangent_start * alpha_start,
+        last + tangent_end * alpha_end,
+        last,
+    )
+}
+
+/// Largest squared distance between a sample and its point on
+/// the curve, with the sample index to split at.
+fn measure_error(
+    points: &[Vec2],
+    parameters: &[f32],
+    curve: &PathSegment,
+) -> (f32, usize) {
+    let mut max_error = 0.0;
+    let mut split = points.len() / 2;
+
+    for index in 1..points.len() - 1 {
+        let error = (curve.eval(parameters[index])
+            - points[index])
+            .length_sq();
+
+        if error >= max_error {
+            max_error = error;
+            split = index;
+        }
+    }
+
+    (max_error, split)
+}
+
+fn reparameterize(
+    points: &[Vec2],
+    parameters: &mut [f32],
+    curve: &PathSegment,
+) {
+    for (&point, u) in points.iter().zip(parameters.iter_mut())
+    {
+        let offset = curve.eval(*u) - point;
+        let d1 = curve.derivative(*u);
+        let d2 = curve.second_derivative(*u);
+        let denominator = d1.dot(d1) + offset.dot(d2);
+
+        if denominator.abs() > f32::EPSILON {
+            *u = (*u - offset.dot(d1) / denominator)
+                .clamp(0.0, 1.0);
+        }
+    }
+}
+
+#[cfg(test)]
+fn max_distance(path: &Path, points: &[Vec2]) -> f32 {
+    points
+        .iter()
+        .map(|&point| {
+            path.nearest_point(point).unwrap().distance
+        })
+        .fold(0.0, f32::max)
+}
+
+#[test]
+fn test_fit_curve_within_tolerance() {
+    let samples = (0..=64)
+        .map(|index| {
+            let t = index as f32 / 64.0 * std::f32::consts::PI;
+            // Wobbly sine with deterministic jitter.
+            let jitter =
+                ((index * 7919) % 13) as f32 / 13.0 - 0.5;
+            Vec2::new(t * 40.0, t.sin() * 30.0 + jitter * 0.4)
+        })
+        .collect::<Vec<_>>();
+
+    let options = FitOptions {
+        tolerance: 1.0,
+        ..Default::default()
+    };
+    let path = fit_curve(&samples, options);
+
+    assert!(max_distance(&path, &samples) <= 1.0);
+    assert!(path.segments().count() < samples.len() / 4);
+
+    let simplified = simplify_polyline(&samples, 1.0);
+    assert!(simplified.len() < samples.len());
+    assert_eq!(simplified.first(), samples.first());
+    assert_eq!(simplified.last(), samples.last());
+}
+
+#[test]
+fn test_fit_curve_keeps_corners() {
+    let mut samples = Vec::new();
+
+    for index in 0..=20 {
+        samples.push(Vec2::new(index as f32, 0.0));
+    }
+
+    for index in 1..=20 {
+        samples.push(Vec2::new(20.0, index as f32));
+    }
+
+    let path = fit_curve(&samples, FitOptions::default());
+    let segments = path.segments().collect::<Vec<_>>();
+
+    assert_eq!(segments.len(), 2);
+    assert_eq!(segments[0].end(), Vec2::new(20.0, 0.0));
+}
+
+#[test]
+fn test_curve_fitter_streaming() {
+    let samples = (0..=200)
+        .map(|index| {
+            let angle =
+                index as f32 / 200.0 * std::f32::consts::TAU;
+            Vec2::new(angle.cos(), angle.sin()) * 50.0
+        })
+        .collect::<Vec<_>>();
+
+    let mut fitter = CurveFitter::new(FitOptions::default());
+
+    for &sample in &samples {
+        fitter.push(sample);
+    }
+
+    let path = fitter.finish();
+
+    assert!(max_distance(&path, &samples) <= 1.0);
+    assert_eq!(path.current_point(), samples.last().copied());
+}
@@ -0,0 +1,276 @@
+use crate::{Mat3, Vec2};
+
+use super::{Path, PathElement, PathSegment};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum FillRule {
+    #[default]
+    NonZero,
+    EvenOdd,
+}
+
+impl FillRule {
+    #[inline]
+    pub const fn is_inside(self, winding: i32) -> bool {
+        match self {
+            Self::NonZero => winding != 0,
+            Self::EvenOdd => winding % 2 != 0,
+        }
+    }
+}
+
+/// Closest location on a path to a query point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NearestPoint {
+    /// Index into [`Path::segments`].
+    pub segment_index: usize,
+    pub t: f32,
+    pub point: Vec2,
+    pub distance: f32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathHit {
+    Stroke(NearestPoint),
+    Fill,
+}
+
+impl Path {
+    /// Winding number of the path around `point`. Open subpaths
+    /// are treated as implicitly closed, as they are when
+    /// filled.
+    pub fn winding_number(&self, point: Vec2) -> i32 {
+        let mut winding = 0;
+        let mut start = None;
+        let mut current = Vec2::ZERO;
+
+        let close = |from: Vec2, to: Vec2| {
+            segment_winding(&PathSegment::Line(from, to), point)
+        };
+
+        for element in &self.elements {
+            let segment = match *element {
+                PathElement::MoveTo(to) => {
+                    if let Some(start) = start.replace(to) {
+                        winding += close(current, start);
+                    }
+
+                    current = to;
+                    continue;
+                }
+                PathElement::LineTo(to) => {
+                    PathSegment::Line(current, to)
+                }
+                PathElement::QuadTo(control, to) => {
+                    PathSegment::Quad(current, control, to)
+                }
+                PathElement::CubicTo(
+                    control1,
+                    control2,
+                    to,
+                ) => PathSegment::Cubic(
+                    current, control1, control2, to,
+                ),
+                PathElement::Close => PathSegment::Line(
+                    current,
+                    start.unwrap_or(current),
+                ),
+            };
+
+            winding += segment_winding(&segment, point);
+            current = segment.end();
+        }
+
+        if let Some(start) = start {
+            winding += close(current, start);
+        }
+
+        winding
+    }
+
+    #[inline]
+    pub fn contains(
+        &self,
+        point: Vec2,
+        fill_rule: FillRule,
+    ) -> bool {
+        fill_rule.is_inside(self.winding_number(point))
+    }
+
+    /// Closest point on any segment of the path.
+    pub fn nearest_point(
+        &self,
+        point: Vec2,
+    ) -> Option<NearestPoint> {
+        self.segments()
+            .enumerate()
+            .map(|(segment_index, segment)| {
+                let (t, nearest) = segment.nearest(point);
+
+                NearestPoint {
+                    segment_index,
+                    t,
+                    point: nearest,
+                    distance: nearest.distance(point),
+                }
+            })
+            .min_by(|a, b| a.distance.total_cmp(&b.distance))
+    }
+
+    /// Nearest point on the outline if it lies within
+    /// `tolerance` of `point`.
+    #[inline]
+    pub fn hit_stroke(
+        &self,
+        point: Vec2,
+        tolerance: f32,
+    ) -> Option<NearestPoint> {
+        self.nearest_point(point)
+            .filter(|nearest| nearest.distance <= tolerance)
+    }
+
+    /// Hit test in world space for a path drawn with
+    /// `transform`. The stroke is tested first so clicks on the
+    /// outline of a filled shape report the exact location.
+    /// `stroke_tolerance` is in world units; results are in the
+    /// path's local space.
+    pub fn hit_test(
+        &self,
+        point: Vec2,
+        transform: Mat3,
+        fill_rule: Option<FillRule>,
+        stroke_tolerance: Option<f32>,
+    ) -> Option<PathHit> {
+        let inverse = transform.inverse()?;
+        let local = inverse.transform(point);
+
+        if let Some(tolerance) = stroke_tolerance {
+            // Approximates the local tolerance with the average
+            // scale of the transform.
+            let scale = transform.determinant().abs().sqrt();
+
+            if let Some(nearest) =
+                self.hit_stroke(local, tolerance / scale)
+            {
+                return Some(PathHit::Stroke(nearest));
+            }
+        }
+
+        match fill_rule {
+            Some(fill_rule)
+                if self.contains(local, fill_rule) =>
+            {
+                Some(PathHit::Fill)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Signed number of times the segment crosses the horizontal
+/// ray going from `point` towards positive x.
+fn segment_winding(segment: &PathSegment, point: Vec2) -> i32 {
+    const BISECTION_ITERATIONS: usize = 32;
+
+    let (ranges, count) = segment.y_monotonic_ranges();
+    let mut winding = 0;
+
+    for window in ranges[..=count].windows(2) {
+        let (mut t0, mut t1) = (window[0], window[1]);
+        let y0 = segment.eval(t0).y;
+        let y1 = segment.eval(t1).y;
+
+        // Half-open test so shared endpoints count once.
+        if (y0 <= point.y) == (y1 <= point.y) {
+            continue;
+        }
+
+        let increasing = y1 > y0;
+
+        for _ in 0..BISECTION_ITERATIONS {
+            let mid = (t0 + t1) * 0.5;
+
+            if (segment.eval(mid).y <= point.y) == increasing {
+                t0 = mid;
+            } else {
+                t1 = mid;
+            }
+        }
+
+        if segment.eval((t0 + t1) * 0.5).x > point.x {
+            winding += if increasing { 1 } else { -1 };
+        }
+    }
+
+    winding
+}
+
+#[test]
+fn test_path_fill_rules() {
+    let mut path = Path::new();
+
+    // Two nested squares wound in the same direction.
+    path.move_to(Vec2::new(0.0, 0.0))
+        .line_to(Vec2::new(10.0, 0.0))
+        .line_to(Vec2::new(10.0, 10.0))
+        .line_to(Vec2::new(0.0, 10.0))
+        .close()
+        .move_to(Vec2::new(2.0, 2.0))
+        .line_to(Vec2::new(8.0, 2.0))
+        .line_to(Vec2::new(8.0, 8.0))
+        .line_to(Vec2::new(2.0, 8.0));
+
+    let center = Vec2::new(5.0, 5.0);
+    let ring = Vec2::new(1.0, 5.0);
+
+    assert_eq!(path.winding_number(center).abs(), 2);
+    assert!(path.contains(center, FillRule::NonZero));
+    assert!(!path.contains(center, FillRule::EvenOdd));
+    assert!(path.contains(ring, FillRule::EvenOdd));
+    assert!(
+        !path.contains(Vec2::new(11.0, 5.0), FillRule::NonZero)
+    );
+}
+
+#[test]
+fn test_path_stroke_hit() {
+    let mut path = Path::new();
+
+    path.move_to(Vec2::new(0.0, 0.0)).cubic_to(
+        Vec2::new(0.0, 10.0),
+        Vec2::new(10.0, 10.0),
+        Vec2::new(10.0, 0.0),
+    );
+
+    let nearest =
+        path.nearest_point(Vec2::new(5.0, 9.0)).unwrap();
+
+    assert_eq!(nearest.segment_index, 0);
+    assert!((nearest.t - 0.5).abs() < 1e-4);
+    assert!((nearest.point.y - 7.5).abs() < 1e-4);
+    assert!(
+        path.hit_stroke(Vec2::new(5.0, 9.0), 1.0).is_none()
+    );
+
+    let transform = Mat3::translation(Vec2::new(100.0, 0.0))
+        * Mat3::scale_f32(2.0);
+
+    assert_eq!(
+        path.hit_test(
+            Vec2::new(110.0, 18.0),
+            transform,
+            None,
+            Some(4.0)
+        ),
+        Some(PathHit::Stroke(nearest))
+    );
+    assert_eq!(
+        path.hit_test(
+            Vec2::new(110.0, 10.0),
+            transform,
+            Some(FillRule::NonZero),
+            Some(1.0)
+        ),
+        Some(PathHit::Fill)
+    );
+}
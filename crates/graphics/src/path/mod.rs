@@ -0,0 +1,263 @@
+mod arc;
+mod fit;
+mod hit_test;
+mod offset;
+mod segment;
+mod shapes;
+
+pub use arc::Arc;
+pub use fit::{
+    CurveFitter, FitOptions, fit_curve, simplify_polyline,
+};
+pub use hit_test::{FillRule, NearestPoint, PathHit};
+pub use offset::{LineCap, LineJoin, StrokeStyle};
+pub use segment::PathSegment;
+
+use crate::{Mat3, Rect, Vec2};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathElement {
+    MoveTo(Vec2),
+    LineTo(Vec2),
+    QuadTo(Vec2, Vec2),
+    CubicTo(Vec2, Vec2, Vec2),
+    Close,
+}
+
+/// Sequence of subpaths made of lines and Bezier curves.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Path {
+    elements: Vec<PathElement>,
+}
+
+impl Path {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            elements: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    pub fn elements(&self) -> &[PathElement] {
+        &self.elements
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.elements.clear();
+    }
+
+    #[inline]
+    pub fn move_to(&mut self, point: Vec2) -> &mut Self {
+        self.elements.push(PathElement::MoveTo(point));
+        self
+    }
+
+    #[inline]
+    pub fn line_to(&mut self, point: Vec2) -> &mut Self {
+        self.elements.push(PathElement::LineTo(point));
+        self
+    }
+
+    #[inline]
+    pub fn quad_to(
+        &mut self,
+        control: Vec2,
+        point: Vec2,
+    ) -> &mut Self {
+        self.elements.push(PathElement::QuadTo(control, point));
+        self
+    }
+
+    #[inline]
+    pub fn cubic_to(
+        &mut self,
+        control1: Vec2,
+        control2: Vec2,
+        point: Vec2,
+    ) -> &mut Self {
+        self.elements.push(PathElement::CubicTo(
+            control1, control2, point,
+        ));
+        self
+    }
+
+    #[inline]
+    pub fn close(&mut self) -> &mut Self {
+        self.elements.push(PathElement::Close);
+        self
+    }
+
+    /// Position of the pen after the last element, if any.
+    pub fn current_point(&self) -> Option<Vec2> {
+        match *self.elements.last()? {
+            PathElement::MoveTo(point)
+            | PathElement::LineTo(point)
+            | PathElement::QuadTo(_, point)
+            | PathElement::CubicTo(_, _, point) => Some(point),
+            PathElement::Close => self
+                .elements
+                .iter()
+                .rev()
+                .find_map(|element| match *element {
+                    PathElement::MoveTo(point) => Some(point),
+                    _ => None,
+                }),
+        }
+    }
+
+    /// Drawable segments in order, including the line produced
+    /// by each `Close`. Segment indices used by hit testing
+    /// refer to this sequence.
+    #[inline]
+    pub fn segments(&self) -> PathSegments<'_> {
+        PathSegments {
+            elements: self.elements.iter(),
+            start: Vec2::ZERO,
+            current: Vec2::ZERO,
+        }
+    }
+
+    /// Exact bounding box of the path geometry.
+    pub fn bounds(&self) -> Option<Rect> {
+        let mut bounds: Option<Rect> = None;
+
+        for element in &self.elements {
+            if let PathElement::MoveTo(point) = *element {
+                bounds = Some(match bounds {
+                    Some(bounds) => bounds.include(point),
+                    None => Rect::from_min_max(point, point),
+                });
+            }
+        }
+
+        for segment in self.segments() {
+            let segment_bounds = segment.bounds();
+
+            bounds = Some(match bounds {
+                Some(bounds) => Rect::from_min_max(
+                    bounds.min.min(segment_bounds.min),
+                    bounds.max.max(segment_bounds.max),
+                ),
+                None => segment_bounds,
+            });
+        }
+
+        bounds
+    }
+
+    /// Applies `matrix` to every point of the path.
+    pub fn transform(&self, matrix: Mat3) -> Path {
+        let elements = self
+            .elements
+            .iter()
+            .map(|element| match *element {
+                PathElement::MoveTo(p) => {
+                    PathElement::MoveTo(matrix.transform(p))
+                }
+                PathElement::LineTo(p) => {
+                    PathElement::LineTo(matrix.transform(p))
+                }
+                PathElement::QuadTo(c, p) => {
+                    PathElement::QuadTo(
+                        matrix.transform(c),
+                        matrix.transform(p),
+                    )
+                }
+                PathElement::CubicTo(c1, c2, p) => {
+                    PathElement::CubicTo(
+                        matrix.transform(c1),
+                        matrix.transform(c2),
+                        matrix.transform(p),
+                    )
+                }
+                PathElement::Close => PathElement::Close,
+            })
+            .collect();
+
+        Path { elements }
+    }
+}
+
+impl FromIterator<PathElement> for Path {
+    fn from_iter<T: IntoIterator<Item = PathElement>>(
+        iter: T,
+    ) -> Self {
+        Self {
+            elements: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<PathElement> for Path {
+    fn extend<T: IntoIterator<Item = PathElement>>(
+        &mut self,
+        iter: T,
+    ) {
+        self.elements.extend(iter);
+    }
+}
+
+pub struct PathSegments<'a> {
+    elements: std::slice::Iter<'a, PathElement>,
+    start: Vec2,
+    current: Vec2,
+}
+
+impl Iterator for PathSegments<'_> {
+    type Item = PathSegment;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let from = self.current;
+
+            match *self.elements.next()? {
+                PathElement::MoveTo(point) => {
+                    self.start = point;
+                    self.current = point;
+                }
+                PathElement::LineTo(point) => {
+                    self.current = point;
+                    return Some(PathSegment::Line(
+                        from, point,
+                    ));
+                }
+                PathElement::QuadTo(control, point) => {
+                    self.current = point;
+                    return Some(PathSegment::Quad(
+                        from, control, point,
+                    ));
+                }
+                PathElement::CubicTo(
+                    control1,
+                    control2,
+                    point,
+                ) => {
+                    self.current = point;
+                    return Some(PathSegment::Cubic(
+                        from, control1, control2, point,
+                    ));
+                }
+                PathElement::Close => {
+                    self.current = self.start;
+                    return Some(PathSegment::Line(
+                        from, self.start,
+                    ));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,980 @@
+use std::f32::consts::PI;
+
+use crate::{Rect, Vec2};
+
+use super::{Arc, Path, PathElement, PathSegment};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LineJoin {
+    /// Sharp corner, replaced with a bevel when the miter length
+    /// exceeds the limit times the offset distance.
+    Miter(f32),
+    Round,
+    Bevel,
+}
+
+impl Default for LineJoin {
+    #[inline]
+    fn default() -> Self {
+        Self::Miter(4.0)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum LineCap {
+    #[default]
+    Butt,
+    Round,
+    Square,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub join: LineJoin,
+    pub cap: LineCap,
+    /// Maximum deviation of the approximated outline from the
+    /// exact offset curve.
+    pub tolerance: f32,
+}
+
+impl Default for StrokeStyle {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            width: 1.0,
+            join: LineJoin::default(),
+            cap: LineCap::default(),
+            tolerance: 0.1,
+        }
+    }
+}
+
+struct Subpath {
+    segments: Vec<PathSegment>,
+    closed: bool,
+}
+
+impl Path {
+    /// Offsets every subpath by `distance`. Positive values grow
+    /// the filled area (outset) and negative values shrink it
+    /// (inset), whichever way the outer contour winds. Open
+    /// subpaths are offset to the left of their direction of
+    /// travel on screen.
+    pub fn offset(
+        &self,
+        distance: f32,
+        join: LineJoin,
+        tolerance: f32,
+    ) -> Path {
+        let subpaths = self.subpaths();
+
+        let area: f32 = subpaths
+            .iter()
+            .filter(|subpath| subpath.closed)
+            .map(|subpath| signed_area(&subpath.segments))
+            .sum();
+
+        let distance =
+            if area < 0.0 { -distance } else { distance };
+        let mut path = Path::new();
+
+        for subpath in &subpaths {
+            let chain = offset_chain(
+                &subpath.segments,
+                subpath.closed,
+                distance,
+                join,
+                tolerance,
+            );
+
+            append_chain(&mut path, &chain, subpath.closed);
+        }
+
+        path
+    }
+
+    /// Converts a stroke into a fillable outline. Closed
+    /// subpaths produce an outer and an inner contour; open
+    /// subpaths produce a single contour with caps.
+    pub fn outline_stroke(&self, style: &StrokeStyle) -> Path {
+        let half_width = style.width.abs() * 0.5;
+        let mut path = Path::new();
+
+        if half_width == 0.0 {
+            return path;
+        }
+
+        for subpath in self.subpaths() {
+            let reversed = subpath
+                .segments
+                .iter()
+                .rev()
+                .map(reverse_segment)
+                .collect::<Vec<_>>();
+
+            let left = offset_chain(
+                &subpath.segments,
+                subpath.closed,
+                half_width,
+                style.join,
+                style.tolerance,
+            );
+            let right = offset_chain(
+                &reversed,
+                subpath.closed,
+                half_width,
+                style.join,
+                style.tolerance,
+            );
+
+            if subpath.closed {
+                append_chain(&mut path, &left, true);
+                append_chain(&mut path, &right, true);
+                continue;
+            }
+
+            let (Some(first), Some(last)) = (
+                subpath.segments.first(),
+                subpath.segments.last(),
+            ) else {
+                continue;
+            };
+
+            let contour_start =
+                left.first().map(PathSegment::start);
+            let mut contour = left;
+            cap(
+                &mut contour,
+                last.end(),
+                unit_tangent(last, 1.0),
+                right.first().map(PathSegment::start),
+                half_width,
+                style.cap,
+            );
+            contour.extend(right);
+            cap(
+                &mut contour,
+                first.start(),
+                -unit_tangent(first, 0.0),
+                contour_start,
+                half_width,
+                style.cap,
+            );
+
+            append_chain(&mut path, &contour, true);
+        }
+
+        path
+    }
+
+    fn subpaths(&self) -> Vec<Subpath> {
+        let mut subpaths = Vec::new();
+        let mut current = Subpath {
+            segments: Vec::new(),
+            closed: false,
+        };
+
+        let mut segments = self.segments();
+
+        for element in &self.elements {
+            if !matches!(element, PathElement::MoveTo(_)) {
+                if let Some(segment) = segments
+                    .next()
+                    .filter(|segment| !is_degenerate(segment))
+                {
+                    current.segments.push(segment);
+                }
+
+                if *element != PathElement::Close {
+                    continue;
+                }
+
+                current.closed = true;
+            }
+
+            subpaths.push(std::mem::replace(
+                &mut current,
+                Subpath {
+                    segments: Vec::new(),
+                    closed: false,
+                },
+            ));
+        }
+
+        if !current.segments.is_empty() {
+            subpaths.push(current);
+        }
+
+        subpaths.retain(|subpath| !subpath.segments.is_empty());
+        subpaths
+    }
+}
+
+fn append_chain(
+    path: &mut Path,
+    chain: &[PathSegment],
+    closed: bool,
+) {
+    let Some(first) = chain.first() else {
+        return;
+    };
+
+    path.move_to(first.start());
+
+    // A trailing line back to the start is drawn by `close`.
+    let chain = match chain.split_last() {
+        Some((PathSegment::Line(_, end), rest))
+            if closed && *end == first.start() =>
+        {
+            rest
+        }
+        _ => chain,
+    };
+
+    for segment in chain {
+        match *segment {
+            PathSegment::Line(_, p1) => {
+                path.line_to(p1);
+            }
+            PathSegment::Quad(_, p1, p2) => {
+                path.quad_to(p1, p2);
+            }
+            PathSegment::Cubic(_, p1, p2, p3) => {
+                path.cubic_to(p1, p2, p3);
+            }
+        }
+    }
+
+    if closed {
+        path.close();
+    }
+}
+
+/// Offsets consecutive segments and connects the pieces with
+/// joins.
+fn offset_chain(
+    segments: &[PathSegment],
+    closed: bool,
+    distance: f32,
+    join: LineJoin,
+    tolerance: f32,
+) -> Vec<PathSegment> {
+    let mut chain: Vec<PathSegment> = Vec::new();
+    let mut previous: Option<&PathSegment> = None;
+    // Pieces of the first segment, joined last on closed paths.
+    let mut head_len = 0;
+
+    for segment in segments {
+        let mut pieces = Vec::new();
+        offset_segment(
+            segment,
+            distance,
+            tolerance,
+            &mut pieces,
+        );
+
+        if pieces.is_empty() {
+            continue;
+        }
+
+        if let Some(previous) = previous {
+            connect(
+                &mut chain,
+                &mut pieces,
+                previous,
+                segment,
+                distance,
+                join,
+            );
+        }
+
+        if previous.is_none() {
+            head_len = pieces.len();
+        }
+
+        chain.extend(pieces);
+        previous = Some(segment);
+    }
+
+    if let Some((last, first)) = previous
+        .zip(segments.first())
+        .filter(|_| closed && chain.len() > head_len)
+    {
+        let mut head = chain.drain(..head_len).collect();
+
+        connect(
+            &mut chain, &mut head, last, first, distance, join,
+        );
+
+        // The first pieces may have been trimmed.
+        chain.splice(0..0, head);
+    }
+
+    chain
+}
+
+/// Joins the end of `chain` with the start of `next`, trimming
+/// them at their intersection on the inner side of a turn.
+fn connect(
+    chain: &mut Vec<PathSegment>,
+    next: &mut Vec<PathSegment>,
+    incoming: &PathSegment,
+    outgoing: &PathSegment,
+    distance: f32,
+    join: LineJoin,
+) {
+    let (Some(&last), Some(&first)) =
+        (chain.last(), next.first())
+    else {
+        return;
+    };
+
+    let from = last.end();
+    let to = first.start();
+
+    if from.distance(to) <= 1e-5 {
+        return;
+    }
+
+    let vertex = incoming.end();
+    let tangent_in = unit_tangent(incoming, 1.0);
+    let tangent_out = unit_tangent(outgoing, 0.0);
+    let turn = tangent_in.cross(tangent_out);
+
+    // Inner side of the turn: the offset pieces overlap.
+    if turn * distance < 0.0 {
+        if trim_crossing(chain, next) {
+            return;
+        }
+
+        chain.push(PathSegment::Line(from, vertex));
+        chain.push(PathSegment::Line(vertex, to));
+        return;
+    }
+
+    match join {
+        LineJoin::Bevel => {
+            chain.push(PathSegment::Line(from, to));
+        }
+        LineJoin::Miter(limit) => {
+            let denominator = tangent_in.cross(tangent_out);
+
+            if denominator.abs() > 1e-6 {
+                // Intersection of the two offset tangent lines.
+                let s = (to - from).cross(tangent_out)
+                    / denominator;
+                let miter = from + tangent_in * s;
+
+                if miter.distance(vertex)
+                    <= limit * distance.abs()
+                {
+                    chain.push(PathSegment::Line(from, miter));
+                    chain.push(PathSegment::Line(miter, to));
+                    return;
+                }
+            }
+
+            chain.push(PathSegment::Line(from, to));
+        }
+        LineJoin::Round => {
+            let u = from - vertex;
+            let v = to - vertex;
+
+            append_arc(
+                chain,
+                vertex,
+                distance.abs(),
+                u.atan2(),
+                u.cross(v).atan2(u.dot(v)),
+            );
+        }
+    }
+}
+
+fn cap(
+    chain: &mut Vec<PathSegment>,
+    point: Vec2,
+    tangent: Vec2,
+    to: Option<Vec2>,
+    half_width: f32,
+    cap: LineCap,
+) {
+    let (Some(from), Some(to)) =
+        (chain.last().map(PathSegment::end), to)
+    else {
+        return;
+    };
+
+    match cap {
+        LineCap::Butt => {
+            chain.push(PathSegment::Line(from, to));
+        }
+        LineCap::Square => {
+            let extension = tangent * half_width;
+            chain.push(PathSegment::Line(
+                from,
+                from + extension,
+            ));
+            chain.push(PathSegment::Line(
+                from + extension,
+                to + extension,
+            ));
+            chain.push(PathSegment::Line(to + extension, to));
+        }
+        LineCap::Round => {
+            let u = from - point;
+            // Sweep through the point ahead of the end.
+            let sweep =
+                if u.cross(tangent) >= 0.0 { PI } else { -PI };
+
+            append_arc(
+                chain,
+                point,
+                half_width,
+                u.atan2(),
+                sweep,
+            );
+        }
+    }
+}
+
+fn append_arc(
+    chain: &mut Vec<PathSegment>,
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+    sweep_angle: f32,
+) {
+    let arc = Arc {
+        center,
+        radii: Vec2::splat(radius),
+        x_axis_rotation: 0.0,
+        start_angle,
+        sweep_angle,
+    };
+
+    let mut path = Path::new();
+    path.move_to(arc.start());
+    arc.append_cubics(&mut path);
+
+    chain.extend(path.segments());
+}
+
+fn offset_segment(
+    segment: &PathSegment,
+    distance: f32,
+    tolerance: f32,
+    output: &mut Vec<PathSegment>,
+) {
+    if let PathSegment::Line(p0, p1) = *segment {
+        let normal = normal(unit_tangent(segment, 0.0));
+        output.push(PathSegment::Line(
+            p0 + normal * distance,
+            p1 + normal * distance,
+        ));
+        return;
+    }
+
+    let cubic = segment.to_cubic();
+
+    for (t0, t1) in valid_ranges(&cubic, distance) {
+        let mut pieces = Vec::new();
+        approximate_offset(
+            &cubic.subsegment(t0, t1),
+            distance,
+            tolerance,
+            0,
+            &mut pieces,
+        );
+
+        // The pieces around a removed loop usually cross each
+        // other; otherwise the gap is bridged with a line.
+        trim_crossing(output, &mut pieces);
+
+        output.extend(pieces);
+    }
+}
+
+/// Parameter ranges where the offset curve keeps the direction
+/// of the source curve. Elsewhere the offset radius exceeds the
+/// radius of curvature, producing cusps and a loop that would
+/// have to be removed anyway.
+fn valid_ranges(
+    segment: &PathSegment,
+    distance: f32,
+) -> Vec<(f32, f32)> {
+    const SAMPLES: usize = 32;
+    const BISECTION_ITERATIONS: usize = 24;
+
+    let speed = |t: f32| 1.0 + curvature(segment, t) * distance;
+
+    let mut ranges = Vec::new();
+    let mut start = (speed(0.0) > 0.0).then_some(0.0);
+    let mut previous_t = 0.0;
+
+    for index in 1..=SAMPLES {
+        let t = index as f32 / SAMPLES as f32;
+        let inside = speed(t) > 0.0;
+
+        if inside == start.is_some() {
+            previous_t = t;
+            continue;
+        }
+
+        // Refine the boundary between previous_t and t.
+        let (mut low, mut high) = (previous_t, t);
+
+        for _ in 0..BISECTION_ITERATIONS {
+            let mid = (low + high) * 0.5;
+
+            if (speed(mid) > 0.0) == inside {
+                high = mid;
+            } else {
+                low = mid;
+            }
+        }
+
+        match start.take() {
+            Some(start) => ranges.push((start, low)),
+            None => start = Some(high),
+        }
+
+        previous_t = t;
+    }
+
+    if let Some(start) = start {
+        ranges.push((start, 1.0));
+    }
+
+    ranges.retain(|(t0, t1)| t1 - t0 > 1e-4);
+    ranges
+}
+
+fn approximate_offset(
+    segment: &PathSegment,
+    distance: f32,
+    tolerance: f32,
+    depth: u32,
+    output: &mut Vec<PathSegment>,
+) {
+    const MAX_DEPTH: u32 = 10;
+
+    let PathSegment::Cubic(p0, p1, p2, p3) = *segment else {
+        return;
+    };
+
+    // Tiller–Hanson style: move the end points along their
+    // normals and scale the handles by the offset curve's speed.
+    let start =
+        p0 + normal(unit_tangent(segment, 0.0)) * distance;
+    let end =
+        p3 + normal(unit_tangent(segment, 1.0)) * distance;
+    let start_scale = 1.0 + curvature(segment, 0.0) * distance;
+    let end_scale = 1.0 + curvature(segment, 1.0) * distance;
+
+    let candidate = PathSegment::Cubic(
+        start,
+        start + (p1 - p0) * start_scale,
+        end + (p2 - p3) * end_scale,
+        end,
+    );
+
+    let error = [0.25, 0.5, 0.75]
+        .into_iter()
+        .map(|t| {
+            let exact = segment.eval(t)
+                + normal(unit_tangent(segment, t)) * distance;
+            let (_, nearest) = candidate.nearest(exact);
+            nearest.distance(exact)
+        })
+        .fold(0.0, f32::max);
+
+    if error <= tolerance || depth >= MAX_DEPTH {
+        output.push(candidate);
+        return;
+    }
+
+    let (head, tail) = segment.split(0.5);
+    approximate_offset(
+        &head,
+        distance,
+        tolerance,
+        depth + 1,
+        output,
+    );
+    approximate_offset(
+        &tail,
+        distance,
+        tolerance,
+        depth + 1,
+        output,
+    );
+}
+
+/// Unit tangent, looking slightly inwards when the derivative
+/// vanishes at a coincident control point.
+fn unit_tangent(segment: &PathSegment, t: f32) -> Vec2 {
+    let tangent = segment.derivative(t).normalize_or_zero();
+
+    if tangent != Vec2::ZERO {
+        return tangent;
+    }
+
+    let nudged = t + if t < 0.5 { 1e-3 } else { -1e-3 };
+    let tangent =
+        segment.derivative(nudged).normalize_or_zero();
+
+    if tangent != Vec2::ZERO {
+        return tangent;
+    }
+
+    (segment.end() - segment.start()).normalize_or_zero()
+}
+
+/// Left-hand normal on screen (y pointing down).
+#[inline]
+fn normal(tangent: Vec2) -> Vec2 {
+    Vec2::new(tangent.y, -tangent.x)
+}
+
+/// Signed curvature, positive when turning towards the
+/// right-hand side on screen.
+fn curvature(segment: &PathSegment, t: f32) -> f32 {
+    let d1 = segment.derivative(t);
+    let d2 = segment.second_derivative(t);
+    let speed = d1.length();
+
+    if speed <= 1e-6 {
+        return 0.0;
+    }
+
+    d1.cross(d2) / (speed * speed * speed)
+}
+
+fn reverse_segment(segment: &PathSegment) -> PathSegment {
+    match *segment {
+        PathSegment::Line(p0, p1) => PathSegment::Line(p1, p0),
+        PathSegment::Quad(p0, p1, p2) => {
+            PathSegment::Quad(p2, p1, p0)
+        }
+        PathSegment::Cubic(p0, p1, p2, p3) => {
+            PathSegment::Cubic(p3, p2, p1, p0)
+        }
+    }
+}
+
+/// Cuts the end of `head` and the start of `tail` back to
+/// their crossing closest to where they meet, so both share
+/// the exact same point.
+fn trim_crossing(
+    head: &mut Vec<PathSegment>,
+    tail: &mut Vec<PathSegment>,
+) -> bool {
+    // Pieces searched on either side of the junction.
+    const WINDOW: usize = 8;
+
+    for i in
+        (head.len().saturating_sub(WINDOW)..head.len()).rev()
+    {
+        for j in 0..tail.len().min(WINDOW) {
+            let Some((ta, tb)) = intersect(&head[i], &tail[j])
+            else {
+                continue;
+            };
+
+            head.truncate(i + 1);
+            head[i] = head[i].subsegment(0.0, ta);
+
+            tail.drain(..j);
+            tail[0] = with_start(
+                &tail[0].subsegment(tb, 1.0),
+                head[i].end(),
+            );
+
+            return true;
+        }
+    }
+
+    false
+}
+
+fn with_start(
+    segment: &PathSegment,
+    start: Vec2,
+) -> PathSegment {
+    match *segment {
+        PathSegment::Line(_, p1) => {
+            PathSegment::Line(start, p1)
+        }
+        PathSegment::Quad(_, p1, p2) => {
+            PathSegment::Quad(start, p1, p2)
+        }
+        PathSegment::Cubic(_, p1, p2, p3) => {
+            PathSegment::Cubic(start, p1, p2, p3)
+        }
+    }
+}
+
+/// Segments shorter than this have no usable tangent, as with
+/// the closing line of a circle that ends a rounding error
+/// away from its start.
+fn is_degenerate(segment: &PathSegment) -> bool {
+    const EPSILON: f32 = 1e-4;
+
+    let start = segment.start();
+    let points = match *segment {
+        PathSegment::Line(_, p1) => [p1, p1, p1],
+        PathSegment::Quad(_, p1, p2) => [p1, p2, p2],
+        PathSegment::Cubic(_, p1, p2, p3) => [p1, p2, p3],
+    };
+
+    points.iter().all(|point| point.distance(start) <= EPSILON)
+}
+
+/// Shoelace area using the control polygon, which has the same
+/// sign as the exact area for well-formed contours.
+fn signed_area(segments: &[PathSegment]) -> f32 {
+    segments
+        .iter()
+        .map(|segment| {
+            let points: &[Vec2] = &match *segment {
+                PathSegment::Line(p0, p1) => vec![p0, p1],
+                PathSegment::Quad(p0, p1, p2) => {
+                    vec![p0, p1, p2]
+                }
+                PathSegment::Cubic(p0, p1, p2, p3) => {
+                    vec![p0, p1, p2, p3]
+                }
+            };
+
+            points
+                .windows(2)
+                .map(|pair| pair[0].cross(pair[1]))
+                .sum::<f32>()
+        })
+        .sum::<f32>()
+        * 0.5
+}
+
+/// Intersection closest to the end of `a` and the start of `b`,
+/// found by recursive bounding box subdivision.
+fn intersect(
+    a: &PathSegment,
+    b: &PathSegment,
+) -> Option<(f32, f32)> {
+    const MAX_DEPTH: u32 = 24;
+    const EPSILON: f32 = 1e-4;
+
+    fn overlaps(a: Rect, b: Rect) -> bool {
+        a.min.x <= b.max.x + EPSILON
+            && b.min.x <= a.max.x + EPSILON
+            && a.min.y <= b.max.y + EPSILON
+            && b.min.y <= a.max.y + EPSILON
+    }
+
+    fn search(
+        a: &PathSegment,
+        (a0, a1): (f32, f32),
+        b: &PathSegment,
+        (b0, b1): (f32, f32),
+        depth: u32,
+    ) -> Option<(f32, f32)> {
+        let sub_a = a.subsegment(a0, a1);
+        let sub_b = b.subsegment(b0, b1);
+        let bounds_a = sub_a.bounds();
+        let bounds_b = sub_b.bounds();
+
+        if !overlaps(bounds_a, bounds_b) {
+            return None;
+        }
+
+        let small = |bounds: Rect| {
+            bounds.width().max(bounds.height()) < EPSILON
+        };
+
+        if depth >= MAX_DEPTH
+            || (small(bounds_a) && small(bounds_b))
+        {
+            return Some(((a0 + a1) * 0.5, (b0 + b1) * 0.5));
+        }
+
+        let am = (a0 + a1) * 0.5;
+        let bm = (b0 + b1) * 0.5;
+
+        // Prefer the end of `a` and the start of `b`.
+        [
+            ((am, a1), (b0, bm)),
+            ((am, a1), (bm, b1)),
+            ((a0, am), (b0, bm)),
+            ((a0, am), (bm, b1)),
+        ]
+        .into_iter()
+        .find_map(|(range_a, range_b)| {
+            search(a, range_a, b, range_b, depth + 1)
+        })
+    }
+
+    let hit = match (*a, *b) {
+        (
+            PathSegment::Line(a0, a1),
+            PathSegment::Line(b0, b1),
+        ) => {
+            let direction_a = a1 - a0;
+            let direction_b = b1 - b0;
+            let denominator = direction_a.cross(direction_b);
+
+            if denominator.abs() <= f32::EPSILON {
+                return None;
+            }
+
+            let offset = b0 - a0;
+            let ta = offset.cross(direction_b) / denominator;
+            let tb = offset.cross(direction_a) / denominator;
+
+            ((0.0..=1.0).contains(&ta)
+                && (0.0..=1.0).contains(&tb))
+            .then_some((ta, tb))
+        }
+        _ => search(a, (0.0, 1.0), b, (0.0, 1.0), 0),
+    };
+
+    hit.filter(|&(ta, tb)| {
+        // Touching end points aren't a crossing.
+        ta < 1.0 - EPSILON || tb > EPSILON
+    })
+}
+
+#[test]
+fn test_offset_rect_joins() {
+    use crate::Size;
+
+    let square =
+        Path::rect(Rect::new(Vec2::ZERO, Size::square(10.0)));
+
+    let outset = square.offset(2.0, LineJoin::default(), 0.01);
+    let bounds = outset.bounds().unwrap();
+    assert!(bounds.min.distance(Vec2::splat(-2.0)) < 1e-4);
+    assert!(bounds.max.distance(Vec2::splat(12.0)) < 1e-4);
+
+    let beveled = square.offset(2.0, LineJoin::Bevel, 0.01);
+    assert!(
+        !beveled.contains(
+            Vec2::splat(-1.9),
+            super::FillRule::NonZero
+        )
+    );
+
+    let rounded = square.offset(2.0, LineJoin::Round, 0.01);
+    let corner = Vec2::splat(-2.0f32.sqrt());
+    assert!(rounded.hit_stroke(corner, 1e-3).is_some());
+
+    // Insets trim the overlapping inner corners.
+    let inset = square.offset(-2.0, LineJoin::default(), 0.01);
+    assert_eq!(inset.segments().count(), 4);
+    let bounds = inset.bounds().unwrap();
+    assert!(bounds.min.distance(Vec2::splat(2.0)) < 1e-4);
+    assert!(bounds.max.distance(Vec2::splat(8.0)) < 1e-4);
+
+    // Reversed winding still grows outwards.
+    let reversed = square.transform(crate::Mat3::scale_x(-1.0));
+    let bounds = reversed
+        .offset(2.0, LineJoin::default(), 0.01)
+        .bounds()
+        .unwrap();
+    assert!((bounds.width() - 14.0).abs() < 1e-4);
+}
+
+#[test]
+fn test_offset_curve_within_tolerance() {
+    let circle = Path::circle(Vec2::ZERO, 10.0);
+
+    for distance in [3.0, -3.0, -12.0] {
+        let offset =
+            circle.offset(distance, LineJoin::Round, 0.01);
+
+        if distance < -10.0 {
+            // Fully inverted: nothing is left.
+            assert!(offset.is_empty());
+            continue;
+        }
+
+        for segment in offset.segments() {
+            for step in 0..=8 {
+                let radius =
+                    segment.eval(step as f32 / 8.0).length();
+                assert!(
+                    (radius - (10.0 + distance)).abs() < 0.02
+                );
+            }
+        }
+    }
+
+    // Offsetting the inside of a tight bend by more than its
+    // radius of curvature removes the loop instead of keeping it.
+    let mut bend = Path::new();
+    bend.move_to(Vec2::ZERO).cubic_to(
+        Vec2::new(30.0, 0.0),
+        Vec2::new(30.0, 10.0),
+        Vec2::new(0.0, 10.0),
+    );
+    let source = bend.segments().next().unwrap();
+
+    for distance in [-3.0, 3.0] {
+        let offset =
+            bend.offset(distance, LineJoin::Round, 0.01);
+
+        for segment in offset.segments() {
+            for step in 0..=8 {
+                let point = segment.eval(step as f32 / 8.0);
+                let (_, nearest) = source.nearest(point);
+                assert!(nearest.distance(point) > 2.95);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_outline_stroke() {
+    let mut line = Path::new();
+    line.move_to(Vec2::ZERO).line_to(Vec2::new(10.0, 0.0));
+
+    let style = StrokeStyle {
+        width: 4.0,
+        cap: LineCap::Square,
+        ..Default::default()
+    };
+
+    let outline = line.outline_stroke(&style);
+    let bounds = outline.bounds().unwrap();
+
+    assert!(bounds.min.distance(Vec2::new(-2.0, -2.0)) < 1e-4);
+    assert!(bounds.max.distance(Vec2::new(12.0, 2.0)) < 1e-4);
+    assert!(outline.contains(
+        Vec2::new(5.0, 1.5),
+        super::FillRule::NonZero
+    ));
+
+    let round = line.outline_stroke(&StrokeStyle {
+        cap: LineCap::Round,
+        ..style
+    });
+    assert!(round.contains(
+        Vec2::new(11.5, 0.0),
+        super::FillRule::NonZero
+    ));
+    assert!(!round.contains(
+        Vec2::new(11.5, 1.8),
+        super::FillRule::NonZero
+    ));
+
+    let ring =
+        Path::circle(Vec2::ZERO, 10.0).outline_stroke(&style);
+    assert!(ring.contains(
+        Vec2::new(10.0, 0.0),
+        super::FillRule::NonZero
+    ));
+    assert!(
+        !ring.contains(Vec2::ZERO, super::FillRule::NonZero)
+    );
+}
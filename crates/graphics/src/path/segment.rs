@@ -0,0 +1,357 @@
+use crate::{Rect, Vec2};
+
+/// Single drawable piece of a path with explicit start point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PathSegment {
+    Line(Vec2, Vec2),
+    Quad(Vec2, Vec2, Vec2),
+    Cubic(Vec2, Vec2, Vec2, Vec2),
+}
+
+impl PathSegment {
+    #[inline]
+    pub const fn start(&self) -> Vec2 {
+        match *self {
+            Self::Line(p0, _)
+            | Self::Quad(p0, _, _)
+            | Self::Cubic(p0, _, _, _) => p0,
+        }
+    }
+
+    #[inline]
+    pub const fn end(&self) -> Vec2 {
+        match *self {
+            Self::Line(_, p1) => p1,
+            Self::Quad(_, _, p2) => p2,
+            Self::Cubic(_, _, _, p3) => p3,
+        }
+    }
+
+    /// Point at parameter `t` in `0.0..=1.0`.
+    #[inline]
+    pub fn eval(&self, t: f32) -> Vec2 {
+        let mt = 1.0 - t;
+
+        match *self {
+            Self::Line(p0, p1) => p0.lerp(p1, t),
+            Self::Quad(p0, p1, p2) => {
+                p0 * (mt * mt)
+                    + p1 * (2.0 * mt * t)
+                    + p2 * (t * t)
+            }
+            Self::Cubic(p0, p1, p2, p3) => {
+                p0 * (mt * mt * mt)
+                    + p1 * (3.0 * mt * mt * t)
+                    + p2 * (3.0 * mt * t * t)
+                    + p3 * (t * t * t)
+            }
+        }
+    }
+
+    /// First derivative with respect to `t`.
+    #[inline]
+    pub fn derivative(&self, t: f32) -> Vec2 {
+        let mt = 1.0 - t;
+
+        match *self {
+            Self::Line(p0, p1) => p1 - p0,
+            Self::Quad(p0, p1, p2) => {
+                ((p1 - p0) * mt + (p2 - p1) * t) * 2.0
+            }
+            Self::Cubic(p0, p1, p2, p3) => {
+                ((p1 - p0) * (mt * mt)
+                    + (p2 - p1) * (2.0 * mt * t)
+                    + (p3 - p2) * (t * t))
+                    * 3.0
+            }
+        }
+    }
+
+    /// Second derivative with respect to `t`.
+    #[inline]
+    pub fn second_derivative(&self, t: f32) -> Vec2 {
+        match *self {
+            Self::Line(..) => Vec2::ZERO,
+            Self::Quad(p0, p1, p2) => {
+                (p2 - p1 * 2.0 + p0) * 2.0
+            }
+            Self::Cubic(p0, p1, p2, p3) => {
+                ((p2 - p1 * 2.0 + p0) * (1.0 - t)
+                    + (p3 - p2 * 2.0 + p1) * t)
+                    * 6.0
+            }
+        }
+    }
+
+    /// Degree-elevated cubic equivalent of this segment.
+    #[inline]
+    pub fn to_cubic(&self) -> Self {
+        match *self {
+            Self::Line(p0, p1) => Self::Cubic(
+                p0,
+                p0.lerp(p1, 1.0 / 3.0),
+                p0.lerp(p1, 2.0 / 3.0),
+                p1,
+            ),
+            Self::Quad(p0, p1, p2) => Self::Cubic(
+                p0,
+                p0.lerp(p1, 2.0 / 3.0),
+                p2.lerp(p1, 2.0 / 3.0),
+                p2,
+            ),
+            cubic @ Self::Cubic(..) => cubic,
+        }
+    }
+
+    /// Splits the segment at `t` using de Casteljau.
+    pub fn split(&self, t: f32) -> (Self, Self) {
+        match *self {
+            Self::Line(p0, p1) => {
+                let mid = p0.lerp(p1, t);
+                (Self::Line(p0, mid), Self::Line(mid, p1))
+            }
+            Self::Quad(p0, p1, p2) => {
+                let a = p0.lerp(p1, t);
+                let b = p1.lerp(p2, t);
+                let mid = a.lerp(b, t);
+                (Self::Quad(p0, a, mid), Self::Quad(mid, b, p2))
+            }
+            Self::Cubic(p0, p1, p2, p3) => {
+                let a = p0.lerp(p1, t);
+                let b = p1.lerp(p2, t);
+                let c = p2.lerp(p3, t);
+                let ab = a.lerp(b, t);
+                let bc = b.lerp(c, t);
+                let mid = ab.lerp(bc, t);
+                (
+                    Self::Cubic(p0, a, ab, mid),
+                    Self::Cubic(mid, bc, c, p3),
+                )
+            }
+        }
+    }
+
+    /// Part of the segment between `t0` and `t1`.
+    pub fn subsegment(&self, t0: f32, t1: f32) -> Self {
+        let (_, tail) = self.split(t0);
+
+        if t0 >= 1.0 {
+            return tail;
+        }
+
+        let (head, _) = tail.split((t1 - t0) / (1.0 - t0));
+        head
+    }
+
+    /// Parameters in `(0, 1)` where the derivative of the given
+    /// axis is zero.
+    fn extrema(&self, axis: impl Fn(Vec2) -> f32) -> Roots {
+        match *self {
+            Self::Line(..) => Roots::default(),
+            Self::Quad(p0, p1, p2) => {
+                let denominator =
+                    axis(p0) - 2.0 * axis(p1) + axis(p2);
+                let mut roots = Roots::default();
+
+                if denominator != 0.0 {
+                    roots.push(
+                        (axis(p0) - axis(p1)) / denominator,
+                    );
+                }
+
+                roots.open_unit()
+            }
+            Self::Cubic(p0, p1, p2, p3) => {
+                let (p0, p1, p2, p3) =
+                    (axis(p0), axis(p1), axis(p2), axis(p3));
+
+                solve_quadratic(
+                    -p0 + 3.0 * p1 - 3.0 * p2 + p3,
+                    2.0 * (p0 - 2.0 * p1 + p2),
+                    p1 - p0,
+                )
+                .open_unit()
+            }
+        }
+    }
+
+    /// Exact axis-aligned bounding box.
+    pub fn bounds(&self) -> Rect {
+        let mut bounds =
+            Rect::from_points(self.start(), self.end());
+
+        for t in self.extrema(|p| p.x).iter() {
+            bounds = bounds.include(self.eval(t));
+        }
+
+        for t in self.extrema(|p| p.y).iter() {
+            bounds = bounds.include(self.eval(t));
+        }
+
+        bounds
+    }
+
+    /// Splits the parameter range into intervals on which `y`
+    /// is monotonic.
+    pub(crate) fn y_monotonic_ranges(
+        &self,
+    ) -> ([f32; 4], usize) {
+        let mut ranges = [0.0, 1.0, 1.0, 1.0];
+        let mut count = 1;
+
+        let mut extrema = self.extrema(|p| p.y);
+        extrema.sort();
+
+        for t in extrema.iter() {
+            ranges[count] = t;
+            count += 1;
+        }
+
+        ranges[count] = 1.0;
+
+        (ranges, count)
+    }
+
+    /// Nearest point on the segment to `point` as `(t, point)`.
+    pub fn nearest(&self, point: Vec2) -> (f32, Vec2) {
+        if let Self::Line(p0, p1) = *self {
+            let direction = p1 - p0;
+            let length_sq = direction.length_sq();
+
+            let t = if length_sq > 0.0 {
+                ((point - p0).dot(direction) / length_sq)
+                    .clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+
+            return (t, p0.lerp(p1, t));
+        }
+
+        const SAMPLES: usize = 16;
+        const NEWTON_ITERATIONS: usize = 8;
+
+        let mut best_t = 0.0;
+        let mut best_distance = f32::INFINITY;
+
+        for index in 0..=SAMPLES {
+            let t = index as f32 / SAMPLES as f32;
+            let distance = (self.eval(t) - point).length_sq();
+
+            if distance < best_distance {
+                best_t = t;
+                best_distance = distance;
+            }
+        }
+
+        // Newton's method on f(t) = (B(t) - p) . B'(t)
+        let mut t = best_t;
+
+        for _ in 0..NEWTON_ITERATIONS {
+            let offset = self.eval(t) - point;
+            let d1 = self.derivative(t);
+            let d2 = self.second_derivative(t);
+
+            let numerator = offset.dot(d1);
+            let denominator = d1.dot(d1) + offset.dot(d2);
+
+            if denominator.abs() <= f32::EPSILON {
+                break;
+            }
+
+            let next =
+                (t - numerator / denominator).clamp(0.0, 1.0);
+
+            if (next - t).abs() < 1e-7 {
+                t = next;
+                break;
+            }
+
+            t = next;
+        }
+
+        if (self.eval(t) - point).length_sq() > best_distance {
+            t = best_t;
+        }
+
+        (t, self.eval(t))
+    }
+}
+
+/// Up to two real roots of a polynomial of degree two or less.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Roots {
+    values: [f32; 2],
+    len: usize,
+}
+
+impl Roots {
+    #[inline]
+    fn push(&mut self, value: f32) {
+        self.values[self.len] = value;
+        self.len += 1;
+    }
+
+    #[inline]
+    pub(crate) fn iter(
+        &self,
+    ) -> impl Iterator<Item = f32> + '_ {
+        self.values[..self.len].iter().copied()
+    }
+
+    #[inline]
+    fn sort(&mut self) {
+        if self.len == 2 && self.values[0] > self.values[1] {
+            self.values.swap(0, 1);
+        }
+    }
+
+    /// Keeps only roots strictly inside `(0, 1)`.
+    #[inline]
+    fn open_unit(self) -> Self {
+        let mut result = Self::default();
+
+        for value in self.iter() {
+            if value > 0.0 && value < 1.0 {
+                result.push(value);
+            }
+        }
+
+        result
+    }
+}
+
+/// Real roots of `a * t^2 + b * t + c = 0`.
+pub(crate) fn solve_quadratic(a: f32, b: f32, c: f32) -> Roots {
+    let mut roots = Roots::default();
+
+    if a.abs() <= 1e-12 {
+        if b != 0.0 {
+            roots.push(-c / b);
+        }
+
+        return roots;
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+
+    if discriminant < 0.0 {
+        return roots;
+    }
+
+    if discriminant == 0.0 {
+        roots.push(-b / (2.0 * a));
+        return roots;
+    }
+
+    // Numerically stable form avoiding cancellation.
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+
+    roots.push(q / a);
+
+    if q != 0.0 {
+        roots.push(c / q);
+    }
+
+    roots
+}
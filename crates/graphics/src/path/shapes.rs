@@ -0,0 +1,249 @@
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+use crate::{BorderRadius, Rect, Vec2};
+
+use super::{Arc, Path};
+
+impl Path {
+    pub fn rect(rect: Rect) -> Self {
+        let [top_left, top_right, bottom_right, bottom_left] =
+            rect.corners();
+
+        let mut path = Self::with_capacity(5);
+        path.move_to(top_left)
+            .line_to(top_right)
+            .line_to(bottom_right)
+            .line_to(bottom_left)
+            .close();
+        path
+    }
+
+    #[inline]
+    pub fn circle(center: Vec2, radius: f32) -> Self {
+        Self::ellipse(center, Vec2::splat(radius))
+    }
+
+    pub fn ellipse(center: Vec2, radii: Vec2) -> Self {
+        let mut path = Self::with_capacity(6);
+        path.arc(Arc {
+            center,
+            radii,
+            x_axis_rotation: 0.0,
+            start_angle: 0.0,
+            sweep_angle: TAU,
+        })
+        .close();
+        path
+    }
+
+    /// Polygon with `sides` vertices on a circle. With zero
+    /// `rotation` the first vertex points up.
+    pub fn regular_polygon(
+        center: Vec2,
+        radius: f32,
+        sides: u32,
+        rotation: f32,
+    ) -> Self {
+        let sides = sides.max(3);
+        let step = TAU / sides as f32;
+
+        Self::polygon((0..sides).map(|index| {
+            polar(
+                center,
+                radius,
+                rotation + step * index as f32,
+            )
+        }))
+    }
+
+    /// Star alternating between `outer_radius` and
+    /// `inner_radius`. With zero `rotation` the first point
+    /// points up.
+    pub fn star(
+        center: Vec2,
+        outer_radius: f32,
+        inner_radius: f32,
+        points: u32,
+        rotation: f32,
+    ) -> Self {
+        let points = points.max(2);
+        let step = PI / points as f32;
+
+        Self::polygon((0..points * 2).map(|index| {
+            let radius = if index % 2 == 0 {
+                outer_radius
+            } else {
+                inner_radius
+            };
+
+            polar(
+                center,
+                radius,
+                rotation + step * index as f32,
+            )
+        }))
+    }
+
+    /// Rounded rectangle with circular corners, matching the
+    /// `rounded_rectangle_color_fill` shader. Radii are clamped
+    /// to half of the shorter side so corners never overlap.
+    pub fn rounded_rect(
+        rect: Rect,
+        radius: BorderRadius,
+    ) -> Self {
+        let max_radius =
+            rect.width().min(rect.height()).max(0.0) * 0.5;
+        let radius = radius.clamp(0.0, max_radius);
+
+        let Rect { min, max } = rect;
+        let mut path = Self::with_capacity(10);
+
+        path.move_to(Vec2::new(min.x + radius.top_left, min.y))
+            .line_to(Vec2::new(
+                max.x - radius.top_right,
+                min.y,
+            ));
+        corner(
+            &mut path,
+            Vec2::new(
+                max.x - radius.top_right,
+                min.y + radius.top_right,
+            ),
+            radius.top_right,
+            -FRAC_PI_2,
+        );
+
+        path.line_to(Vec2::new(
+            max.x,
+            max.y - radius.bottom_right,
+        ));
+        corner(
+            &mut path,
+            Vec2::new(
+                max.x - radius.bottom_right,
+                max.y - radius.bottom_right,
+            ),
+            radius.bottom_right,
+            0.0,
+        );
+
+        path.line_to(Vec2::new(
+            min.x + radius.bottom_left,
+            max.y,
+        ));
+        corner(
+            &mut path,
+            Vec2::new(
+                min.x + radius.bottom_left,
+                max.y - radius.bottom_left,
+            ),
+            radius.bottom_left,
+            FRAC_PI_2,
+        );
+
+        path.line_to(Vec2::new(min.x, min.y + radius.top_left));
+        corner(
+            &mut path,
+            Vec2::new(
+                min.x + radius.top_left,
+                min.y + radius.top_left,
+            ),
+            radius.top_left,
+            PI,
+        );
+
+        path.close();
+        path
+    }
+
+    fn polygon(points: impl Iterator<Item = Vec2>) -> Self {
+        let mut path = Self::new();
+
+        for point in points {
+            if path.is_empty() {
+                path.move_to(point);
+            } else {
+                path.line_to(point);
+            }
+        }
+
+        path.close();
+        path
+    }
+}
+
+/// Clockwise quarter circle (in y-down space) starting at
+/// `start_angle`. Square corners are skipped.
+fn corner(
+    path: &mut Path,
+    center: Vec2,
+    radius: f32,
+    start_angle: f32,
+) {
+    if radius <= 0.0 {
+        return;
+    }
+
+    Arc {
+        center,
+        radii: Vec2::splat(radius),
+        x_axis_rotation: 0.0,
+        start_angle,
+        sweep_angle: FRAC_PI_2,
+    }
+    .append_cubics(path);
+}
+
+#[inline]
+fn polar(center: Vec2, radius: f32, angle: f32) -> Vec2 {
+    // Measured from straight up, clockwise in y-down space.
+    let (sin, cos) = angle.sin_cos();
+    center + Vec2::new(sin, -cos) * radius
+}
+
+#[test]
+fn test_shape_builders() {
+    use crate::{FillRule, Size};
+
+    let circle = Path::circle(Vec2::new(5.0, 5.0), 5.0);
+    let bounds = circle.bounds().unwrap();
+
+    assert!(bounds.min.distance(Vec2::ZERO) < 1e-4);
+    assert!(bounds.max.distance(Vec2::splat(10.0)) < 1e-4);
+
+    let hexagon =
+        Path::regular_polygon(Vec2::ZERO, 1.0, 6, 0.0);
+    assert_eq!(hexagon.segments().count(), 6);
+    assert!(hexagon.contains(Vec2::ZERO, FillRule::NonZero));
+
+    let star = Path::star(Vec2::ZERO, 2.0, 1.0, 5, 0.0);
+    assert_eq!(star.segments().count(), 10);
+    assert!(
+        star.contains(Vec2::new(0.0, -1.5), FillRule::NonZero)
+    );
+    assert!(
+        !star.contains(Vec2::new(0.6, -1.5), FillRule::NonZero)
+    );
+
+    let rect = Rect::new(Vec2::ZERO, Size::new(20.0, 10.0));
+    let rounded =
+        Path::rounded_rect(rect, BorderRadius::top(8.0));
+
+    // Radii are clamped to half of the height.
+    assert!(
+        !rounded
+            .contains(Vec2::new(0.5, 0.5), FillRule::NonZero)
+    );
+    assert!(
+        rounded
+            .contains(Vec2::new(0.5, 9.5), FillRule::NonZero)
+    );
+    assert!(
+        rounded
+            .contains(Vec2::new(5.0, 0.5), FillRule::NonZero)
+    );
+
+    let bounds = rounded.bounds().unwrap();
+    assert!(bounds.min.distance(rect.min) < 1e-4);
+    assert!(bounds.max.distance(rect.max) < 1e-4);
+}
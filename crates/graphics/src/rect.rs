@@ -0,0 +1,340 @@
+use std::fmt;
+
+use crate::{Mat3, Size, Vec2};
+
+/// Axis-aligned rectangle stored as its minimum and maximum
+/// corners.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rect {
+    pub min: Vec2,
+    pub max: Vec2,
+}
+
+impl Rect {
+    pub const ZERO: Self =
+        Self::from_min_max(Vec2::ZERO, Vec2::ZERO);
+
+    #[inline]
+    pub const fn new(origin: Vec2, size: Size) -> Self {
+        Self {
+            min: origin,
+            max: Vec2::new(
+                origin.x + size.width,
+                origin.y + size.height,
+            ),
+        }
+    }
+
+    #[inline]
+    pub const fn from_min_max(min: Vec2, max: Vec2) -> Self {
+        Self { min, max }
+    }
+
+    /// Smallest rectangle containing both points, regardless of
+    /// their order.
+    #[inline]
+    pub fn from_points(a: Vec2, b: Vec2) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    #[inline]
+    pub const fn from_size(size: Size) -> Self {
+        Self::new(Vec2::ZERO, size)
+    }
+
+    #[inline(always)]
+    pub const fn origin(&self) -> Vec2 {
+        self.min
+    }
+
+    #[inline]
+    pub const fn size(&self) -> Size {
+        Size::new(self.width(), self.height())
+    }
+
+    #[inline(always)]
+    pub const fn width(&self) -> f32 {
+        self.max.x - self.min.x
+    }
+
+    #[inline(always)]
+    pub const fn height(&self) -> f32 {
+        self.max.y - self.min.y
+    }
+
+    #[inline]
+    pub const fn area(&self) -> f32 {
+        self.width() * self.height()
+    }
+
+    #[inline]
+    pub fn center(&self) -> Vec2 {
+        self.min.lerp(self.max, 0.5)
+    }
+
+    /// Corners in the order top-left, top-right, bottom-right,
+    /// bottom-left.
+    #[inline]
+    pub const fn corners(&self) -> [Vec2; 4] {
+        [
+            self.min,
+            Vec2::new(self.max.x, self.min.y),
+            self.max,
+            Vec2::new(self.min.x, self.max.y),
+        ]
+    }
+
+    /// `true` when the rectangle encloses no area.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !(self.max.x > self.min.x && self.max.y > self.min.y)
+    }
+
+    #[inline]
+    pub fn is_finite(&self) -> bool {
+        self.min.is_finite() && self.max.is_finite()
+    }
+
+    #[inline]
+    pub fn is_nan(&self) -> bool {
+        self.min.is_nan() || self.max.is_nan()
+    }
+
+    /// Half-open containment: points on the max edges are
+    /// outside, so adjacent rectangles never share a point.
+    #[inline]
+    pub fn contains(&self, point: Vec2) -> bool {
+        point.x >= self.min.x
+            && point.y >= self.min.y
+            && point.x < self.max.x
+            && point.y < self.max.y
+    }
+
+    #[inline]
+    pub fn contains_rect(&self, other: Rect) -> bool {
+        other.min.x >= self.min.x
+            && other.min.y >= self.min.y
+            && other.max.x <= self.max.x
+            && other.max.y <= self.max.y
+    }
+
+    #[inline]
+    pub fn intersects(&self, other: Rect) -> bool {
+        self.min.x < other.max.x
+            && other.min.x < self.max.x
+            && self.min.y < other.max.y
+            && other.min.y < self.max.y
+    }
+
+    /// Overlapping region, or `None` if the rectangles don't
+    /// overlap.
+    #[inline]
+    pub fn intersection(&self, other: Rect) -> Option<Rect> {
+        let result = Self {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
+        };
+
+        if result.is_empty() {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
+    /// Smallest rectangle containing both rectangles. Empty
+    /// rectangles are ignored.
+    #[inline]
+    pub fn union(&self, other: Rect) -> Rect {
+        if self.is_empty() {
+            return other;
+        }
+
+        if other.is_empty() {
+            return *self;
+        }
+
+        Self {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
+        }
+    }
+
+    /// Grows the rectangle to include `point`.
+    #[inline]
+    pub fn include(&self, point: Vec2) -> Rect {
+        Self {
+            min: self.min.min(point),
+            max: self.max.max(point),
+        }
+    }
+
+    #[inline]
+    pub fn translate(&self, offset: Vec2) -> Rect {
+        Self {
+            min: self.min + offset,
+            max: self.max + offset,
+        }
+    }
+
+    /// Moves every edge outwards by `amount`.
+    #[inline]
+    pub fn inflate(&self, amount: Vec2) -> Rect {
+        Self {
+            min: self.min - amount,
+            max: self.max + amount,
+        }
+    }
+
+    /// Moves every edge inwards by `amount`. The result is
+    /// collapsed to its center instead of turning inside out.
+    #[inline]
+    pub fn deflate(&self, amount: Vec2) -> Rect {
+        let center = self.center();
+
+        Self {
+            min: (self.min + amount).min(center),
+            max: (self.max - amount).max(center),
+        }
+    }
+
+    /// Snaps both corners to the nearest pixel.
+    #[inline]
+    pub fn round(&self) -> Rect {
+        Self {
+            min: self.min.round(),
+            max: self.max.round(),
+        }
+    }
+
+    /// Smallest pixel-aligned rectangle containing `self`.
+    #[inline]
+    pub fn round_out(&self) -> Rect {
+        Self {
+            min: self.min.floor(),
+            max: self.max.ceil(),
+        }
+    }
+
+    /// Largest pixel-aligned rectangle contained in `self`.
+    #[inline]
+    pub fn round_in(&self) -> Rect {
+        let rounded = Self {
+            min: self.min.ceil(),
+            max: self.max.floor(),
+        };
+
+        Self {
+            min: rounded.min,
+            max: rounded.max.max(rounded.min),
+        }
+    }
+
+    /// Bounding box of the rectangle after applying `matrix`.
+    #[inline]
+    pub fn transform(&self, matrix: Mat3) -> Rect {
+        let [a, b, c, d] = self
+            .corners()
+            .map(|corner| matrix.transform(corner));
+
+        Self {
+            min: a.min(b).min(c).min(d),
+            max: a.max(b).max(c).max(d),
+        }
+    }
+
+    #[inline]
+    pub fn validate(self) -> logging::Result<Self> {
+        self.size().validate()?;
+
+        Ok(self)
+    }
+}
+
+impl fmt::Display for Rect {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Rect")
+            .field(&self.min)
+            .field(&self.max)
+            .finish()
+    }
+}
+
+impl From<Size> for Rect {
+    #[inline(always)]
+    fn from(size: Size) -> Self {
+        Self::from_size(size)
+    }
+}
+
+#[test]
+fn test_rect_set_operations() {
+    let a =
+        Rect::new(Vec2::new(0.0, 0.0), Size::new(10.0, 10.0));
+    let b =
+        Rect::new(Vec2::new(5.0, 5.0), Size::new(10.0, 10.0));
+    let c =
+        Rect::new(Vec2::new(20.0, 0.0), Size::new(1.0, 1.0));
+
+    assert_eq!(
+        a.intersection(b),
+        Some(Rect::from_min_max(
+            Vec2::new(5.0, 5.0),
+            Vec2::new(10.0, 10.0)
+        ))
+    );
+    assert_eq!(a.intersection(c), None);
+    assert_eq!(
+        a.union(b),
+        Rect::from_min_max(Vec2::ZERO, Vec2::new(15.0, 15.0))
+    );
+    assert_eq!(a.union(Rect::ZERO), a);
+
+    assert!(a.contains(Vec2::new(0.0, 9.5)));
+    assert!(!a.contains(Vec2::new(10.0, 5.0)));
+    assert!(a.inflate(Vec2::splat(5.0)).contains_rect(b));
+    assert_eq!(
+        a.deflate(Vec2::splat(20.0)),
+        Rect::from_min_max(Vec2::splat(5.0), Vec2::splat(5.0))
+    );
+}
+
+#[test]
+fn test_rect_rounding_and_transform() {
+    let rect = Rect::from_min_max(
+        Vec2::new(0.4, 0.6),
+        Vec2::new(9.5, 9.4),
+    );
+
+    assert_eq!(
+        rect.round_out(),
+        Rect::from_min_max(Vec2::ZERO, Vec2::splat(10.0))
+    );
+    assert_eq!(
+        rect.round_in(),
+        Rect::from_min_max(Vec2::splat(1.0), Vec2::splat(9.0))
+    );
+
+    let rotated = Rect::from_size(Size::square(2.0)).transform(
+        Mat3::rotation_z(std::f32::consts::FRAC_PI_4),
+    );
+
+    assert!((rotated.width() - 8.0f32.sqrt()).abs() < 1e-5);
+    assert!((rotated.center().x).abs() < 1e-5);
+
+    assert!(
+        Rect::from_min_max(Vec2::splat(1.0), Vec2::ZERO)
+            .validate()
+            .is_err()
+    );
+    assert!(
+        Rect::from_size(Size::square(Size::MAX.width + 1.0))
+            .validate()
+            .is_err()
+    );
+}
@@ -0,0 +1,125 @@
+//! Shader registry generated by the build script: a module per
+//! stage with an id per entry point, its embedded SPIR-V and
+//! what Slang reflected about its bindings and inputs.
+#![allow(dead_code)]
+
+use ash::vk;
+
+include!(concat!(env!("OUT_DIR"), "/shaders.rs"));
+
+impl<K> ShaderSource<K> {
+    #[inline]
+    pub(crate) fn create_info(
+        &self,
+    ) -> vk::ShaderModuleCreateInfo<'static> {
+        vk::ShaderModuleCreateInfo::default().code(self.code)
+    }
+
+    #[inline]
+    pub(crate) fn stage(&self) -> vk::ShaderStageFlags {
+        vk::ShaderStageFlags::from_raw(self.stage_flags)
+    }
+
+    /// Push constants of the entry point, if it has any.
+    pub(crate) fn push_constant_range(
+        &self,
+    ) -> Option<vk::PushConstantRange> {
+        (self.push_constant_size > 0).then(|| {
+            vk::PushConstantRange {
+                stage_flags: self.stage(),
+                offset: 0,
+                size: self.push_constant_size,
+            }
+        })
+    }
+
+    /// Vertex input state for reading the inputs of a vertex
+    /// shader from one vertex buffer at `binding`, interleaved in
+    /// location order without padding.
+    pub(crate) fn vertex_input(
+        &self,
+        binding: u32,
+    ) -> (
+        vk::VertexInputBindingDescription,
+        Vec<vk::VertexInputAttributeDescription>,
+    ) {
+        let mut stride = 0;
+
+        let attributes = self
+            .vertex_inputs
+            .iter()
+            .map(|input| {
+                let attribute =
+                    vk::VertexInputAttributeDescription {
+                        location: input.location,
+                        binding,
+                        format: vk::Format::from_raw(
+                            input.format,
+                        ),
+                        offset: stride,
+                    };
+
+                stride += input.size;
+                attribute
+            })
+            .collect();
+
+        let binding = vk::VertexInputBindingDescription {
+            binding,
+            stride,
+            input_rate: vk::VertexInputRate::VERTEX,
+        };
+
+        (binding, attributes)
+    }
+}
+
+/// Bindings of the descriptor set layouts of a pipeline, one list
+/// per set, from the bindings of each of its stages. A binding
+/// used by several stages is visible to all of them.
+///
+/// Unsized arrays have a count of `0`, which the caller replaces
+/// along with `VARIABLE_DESCRIPTOR_COUNT`.
+pub(crate) fn descriptor_set_layout_bindings<'a>(
+    stages: impl IntoIterator<
+        Item = (vk::ShaderStageFlags, &'a [DescriptorBinding]),
+    >,
+) -> Vec<Vec<vk::DescriptorSetLayoutBinding<'static>>> {
+    let mut sets =
+        Vec::<Vec<vk::DescriptorSetLayoutBinding>>::new();
+
+    for (stage, bindings) in stages {
+        for binding in bindings {
+            let set = binding.set as usize;
+
+            if sets.len() <= set {
+                sets.resize_with(set + 1, Vec::new);
+            }
+
+            let existing = sets[set]
+                .iter_mut()
+                .find(|other| other.binding == binding.binding);
+
+            match existing {
+                Some(existing) => existing.stage_flags |= stage,
+                None => sets[set].push(
+                    vk::DescriptorSetLayoutBinding::default()
+                        .binding(binding.binding)
+                        .descriptor_type(
+                            vk::DescriptorType::from_raw(
+                                binding.descriptor_type,
+                            ),
+                        )
+                        .descriptor_count(binding.count)
+                        .stage_flags(stage),
+                ),
+            }
+        }
+    }
+
+    for bindings in sets.iter_mut() {
+        bindings.sort_by_key(|binding| binding.binding);
+    }
+
+    sets
+}
@@ -0,0 +1,172 @@
+use ash::vk;
+
+use crate::OutputEncoding;
+
+/// What to ask of the swapchain when picking its format. The
+/// closest match the surface supports is used.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SurfacePreference {
+    /// Prefer Display P3 or extended sRGB color spaces. Needs
+    /// `VK_EXT_swapchain_colorspace`.
+    pub wide_gamut: bool,
+    /// Prefer 10-bit and FP16 formats over 8-bit ones.
+    pub high_bit_depth: bool,
+}
+
+impl Default for SurfacePreference {
+    fn default() -> Self {
+        Self {
+            wide_gamut: true,
+            high_bit_depth: true,
+        }
+    }
+}
+
+impl SurfacePreference {
+    pub const SRGB: Self = Self {
+        wide_gamut: false,
+        high_bit_depth: false,
+    };
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct SurfaceFormat {
+    pub format: vk::Format,
+    pub color_space: vk::ColorSpaceKHR,
+    pub encoding: OutputEncoding,
+}
+
+struct Candidate {
+    format: vk::Format,
+    color_space: vk::ColorSpaceKHR,
+    encoding: OutputEncoding,
+    wide_gamut: bool,
+    high_bit_depth: bool,
+}
+
+const fn candidate(
+    format: vk::Format,
+    color_space: vk::ColorSpaceKHR,
+    encoding: OutputEncoding,
+    high_bit_depth: bool,
+) -> Candidate {
+    Candidate {
+        format,
+        color_space,
+        encoding,
+        wide_gamut: encoding.is_wide_gamut(),
+        high_bit_depth,
+    }
+}
+
+/// Supported formats from most to least preferred.
+const CANDIDATES: &[Candidate] = &[
+    candidate(
+        vk::Format::R16G16B16A16_SFLOAT,
+        vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT,
+        OutputEncoding::ExtendedLinearSrgb,
+        true,
+    ),
+    candidate(
+        vk::Format::A2B10G10R10_UNORM_PACK32,
+        vk::ColorSpaceKHR::DISPLAY_P3_NONLINEAR_EXT,
+        OutputEncoding::DisplayP3,
+        true,
+    ),
+    candidate(
+        vk::Format::A2R10G10B10_UNORM_PACK32,
+        vk::ColorSpaceKHR::DISPLAY_P3_NONLINEAR_EXT,
+        OutputEncoding::DisplayP3,
+        true,
+    ),
+    candidate(
+        vk::Format::B8G8R8A8_UNORM,
+        vk::ColorSpaceKHR::DISPLAY_P3_NONLINEAR_EXT,
+        OutputEncoding::DisplayP3,
+        false,
+    ),
+    candidate(
+        vk::Format::R8G8B8A8_UNORM,
+        vk::ColorSpaceKHR::DISPLAY_P3_NONLINEAR_EXT,
+        OutputEncoding::DisplayP3,
+        false,
+    ),
+    candidate(
+        vk::Format::A2B10G10R10_UNORM_PACK32,
+        vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        OutputEncoding::Srgb,
+        true,
+    ),
+    candidate(
+        vk::Format::A2R10G10B10_UNORM_PACK32,
+        vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        OutputEncoding::Srgb,
+        true,
+    ),
+    candidate(
+        vk::Format::B8G8R8A8_UNORM,
+        vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        OutputEncoding::Srgb,
+        false,
+    ),
+    candidate(
+        vk::Format::R8G8B8A8_UNORM,
+        vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        OutputEncoding::Srgb,
+        false,
+    ),
+    candidate(
+        vk::Format::B8G8R8A8_SRGB,
+        vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        OutputEncoding::LinearSrgb,
+        false,
+    ),
+    candidate(
+        vk::Format::R8G8B8A8_SRGB,
+        vk::ColorSpaceKHR::SRGB_NONLINEAR,
+        OutputEncoding::LinearSrgb,
+        false,
+    ),
+];
+
+impl SurfaceFormat {
+    /// Picks the available format closest to `preference`.
+    /// Wide gamut color spaces are only considered when
+    /// `swapchain_colorspace` (the instance extension) is
+    /// enabled.
+    pub(crate) fn select(
+        available: &[vk::SurfaceFormatKHR],
+        preference: SurfacePreference,
+        swapchain_colorspace: bool,
+    ) -> logging::Result<Self> {
+        CANDIDATES
+            .iter()
+            .enumerate()
+            .filter(|(_, candidate)| {
+                (swapchain_colorspace || !candidate.wide_gamut)
+                    && available.iter().any(|format| {
+                        format.format == candidate.format
+                            && format.color_space
+                                == candidate.color_space
+                    })
+            })
+            .min_by_key(|(index, candidate)| {
+                (
+                    candidate.wide_gamut
+                        != preference.wide_gamut,
+                    candidate.high_bit_depth
+                        != preference.high_bit_depth,
+                    *index,
+                )
+            })
+            .map(|(_, candidate)| Self {
+                format: candidate.format,
+                color_space: candidate.color_space,
+                encoding: candidate.encoding,
+            })
+            .ok_or(
+                logging::ErrorKind::UnsupportedSurfaceFormat
+                    .into_error(),
+            )
+    }
+}
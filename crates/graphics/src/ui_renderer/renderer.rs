@@ -1,7 +1,9 @@
 use ash::{khr, vk};
 
 use crate::{
+    OutputEncoding,
     allocation_callbacks::ALLOCATION_CALLBACKS,
+    surface_format::SurfaceFormat,
     // spirv::{fs::FragmentShaderId, vs::VertexShaderId},
     // vk_object_store::{
     //     FragmentShaderStore, VertexShaderStore, VkObjectStore,
@@ -19,6 +21,7 @@ pub struct UiRenderer {
     setup_command_buffer: vk::CommandBuffer,
     draw_command_buffer: vk::CommandBuffer,
     present_image_views: Box<[vk::ImageView]>,
+    output_encoding: OutputEncoding,
     // vertex_shaders: VertexShaderStore,
     // fragment_shaders: FragmentShaderStore,
     // render_pipelines:
@@ -41,7 +44,7 @@ impl UiRenderer {
         swapchain_loader: khr::swapchain::Device,
         surface_khr: vk::SurfaceKHR,
         swapchain_khr: vk::SwapchainKHR,
-        surface_format: vk::Format,
+        surface_format: SurfaceFormat,
         device: ash::Device,
         present_queue: vk::Queue,
         command_pool: vk::CommandPool,
@@ -68,7 +71,7 @@ impl UiRenderer {
         //     }];
 
         //     let attachments = [ vk::AttachmentDescription {
-        //         format: surface_format,
+        //         format: surface_format.format,
         //         samples: vk::SampleCountFlags::TYPE_1,
         //         load_op: vk::AttachmentLoadOp::CLEAR,
         //         store_op: vk::AttachmentStoreOp::STORE,
@@ -116,12 +119,20 @@ impl UiRenderer {
             setup_command_buffer,
             draw_command_buffer,
             present_image_views,
+            output_encoding: surface_format.encoding,
             // vertex_shaders,
             // fragment_shaders,
             // render_pipelines: VkObjectStore::default(),
             // render_pass: Some(render_pass),
         })
     }
+
+    /// Encoding the swapchain expects; pipelines pass it to the
+    /// shaders as specialization constant 0.
+    #[inline]
+    pub fn output_encoding(&self) -> OutputEncoding {
+        self.output_encoding
+    }
 }
 
 impl Drop for UiRenderer {
@@ -0,0 +1,61 @@
+//! Buffer structs of the shaders, generated by the build script
+//! from Slang reflection, one module per shader module. The
+//! blocks the renderer fills have constructors taking the
+//! crate's own types.
+
+use rounded_rectangle_color_fill::RoundedRectangleShape;
+
+use crate::{BorderRadius, Color, Mat3, Size, Vec2};
+
+include!(concat!(env!("OUT_DIR"), "/uniforms.rs"));
+
+/// `Paint` block of `shaders/common/paint.slang`, see
+/// [`PaintUniform::new`].
+pub use paint_fill::Paint as PaintUniform;
+
+impl quad_emit_uv::ProjectionBlock {
+    #[inline]
+    pub fn new(scale: Vec2, translate: Vec2) -> Self {
+        Self {
+            scale: [scale.x, scale.y],
+            translate: [translate.x, translate.y],
+        }
+    }
+}
+
+impl quad_emit_uv::EmitQuadUvBlock {
+    #[inline]
+    pub fn new(
+        transform: Mat3,
+        position: Vec2,
+        z: f32,
+    ) -> Self {
+        Self {
+            transform: transform.padded_rows(),
+            position: [position.x, position.y],
+            z,
+            ..Self::default()
+        }
+    }
+}
+
+impl rounded_rectangle_color_fill::RoundedRectangleColorFill {
+    #[inline]
+    pub fn new(
+        color: Color,
+        size: Size,
+        radius: BorderRadius,
+    ) -> Self {
+        Self {
+            color: [color.r, color.g, color.b, color.a],
+            shape: RoundedRectangleShape {
+                size: [size.width, size.height],
+                radius_tl: radius.top_left,
+                radius_tr: radius.top_right,
+                radius_bl: radius.bottom_left,
+                radius_br: radius.bottom_right,
+                ..Default::default()
+            },
+        }
+    }
+}
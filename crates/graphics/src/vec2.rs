@@ -28,6 +28,11 @@ impl Vec2 {
         sq_x + sq_y
     }
 
+    #[inline]
+    pub fn cross(self, other: Vec2) -> f32 {
+        self.x * other.y - self.y * other.x
+    }
+
     #[inline]
     pub fn length(self) -> f32 {
         self.x.hypot(self.y)
@@ -41,6 +46,24 @@ impl Vec2 {
         sq_x + sq_y
     }
 
+    #[inline]
+    pub fn distance(self, other: Vec2) -> f32 {
+        (other - self).length()
+    }
+
+    /// Unit vector in the same direction, or zero if the
+    /// length is zero.
+    #[inline]
+    pub fn normalize_or_zero(self) -> Self {
+        let length = self.length();
+
+        if length > 0.0 {
+            self / length
+        } else {
+            Self::ZERO
+        }
+    }
+
     #[inline]
     pub fn atan2(self) -> f32 {
         self.y.atan2(self.x)
@@ -71,6 +94,46 @@ impl Vec2 {
             y: self.y.recip(),
         }
     }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self {
+        Self {
+            x: self.x.min(other.x),
+            y: self.y.min(other.y),
+        }
+    }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self {
+        Self {
+            x: self.x.max(other.x),
+            y: self.y.max(other.y),
+        }
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self {
+            x: self.x.floor(),
+            y: self.y.floor(),
+        }
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self {
+            x: self.x.ceil(),
+            y: self.y.ceil(),
+        }
+    }
+
+    #[inline]
+    pub fn round(self) -> Self {
+        Self {
+            x: self.x.round(),
+            y: self.y.round(),
+        }
+    }
 }
 
 impl ops::Add for Vec2 {
@@ -97,6 +160,18 @@ impl ops::Sub for Vec2 {
     }
 }
 
+impl ops::Neg for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
 impl ops::Mul<f32> for Vec2 {
     type Output = Vec2;
 
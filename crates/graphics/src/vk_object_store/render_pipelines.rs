@@ -3,6 +3,7 @@ use std::mem::MaybeUninit;
 use ash::vk;
 
 use crate::{
+    BlendMode, OutputEncoding,
     allocation_callbacks::ALLOCATION_CALLBACKS,
     spirv::{
         ShaderSource, fs::FragmentShaderId, vs::VertexShaderId,
@@ -20,6 +21,7 @@ impl
         render_pass: &vk::RenderPass,
         vertex_shaders: &mut super::VertexShaderStore,
         fragment_shaders: &mut super::FragmentShaderStore,
+        output_encoding: OutputEncoding,
         sources: [(
             ShaderSource<VertexShaderId>,
             ShaderSource<FragmentShaderId>,
@@ -33,6 +35,20 @@ impl
             // will add more later
         }
 
+        // `OUTPUT_ENCODING` in shaders/common/output.slang.
+        let output_encoding =
+            (output_encoding as u32).to_ne_bytes();
+        let specialization_entries =
+            [vk::SpecializationMapEntry {
+                constant_id: 0,
+                offset: 0,
+                size: size_of::<u32>(),
+            }];
+        let specialization_info =
+            vk::SpecializationInfo::default()
+                .map_entries(&specialization_entries)
+                .data(&output_encoding);
+
         let multisample_state =
             vk::PipelineMultisampleStateCreateInfo {
                 rasterization_samples:
@@ -40,23 +56,12 @@ impl
                 ..Default::default()
             };
 
-        let color_blend_attachment_states =
-            [vk::PipelineColorBlendAttachmentState {
-                blend_enable: 0,
-                src_color_blend_factor:
-                    vk::BlendFactor::SRC_COLOR,
-                dst_color_blend_factor:
-                    vk::BlendFactor::ONE_MINUS_DST_COLOR,
-                color_blend_op: vk::BlendOp::ADD,
-                src_alpha_blend_factor: vk::BlendFactor::ZERO,
-                dst_alpha_blend_factor: vk::BlendFactor::ZERO,
-                alpha_blend_op: vk::BlendOp::ADD,
-                color_write_mask: vk::ColorComponentFlags::RGBA,
-            }];
+        let color_blend_attachment_states = [BlendMode::SourceOver
+            .color_blend_attachment()
+            .unwrap_or_default()];
 
         let color_blend_state =
             vk::PipelineColorBlendStateCreateInfo::default()
-                .logic_op(vk::LogicOp::CLEAR)
                 .attachments(&color_blend_attachment_states);
 
         let dynamic_state = [
@@ -83,8 +88,10 @@ impl
             let fs_info = fragment_shaders
                 .use_shader(device, fs_source)?;
 
-            deps[index].stages =
-                MaybeUninit::new([vs_info, fs_info]);
+            deps[index].stages = MaybeUninit::new([
+                vs_info,
+                fs_info.specialization_info(&specialization_info),
+            ]);
 
             let stages_ptr = deps[index].stages.as_ptr();
 
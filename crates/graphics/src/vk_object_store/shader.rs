@@ -15,8 +15,6 @@ pub(crate) type FragmentShaderStore = super::VkObjectStore<
     ShaderModule,
 >;
 
-const ENTRY_POINT_NAME: *const i8 = c"main".as_ptr();
-
 impl<K> super::VkObjectStore<K, ShaderModule>
 where
     K: Copy
@@ -128,9 +126,9 @@ impl VertexShaderStore {
         Ok(vk::PipelineShaderStageCreateInfo {
             s_type: vk::PipelineShaderStageCreateInfo::STRUCTURE_TYPE,
             p_next: core::ptr::null(),
-            stage: vk::ShaderStageFlags::VERTEX,
+            stage: source.stage(),
             module: *module,
-            p_name: ENTRY_POINT_NAME,
+            p_name: source.entry_point.as_ptr(),
             ..Default::default()
         })
     }
@@ -153,9 +151,9 @@ impl FragmentShaderStore {
         Ok(vk::PipelineShaderStageCreateInfo {
             s_type: vk::PipelineShaderStageCreateInfo::STRUCTURE_TYPE,
             p_next: core::ptr::null(),
-            stage: vk::ShaderStageFlags::FRAGMENT,
+            stage: source.stage(),
             module: *module,
-            p_name: ENTRY_POINT_NAME,
+            p_name: source.entry_point.as_ptr(),
             ..Default::default()
         })
     }
@@ -0,0 +1,101 @@
+//! Shader math run on the CPU with `slang_build::HostKernel`
+//! and compared with Rust implementations.
+
+use std::path::Path;
+
+use slang_build::HostKernel;
+
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+struct RoundedSdfInput {
+    p: [f32; 2],
+    b: [f32; 2],
+    r: f32,
+}
+
+unsafe impl slang_build::HostData for RoundedSdfInput {}
+
+#[test]
+fn test_shader_math() {
+    let shaders = Path::new(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/shaders"
+    ));
+    let search_paths = [shaders];
+
+    let median = HostKernel::<[f32; 3], f32>::compile(
+        shaders.join("tests/median.slang"),
+        "median_kernel",
+        &search_paths,
+    )
+    .unwrap();
+
+    median.assert_reference(
+        &[
+            [0.0, 0.5, 1.0],
+            [1.0, 0.0, 0.5],
+            [0.5, 1.0, 0.0],
+            [-2.0, 3.0, 3.0],
+            [0.25, 0.25, -1.0],
+        ],
+        |&[r, g, b]| r.min(g).max(r.max(g).min(b)),
+        0.0,
+    );
+
+    let rounded_sdf =
+        HostKernel::<RoundedSdfInput, f32>::compile(
+            shaders.join("tests/rounded_sdf.slang"),
+            "rounded_sdf_kernel",
+            &search_paths,
+        )
+        .unwrap();
+
+    let inputs =
+        [[0.0, 0.0], [10.0, 0.0], [12.0, 7.0], [-3.0, 4.5]]
+            .into_iter()
+            .flat_map(|p| {
+                [0.0, 2.0, 5.0].map(|r| RoundedSdfInput {
+                    p,
+                    b: [10.0, 5.0],
+                    r,
+                })
+            })
+            .collect::<Vec<_>>();
+
+    rounded_sdf.assert_reference(
+        &inputs,
+        |input| {
+            let dx = input.p[0].abs() - input.b[0] + input.r;
+            let dy = input.p[1].abs() - input.b[1] + input.r;
+
+            dx.max(dy).min(0.0) + dx.max(0.0).hypot(dy.max(0.0))
+                - input.r
+        },
+        1e-5,
+    );
+
+    let msdf_coverage = HostKernel::<[f32; 2], f32>::compile(
+        shaders.join("tests/msdf_coverage.slang"),
+        "msdf_coverage_kernel",
+        &search_paths,
+    )
+    .unwrap();
+
+    msdf_coverage.assert_reference(
+        &[
+            [-1.0, 0.5],
+            [-0.25, 0.5],
+            [0.0, 0.5],
+            [0.1, 0.5],
+            [2.0, 0.5],
+        ],
+        |&[signed_distance, threshold]| {
+            let t = ((signed_distance + threshold)
+                / (2.0 * threshold))
+                .clamp(0.0, 1.0);
+
+            1.0 - t * t * (3.0 - 2.0 * t)
+        },
+        1e-6,
+    );
+}
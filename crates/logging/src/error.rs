@@ -34,6 +34,10 @@ pub enum ErrorKind {
     NoCompatibleDevice,
     #[error("Unsupported surface format")]
     UnsupportedSurfaceFormat,
+    #[error("Invalid CSS color `{0}`")]
+    InvalidCssColor(String),
+    #[error("Invalid palette: {0}")]
+    InvalidPalette(String),
 }
 
 pub type Result<T> =
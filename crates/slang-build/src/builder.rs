@@ -1,139 +1,606 @@
-use std::{ffi::CString, path::Path, str::FromStr};
+use std::{
+    collections::BTreeMap,
+    ffi::CString,
+    path::{Path, PathBuf},
+};
 
-use crate::{config, slang::Slang};
+use crate::{
+    config::{self, Settings},
+    config_file::ConfigFile,
+    dependencies::{self, DependencyManifest},
+    output_manifest::OutputManifest,
+    permutations::{
+        self, OptionKind, Permutation, PermutationOption,
+    },
+    registry,
+    slang::{CompileRequest, Slang},
+    uniforms,
+};
 
 pub struct Builder {
     slang: Slang,
-    search_path: CString,
-    output_dir: Box<Path>,
+    input_dir: Box<Path>,
+    search_paths: Box<[CString]>,
+    cache_dir: Box<Path>,
     entries: Box<[BuildEntry]>,
-    optimization: crate::OptimizationLevel,
+    targets: Box<[BuildTarget]>,
+    output_dir: Box<Path>,
+    /// Configuration file to watch, if any.
+    config_file: Option<PathBuf>,
+}
+
+/// [`crate::Target`] with its output directory resolved.
+#[derive(Debug)]
+struct BuildTarget {
+    format: crate::TargetFormat,
+    profile: Option<CString>,
+    output_dir: PathBuf,
 }
 
 #[derive(Debug)]
 struct BuildEntry {
+    path: PathBuf,
     input: CString,
+    /// Path relative to the input directory, which overrides are
+    /// matched against and the module is recorded under in the
+    /// dependency manifest.
+    name: String,
+    /// Name of the module, the path relative to the input
+    /// directory made an identifier. Outputs go in a directory
+    /// of that name and generated Rust code uses it as module.
+    rust_module: String,
+    /// [`BuildEntry::rust_module`] as Slang module name.
     module_name: CString,
+    includes: Box<[PathBuf]>,
+    /// Hash of the sources and of the options of the module.
+    input_hash: String,
+    optimization: crate::OptimizationLevel,
+    defines: Box<[(CString, CString)]>,
+    /// Options the module is compiled once per combination of.
+    options: Vec<PermutationOption>,
 }
 
 impl Builder {
-    #[allow(clippy::new_without_default)]
-    pub fn new(config: config::Config) -> Self {
-        let manifest =
-            std::env::var_os("CARGO_MANIFEST_DIR").unwrap();
-        let manifest = Path::new(&manifest);
-
-        let input_dir_iter =
-            if config.input_dir.is_absolute() {
-                std::fs::read_dir(config.input_dir)
-            } else {
-                std::fs::read_dir(
-                    manifest.join(config.input_dir),
+    pub fn new(config: config::Config) -> crate::Result<Self> {
+        let manifest_dir = manifest_dir()?;
+
+        Self::with_settings(config.settings(&manifest_dir)?)
+    }
+
+    /// Builder configured by `slang-build.toml` next to the
+    /// crate manifest, so adding shaders or targets doesn't
+    /// touch `build.rs`. The file is validated before anything
+    /// is compiled.
+    ///
+    /// ```toml
+    /// input-dir = "shaders"
+    /// optimization = "maximal"
+    ///
+    /// [[targets]]
+    /// format = "spirv"
+    /// ```
+    pub fn from_config_file() -> crate::Result<Self> {
+        let path = manifest_dir()?.join(ConfigFile::FILE_NAME);
+
+        Self::with_settings(ConfigFile::load(&path)?)
+    }
+
+    fn with_settings(
+        settings: Settings,
+    ) -> crate::Result<Self> {
+        let mut modules = Vec::new();
+        find_modules(
+            &settings.input_dir,
+            &settings,
+            &mut modules,
+        )?;
+        modules.sort();
+
+        let search_paths = settings
+            .search_paths
+            .iter()
+            .map(PathBuf::as_path)
+            .collect::<Vec<_>>();
+
+        let entries = modules
+            .into_iter()
+            .map(|(path, name)| {
+                BuildEntry::new(
+                    path,
+                    &name,
+                    &settings,
+                    &search_paths,
                 )
+            })
+            .collect::<crate::Result<Box<[BuildEntry]>>>()?;
+
+        let mut module_paths = BTreeMap::new();
+
+        for entry in entries.iter() {
+            let first = module_paths.insert(
+                entry.rust_module.as_str(),
+                &entry.path,
+            );
+
+            if let Some(first) = first {
+                return Err(crate::Error::DuplicateModule {
+                    name: entry.rust_module.clone(),
+                    first: first.clone(),
+                    second: entry.path.clone(),
+                });
             }
-            .unwrap();
+        }
 
-        let entries = input_dir_iter
-            .into_iter()
-            .filter_map(|entry| {
-                let entry = entry.ok()?;
+        let targets = settings
+            .targets
+            .iter()
+            .map(|target| {
+                let profile = match target.profile.as_deref() {
+                    Some(profile) => Some(cstring(profile)?),
+                    None => None,
+                };
 
-                let input = entry.path();
+                Ok(BuildTarget {
+                    format: target.format,
+                    profile,
+                    output_dir: target.output_dir.clone(),
+                })
+            })
+            .collect::<crate::Result<_>>()?;
 
-                if !input.is_file() {
-                    return None;
-                }
+        Ok(Self {
+            slang: Slang::new()?,
+            search_paths: search_paths
+                .into_iter()
+                .map(path_to_cstring)
+                .collect::<crate::Result<_>>()?,
+            input_dir: settings.input_dir.into_boxed_path(),
+            output_dir: settings.output_dir.into_boxed_path(),
+            entries,
+            cache_dir: match std::env::var_os("OUT_DIR") {
+                Some(dir) => PathBuf::from(dir),
+                None => manifest_dir()?,
+            }
+            .into_boxed_path(),
+            targets,
+            config_file: settings.file,
+        })
+    }
 
-                if let Some("slang") =
-                    input.extension().and_then(|v| v.to_str())
+    /// Compiles the modules whose sources, includes or outputs
+    /// changed since the last build, and tells cargo which files
+    /// to watch. Every entry point is written once per target, as
+    /// `<module>/<entry point>.<extension>`, and `shaders.toml` in
+    /// the output directory lists them all.
+    ///
+    /// Two Rust files are generated for the crate to include:
+    ///
+    /// - `OUT_DIR/shaders.rs` has a module per stage (`vs`, `fs`,
+    ///   `cs`, ...) with an id enum and a `ShaderSource` constant
+    ///   per entry point, embedding its code along with the
+    ///   descriptor bindings, push constant size, vertex inputs
+    ///   and workgroup size Slang reflected. Names are prefixed
+    ///   with the module unless the entry point is named like
+    ///   it, so `main` of `blur` is `BlurMain` and `BLUR_MAIN`.
+    ///   Permuted modules get an id per permutation and a
+    ///   `keys::<module>::Key` that a function named like the
+    ///   entry point, `blur_main`, maps to the id.
+    /// - `OUT_DIR/uniforms.rs` has structs matching the buffers of
+    ///   every shader module, one Rust module per shader module.
+    ///
+    /// ```ignore
+    /// #[allow(dead_code)]
+    /// mod uniforms {
+    ///     include!(concat!(env!("OUT_DIR"), "/uniforms.rs"));
+    /// }
+    /// ```
+    ///
+    /// Slang warnings are passed on as `cargo:warning` lines. A
+    /// failed compile returns [`crate::Error::Compile`], which
+    /// shows the errors with the shader lines they point at.
+    pub fn compile(self) -> crate::Result<()> {
+        // The directory itself is watched so that new modules are
+        // picked up.
+        println!(
+            "cargo:rerun-if-changed={}",
+            self.input_dir.display()
+        );
+
+        let mut watched = std::collections::BTreeSet::new();
+        watched.extend(self.config_file.as_deref());
+
+        for entry in self.entries.iter() {
+            watched.insert(entry.path.as_path());
+            watched.extend(
+                entry.includes.iter().map(PathBuf::as_path),
+            );
+        }
+
+        for path in watched {
+            println!(
+                "cargo:rerun-if-changed={}",
+                path.display()
+            );
+        }
+
+        // Per module options are part of the input hashes.
+        let options = format!(
+            "search_paths={:?};targets={:?}",
+            self.search_paths, self.targets,
+        );
+
+        let output_dirs = self
+            .targets
+            .iter()
+            .map(|target| target.output_dir.as_path())
+            .chain([self.output_dir.as_ref()]);
+
+        for output_dir in output_dirs {
+            std::fs::create_dir_all(output_dir)
+                .map_err(crate::Error::io(output_dir))?;
+        }
+
+        let mut manifest =
+            DependencyManifest::load(&self.cache_dir, &options);
+
+        manifest.retain(
+            self.entries
+                .iter()
+                .map(|entry| entry.name.as_str()),
+        );
+
+        let uniforms_dir = self.cache_dir.join("uniforms");
+        std::fs::create_dir_all(&uniforms_dir)
+            .map_err(crate::Error::io(&uniforms_dir))?;
+
+        for entry in self.entries.iter() {
+            if !manifest
+                .is_outdated(&entry.name, &entry.input_hash)
+            {
+                continue;
+            }
+
+            let mut entry_points = Vec::new();
+            let mut uniform_structs = None;
+
+            for permutation in
+                permutations::combinations(&entry.options)
+            {
+                let compile_request =
+                    self.compile_request(entry, &permutation)?;
+
+                entry_points.extend(compile_request.compile(
+                    &entry.rust_module,
+                    &permutation,
+                )?);
+
+                for diagnostic in compile_request.diagnostics()
                 {
-                    let file_name = input
-                        .with_extension("")
-                        .file_name()?
-                        .to_owned();
-
-                    // let output =
-                    //     if config.output_dir.is_absolute() {
-                    //         config.output_dir.join(&file_name)
-                    //     } else {
-                    //         manifest
-                    //             .join(config.output_dir)
-                    //             .join(&file_name)
-                    //     }
-                    //     .with_extension("spv");
-
-                    // if output.exists() {
-                    //     let input_modified_date = entry
-                    //         .metadata()
-                    //         .ok()?
-                    //         .modified()
-                    //         .ok()?;
-
-                    //     let output_modified_date = output
-                    //         .metadata()
-                    //         .ok()?
-                    //         .modified()
-                    //         .ok()?;
-
-                    //     if output_modified_date
-                    //         > input_modified_date
-                    //     {
-                    //         return None;
-                    //     }
-                    // }
-
-                    Some(BuildEntry {
-                        input: CString::from_str(
-                            input.to_str()?,
-                        )
-                        .ok()?,
-                        module_name: CString::from_str(
-                            &file_name.to_str()?.to_uppercase(),
-                        )
-                        .ok()?,
-                    })
-                } else {
-                    None
+                    println!("cargo:warning={diagnostic}");
                 }
-            })
-            .collect::<Box<[BuildEntry]>>();
 
-        let search_path =
-            CString::new(config.input_dir.to_str().unwrap())
-                .unwrap();
+                // Permutations are expected to share buffers, so
+                // the structs come from the first one.
+                if uniform_structs.is_none() {
+                    uniform_structs = Some(
+                        compile_request.uniform_structs()?,
+                    );
+                }
+            }
 
-        Self {
-            slang: Slang::new(),
-            search_path,
-            entries,
-            output_dir: if config.output_dir.is_absolute() {
-                config.output_dir.into()
-            } else {
-                manifest
-                    .join(config.output_dir)
-                    .into_boxed_path()
-            },
-            optimization: config.optimization,
+            let mut outputs = entry_points
+                .iter()
+                .flat_map(|entry_point| {
+                    entry_point.outputs.iter().cloned()
+                })
+                .collect::<Vec<_>>();
+
+            let uniforms_path =
+                entry.uniforms_path(&uniforms_dir);
+            write(
+                &uniforms_path,
+                uniforms::module_source(
+                    &uniform_structs.unwrap_or_default(),
+                )?,
+            )?;
+            outputs.push(uniforms_path);
+
+            let outputs = outputs
+                .into_iter()
+                .map(|path| {
+                    let bytes = std::fs::read(&path)
+                        .map_err(crate::Error::io(&path))?;
+
+                    Ok(dependencies::OutputRecord {
+                        hash: dependencies::hash(&bytes),
+                        path,
+                    })
+                })
+                .collect::<crate::Result<_>>()?;
+
+            manifest.insert(
+                entry.name.clone(),
+                dependencies::ModuleRecord {
+                    input_hash: entry.input_hash.clone(),
+                    includes: entry.includes.to_vec(),
+                    entry_points,
+                    outputs,
+                },
+            );
         }
+
+        manifest.save(&self.cache_dir)?;
+
+        registry::check_duplicates(manifest.entry_points())?;
+
+        let mut outputs = OutputManifest::default();
+
+        for entry in self.entries.iter() {
+            let Some(record) = manifest.module(&entry.name)
+            else {
+                continue;
+            };
+
+            outputs.insert(
+                &entry.rust_module,
+                &entry.path,
+                record.entry_points.iter(),
+                &self.input_dir,
+                &self.output_dir,
+            );
+        }
+
+        write(
+            &self.output_dir.join(OutputManifest::FILE_NAME),
+            outputs.source()?,
+        )?;
+
+        write(
+            &self.cache_dir.join("shaders.rs"),
+            registry::source(
+                manifest.entry_points(),
+                self.targets.iter().position(|target| {
+                    target.format == crate::TargetFormat::Spirv
+                }),
+                &self
+                    .entries
+                    .iter()
+                    .map(|entry| {
+                        (
+                            entry.rust_module.as_str(),
+                            entry.options.as_slice(),
+                        )
+                    })
+                    .collect::<Vec<_>>(),
+            ),
+        )?;
+
+        let modules = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let path = entry.uniforms_path(&uniforms_dir);
+                let body = std::fs::read_to_string(&path)
+                    .map_err(crate::Error::io(path))?;
+
+                Ok((entry.rust_module.as_str(), body))
+            })
+            .collect::<crate::Result<Vec<_>>>()?;
+
+        write(
+            &self.cache_dir.join("uniforms.rs"),
+            uniforms::source(
+                modules
+                    .iter()
+                    .map(|(name, body)| (*name, body.as_str())),
+            ),
+        )
     }
+}
 
-    pub fn compile(self) {
-        let compile_request = self
+impl Builder {
+    /// Request compiling one permutation of `entry`.
+    fn compile_request(
+        &self,
+        entry: &BuildEntry,
+        permutation: &Permutation,
+    ) -> crate::Result<CompileRequest> {
+        let mut compile_request = self
             .slang
-            .create_compile_request(self.optimization);
+            .create_compile_request(entry.optimization)?;
+
+        for target in self.targets.iter() {
+            compile_request.add_target(
+                target.format,
+                target.profile.as_deref(),
+                &target.output_dir,
+            )?;
+        }
+
+        for search_path in self.search_paths.iter() {
+            compile_request.add_search_path(search_path);
+        }
 
-        compile_request.add_search_path(&self.search_path);
+        for (key, value) in entry.defines.iter() {
+            compile_request.add_preprocessor_define(key, value);
+        }
 
-        for entry in self.entries {
+        for (option, (name, value)) in
+            entry.options.iter().zip(permutation)
+        {
+            if option.kind == OptionKind::Define {
+                compile_request.add_preprocessor_define(
+                    &cstring(name)?,
+                    &cstring(&value.define())?,
+                );
+            }
+        }
+
+        let unit_index = compile_request
+            .add_translation_unit(&entry.module_name);
+
+        compile_request.add_translation_unit_source_file(
+            unit_index,
+            &entry.input,
+        );
+
+        let constants = permutations::constants_source(
+            &entry.options,
+            permutation,
+        );
+
+        if let Some(constants) = constants {
             let unit_index = compile_request
-                .add_translation_unit(&entry.module_name);
+                .add_translation_unit(c"PERMUTATION_CONSTANTS");
 
-            compile_request.add_translation_unit_source_file(
+            compile_request.add_translation_unit_source_string(
                 unit_index,
-                &entry.input,
+                c"permutation-constants.slang",
+                &cstring(&constants)?,
             );
         }
 
-        compile_request.compile(self.output_dir.as_ref());
+        Ok(compile_request)
     }
 }
+
+impl BuildEntry {
+    /// `name` is the path relative to the input directory, which
+    /// overrides are matched against.
+    fn new(
+        input: PathBuf,
+        name: &str,
+        settings: &Settings,
+        search_paths: &[&Path],
+    ) -> crate::Result<Self> {
+        let rust_module = uniforms::identifier(
+            &name.trim_end_matches(".slang").to_lowercase(),
+        );
+
+        let includes =
+            dependencies::includes(&input, search_paths);
+        let (optimization, defines) =
+            settings.module_options(name);
+        let options = settings.module_permutations(name);
+
+        let mut sources = std::fs::read(&input)
+            .map_err(crate::Error::io(&input))?;
+
+        for include in includes.iter() {
+            sources.extend(
+                std::fs::read(include)
+                    .map_err(crate::Error::io(include))?,
+            );
+        }
+
+        sources.extend(
+            format!("{optimization:?}{defines:?}{options:?}")
+                .into_bytes(),
+        );
+
+        Ok(Self {
+            input: path_to_cstring(&input)?,
+            name: name.to_owned(),
+            module_name: cstring(&rust_module)?,
+            rust_module,
+            includes: includes.into_iter().collect(),
+            input_hash: dependencies::hash(&sources),
+            path: input,
+            optimization,
+            defines: defines
+                .iter()
+                .map(|(key, value)| {
+                    Ok((cstring(key)?, cstring(value)?))
+                })
+                .collect::<crate::Result<_>>()?,
+            options,
+        })
+    }
+
+    /// Generated structs of this module alone.
+    fn uniforms_path(&self, uniforms_dir: &Path) -> PathBuf {
+        uniforms_dir
+            .join(&self.rust_module)
+            .with_extension("rs")
+    }
+}
+
+fn write(
+    path: &Path,
+    contents: impl AsRef<[u8]>,
+) -> crate::Result<()> {
+    std::fs::write(path, contents)
+        .map_err(crate::Error::io(path))
+}
+
+pub(crate) fn path_to_cstring(
+    path: &Path,
+) -> crate::Result<CString> {
+    path.to_str()
+        .and_then(|path| CString::new(path).ok())
+        .ok_or_else(|| {
+            crate::Error::InvalidPath(path.to_owned())
+        })
+}
+
+fn manifest_dir() -> crate::Result<PathBuf> {
+    std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .ok_or(crate::Error::MissingEnvironment(
+            "CARGO_MANIFEST_DIR",
+        ))
+}
+
+/// Modules under `dir` the settings include, with their paths
+/// relative to the input directory.
+fn find_modules(
+    dir: &Path,
+    settings: &Settings,
+    modules: &mut Vec<(PathBuf, String)>,
+) -> crate::Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(crate::Error::io(dir))?;
+
+    for entry in entries {
+        let path = entry.map_err(crate::Error::io(dir))?.path();
+
+        if path.is_dir() {
+            find_modules(&path, settings, modules)?;
+            continue;
+        }
+
+        if path.extension().is_none_or(|v| v != "slang") {
+            continue;
+        }
+
+        let Ok(relative) =
+            path.strip_prefix(&settings.input_dir)
+        else {
+            continue;
+        };
+
+        let name = relative
+            .components()
+            .map(|component| component.as_os_str().to_str())
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| {
+                crate::Error::InvalidPath(path.clone())
+            })?
+            .join("/");
+
+        if settings.is_included(&name) {
+            modules.push((path, name));
+        }
+    }
+
+    Ok(())
+}
+
+fn cstring(value: &str) -> crate::Result<CString> {
+    CString::new(value).map_err(|_| {
+        crate::Error::InvalidConfig(format!(
+            "`{}` contains a nul byte",
+            value.escape_debug()
+        ))
+    })
+}
@@ -1,14 +1,322 @@
-use std::path::Path;
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
 
+use serde::Deserialize;
+
+use crate::{
+    glob::Glob,
+    permutations::{PermutationOption, Permutations},
+};
+
+/// Configuration in `build.rs`. It compiles the modules directly
+/// in the input directory, without defines or extra search
+/// paths. [`crate::Builder::from_config_file`] takes all options
+/// from `slang-build.toml` instead.
 pub struct Config<'a> {
     pub input_dir: &'a Path,
     pub output_dir: &'a Path,
     pub optimization: OptimizationLevel,
+    /// Code generated for every entry point. Empty means SPIR-V
+    /// only, like `&[Target::SPIRV]`.
+    pub targets: &'a [Target<'a>],
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
 pub enum OptimizationLevel {
     Default,
     High,
     Maximal,
 }
+
+/// One kind of code to generate and where it goes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Target<'a> {
+    pub format: TargetFormat,
+    /// Slang profile, like `spirv_1_5` or `glsl_450`. `None`
+    /// uses the default of the format.
+    pub profile: Option<&'a str>,
+    /// Directory relative to [`Config::output_dir`]. `None`
+    /// writes to the output directory itself.
+    pub output_dir: Option<&'a Path>,
+}
+
+impl Target<'_> {
+    /// SPIR-V written to the output directory, which the
+    /// generated shader registry embeds.
+    pub const SPIRV: Target<'static> = Target {
+        format: TargetFormat::Spirv,
+        profile: None,
+        output_dir: None,
+    };
+}
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum TargetFormat {
+    Spirv,
+    /// SPIR-V disassembly, readable in code review diffs.
+    SpirvAsm,
+    Glsl,
+    Hlsl,
+    Wgsl,
+    Metal,
+    /// C++ source of every entry point, for running shaders on
+    /// the CPU. It includes Slang's `slang-cpp-prelude.h`.
+    Cpp,
+}
+
+impl TargetFormat {
+    /// Extension of the files written for the format.
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Spirv => "spv",
+            Self::SpirvAsm => "spvasm",
+            Self::Glsl => "glsl",
+            Self::Hlsl => "hlsl",
+            Self::Wgsl => "wgsl",
+            Self::Metal => "metal",
+            Self::Cpp => "cpp",
+        }
+    }
+}
+
+/// Everything the builder needs, from a [`Config`] or from
+/// `slang-build.toml`, with paths made absolute.
+#[derive(Debug)]
+pub(crate) struct Settings {
+    pub(crate) input_dir: PathBuf,
+    /// Where the targets write to unless they set their own
+    /// directory, and where `shaders.toml` goes.
+    pub(crate) output_dir: PathBuf,
+    /// Directories searched for includes and imports, the input
+    /// directory first.
+    pub(crate) search_paths: Vec<PathBuf>,
+    pub(crate) optimization: OptimizationLevel,
+    pub(crate) defines: BTreeMap<String, String>,
+    /// Modules to compile, matched against paths relative to the
+    /// input directory.
+    pub(crate) include: Vec<Glob>,
+    pub(crate) exclude: Vec<Glob>,
+    pub(crate) targets: Vec<TargetSettings>,
+    pub(crate) overrides: Vec<Override>,
+    pub(crate) permutations: Vec<Permutations>,
+    /// File the settings were read from, watched for changes.
+    pub(crate) file: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+pub(crate) struct TargetSettings {
+    pub(crate) format: TargetFormat,
+    pub(crate) profile: Option<String>,
+    pub(crate) output_dir: PathBuf,
+}
+
+/// Settings of the modules matching `files`, applied after the
+/// global ones. Later overrides win.
+#[derive(Debug)]
+pub(crate) struct Override {
+    pub(crate) files: Glob,
+    pub(crate) optimization: Option<OptimizationLevel>,
+    pub(crate) defines: BTreeMap<String, String>,
+}
+
+impl Config<'_> {
+    pub(crate) fn settings(
+        &self,
+        manifest_dir: &Path,
+    ) -> crate::Result<Settings> {
+        let input_dir = manifest_dir.join(self.input_dir);
+        let output_dir = manifest_dir.join(self.output_dir);
+
+        let targets = if self.targets.is_empty() {
+            &[Target::SPIRV]
+        } else {
+            self.targets
+        };
+
+        let settings = Settings {
+            search_paths: vec![input_dir.clone()],
+            input_dir,
+            optimization: self.optimization,
+            defines: BTreeMap::new(),
+            include: vec![Glob::new("*.slang").unwrap()],
+            exclude: Vec::new(),
+            targets: targets
+                .iter()
+                .map(|target| TargetSettings {
+                    format: target.format,
+                    profile: target.profile.map(str::to_owned),
+                    output_dir: match target.output_dir {
+                        Some(dir) => output_dir.join(dir),
+                        None => output_dir.clone(),
+                    },
+                })
+                .collect(),
+            overrides: Vec::new(),
+            permutations: Vec::new(),
+            file: None,
+            output_dir,
+        };
+
+        settings.validate()?;
+
+        Ok(settings)
+    }
+}
+
+impl Settings {
+    /// Checks what Slang would otherwise fail on late or with a
+    /// less clear message.
+    pub(crate) fn validate(&self) -> crate::Result<()> {
+        let invalid = |message: String| {
+            Err(crate::Error::InvalidConfig(message))
+        };
+
+        if !self.input_dir.is_dir() {
+            return invalid(format!(
+                "Input directory `{}` does not exist",
+                self.input_dir.display()
+            ));
+        }
+
+        if self.include.is_empty() {
+            return invalid("No include patterns".to_owned());
+        }
+
+        for (index, target) in self.targets.iter().enumerate() {
+            let duplicate =
+                self.targets[..index].iter().any(|other| {
+                    other.format == target.format
+                        && other.output_dir == target.output_dir
+                });
+
+            if duplicate {
+                return invalid(format!(
+                    "Target {:?} is written to `{}` twice",
+                    target.format,
+                    target.output_dir.display(),
+                ));
+            }
+
+            if target.profile.as_deref().is_some_and(
+                |profile| {
+                    profile.is_empty() || profile.contains('\0')
+                },
+            ) {
+                return invalid(format!(
+                    "Invalid profile of target {:?}",
+                    target.format
+                ));
+            }
+        }
+
+        let defines = self.defines.iter().chain(
+            self.overrides
+                .iter()
+                .flat_map(|entry| entry.defines.iter()),
+        );
+
+        for (name, value) in defines {
+            if !is_identifier(name) || value.contains('\0') {
+                return invalid(format!(
+                    "Invalid define `{name}`"
+                ));
+            }
+        }
+
+        for option in self
+            .permutations
+            .iter()
+            .flat_map(|entry| entry.options.iter())
+        {
+            option
+                .validate()
+                .map_err(crate::Error::InvalidConfig)?;
+
+            if !is_identifier(&option.name) {
+                return invalid(format!(
+                    "Invalid option `{}`",
+                    option.name
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether the module at `path`, relative to the input
+    /// directory, is compiled.
+    pub(crate) fn is_included(&self, path: &str) -> bool {
+        self.include.iter().any(|glob| glob.matches(path))
+            && !self
+                .exclude
+                .iter()
+                .any(|glob| glob.matches(path))
+    }
+
+    /// Optimization level and defines of the module at `path`,
+    /// after overrides.
+    pub(crate) fn module_options(
+        &self,
+        path: &str,
+    ) -> (OptimizationLevel, BTreeMap<String, String>) {
+        let mut optimization = self.optimization;
+        let mut defines = self.defines.clone();
+
+        for entry in self.overrides.iter() {
+            if !entry.files.matches(path) {
+                continue;
+            }
+
+            if let Some(level) = entry.optimization {
+                optimization = level;
+            }
+
+            defines.extend(
+                entry
+                    .defines
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone())),
+            );
+        }
+
+        (optimization, defines)
+    }
+
+    /// Options the module at `path` is permuted over, from every
+    /// matching `[[permutations]]` in order. Options named again
+    /// replace the earlier ones.
+    pub(crate) fn module_permutations(
+        &self,
+        path: &str,
+    ) -> Vec<PermutationOption> {
+        let mut options = Vec::<PermutationOption>::new();
+
+        let matching = self
+            .permutations
+            .iter()
+            .filter(|entry| entry.files.matches(path));
+
+        for option in
+            matching.flat_map(|entry| entry.options.iter())
+        {
+            options.retain(|other| other.name != option.name);
+            options.push(option.clone());
+        }
+
+        options
+    }
+}
+
+fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| {
+        c.is_ascii_alphabetic() || c == '_'
+    }) && name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
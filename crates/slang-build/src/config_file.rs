@@ -0,0 +1,311 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::{
+    OptimizationLevel, TargetFormat,
+    config::{Override, Settings, TargetSettings},
+    glob::Glob,
+    permutations::{
+        OptionKind, PermutationOption, Permutations, Value,
+    },
+};
+
+/// Contents of `slang-build.toml`. Relative paths are relative
+/// to the file. Without `output-dir`, outputs go to `shaders` in
+/// Cargo's `OUT_DIR`, out of the source tree.
+///
+/// ```toml
+/// input-dir = "shaders"
+/// output-dir = "src/shaders"
+/// search-paths = ["../common/shaders"]
+/// optimization = "maximal"
+/// include = ["**/*.slang"]
+/// exclude = ["common/**"]
+///
+/// [defines]
+/// MAX_LIGHTS = "16"
+///
+/// [[targets]]
+/// format = "spirv"
+///
+/// [[targets]]
+/// format = "spirv-asm"
+/// output-dir = "asm"
+///
+/// [[overrides]]
+/// files = "debug_*.slang"
+/// optimization = "default"
+/// defines = { DEBUG = "1" }
+///
+/// # Compiled once per combination of values. Constants are
+/// # declared `extern static const bool BORDER;` in the module.
+/// [[permutations]]
+/// files = "rounded_rectangle_*.slang"
+/// defines = { FILL = ["SOLID", "GRADIENT"] }
+/// constants = { BORDER = [false, true] }
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+pub(crate) struct ConfigFile {
+    input_dir: PathBuf,
+    output_dir: Option<PathBuf>,
+    #[serde(default)]
+    search_paths: Vec<PathBuf>,
+    #[serde(default = "default_optimization")]
+    optimization: OptimizationLevel,
+    #[serde(default)]
+    defines: BTreeMap<String, String>,
+    #[serde(default = "default_include")]
+    include: Vec<String>,
+    #[serde(default)]
+    exclude: Vec<String>,
+    #[serde(default = "default_targets")]
+    targets: Vec<TargetEntry>,
+    #[serde(default)]
+    overrides: Vec<OverrideEntry>,
+    #[serde(default)]
+    permutations: Vec<PermutationEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct TargetEntry {
+    format: TargetFormat,
+    profile: Option<String>,
+    /// Relative to the output directory.
+    output_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct OverrideEntry {
+    files: String,
+    optimization: Option<OptimizationLevel>,
+    #[serde(default)]
+    defines: BTreeMap<String, String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct PermutationEntry {
+    files: String,
+    #[serde(default)]
+    defines: BTreeMap<String, Vec<Value>>,
+    #[serde(default)]
+    constants: BTreeMap<String, Vec<Value>>,
+}
+
+fn default_optimization() -> OptimizationLevel {
+    OptimizationLevel::Default
+}
+
+fn default_include() -> Vec<String> {
+    vec!["*.slang".to_owned()]
+}
+
+fn default_targets() -> Vec<TargetEntry> {
+    vec![TargetEntry {
+        format: TargetFormat::Spirv,
+        profile: None,
+        output_dir: None,
+    }]
+}
+
+impl ConfigFile {
+    pub(crate) const FILE_NAME: &'static str =
+        "slang-build.toml";
+
+    pub(crate) fn load(path: &Path) -> crate::Result<Settings> {
+        let text = std::fs::read_to_string(path)
+            .map_err(crate::Error::io(path))?;
+
+        let file = toml::from_str::<Self>(&text).map_err(
+            |source| crate::Error::ConfigFile {
+                path: path.to_owned(),
+                source,
+            },
+        )?;
+
+        let settings = file.settings(path)?;
+        settings.validate()?;
+
+        Ok(settings)
+    }
+
+    fn settings(self, path: &Path) -> crate::Result<Settings> {
+        let dir = path.parent().unwrap_or(Path::new(""));
+        let input_dir = dir.join(self.input_dir);
+        let output_dir = match self.output_dir {
+            Some(output_dir) => dir.join(output_dir),
+            None => std::env::var_os("OUT_DIR")
+                .map(|dir| PathBuf::from(dir).join("shaders"))
+                .ok_or(crate::Error::MissingEnvironment(
+                    "OUT_DIR",
+                ))?,
+        };
+
+        let globs = |patterns: Vec<String>| {
+            patterns
+                .iter()
+                .map(|pattern| {
+                    Glob::new(pattern)
+                        .map_err(crate::Error::InvalidConfig)
+                })
+                .collect::<crate::Result<Vec<_>>>()
+        };
+
+        let overrides = self
+            .overrides
+            .into_iter()
+            .map(|entry| {
+                Ok(Override {
+                    files: Glob::new(&entry.files)
+                        .map_err(crate::Error::InvalidConfig)?,
+                    optimization: entry.optimization,
+                    defines: entry.defines,
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        let permutations = self
+            .permutations
+            .into_iter()
+            .map(|entry| {
+                let options =
+                    |kind, options: BTreeMap<_, _>| {
+                        options.into_iter().map(
+                            move |(name, values)| {
+                                PermutationOption {
+                                    name,
+                                    kind,
+                                    values,
+                                }
+                            },
+                        )
+                    };
+
+                Ok(Permutations {
+                    files: Glob::new(&entry.files)
+                        .map_err(crate::Error::InvalidConfig)?,
+                    options: options(
+                        OptionKind::Define,
+                        entry.defines,
+                    )
+                    .chain(options(
+                        OptionKind::Constant,
+                        entry.constants,
+                    ))
+                    .collect(),
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Settings {
+            search_paths: std::iter::once(input_dir.clone())
+                .chain(
+                    self.search_paths.iter().map(
+                        |search_path| dir.join(search_path),
+                    ),
+                )
+                .collect(),
+            input_dir,
+            optimization: self.optimization,
+            defines: self.defines,
+            include: globs(self.include)?,
+            exclude: globs(self.exclude)?,
+            targets: self
+                .targets
+                .into_iter()
+                .map(|target| TargetSettings {
+                    format: target.format,
+                    profile: target.profile,
+                    output_dir: match target.output_dir {
+                        Some(target_dir) => {
+                            output_dir.join(target_dir)
+                        }
+                        None => output_dir.clone(),
+                    },
+                })
+                .collect(),
+            overrides,
+            permutations,
+            file: Some(path.to_owned()),
+            output_dir,
+        })
+    }
+}
+
+#[test]
+fn test_config_file() {
+    let file = toml::from_str::<ConfigFile>(
+        r#"
+        input-dir = "shaders"
+        output-dir = "src/shaders"
+        optimization = "maximal"
+        include = ["**/*.slang"]
+        exclude = ["common/**"]
+
+        [defines]
+        MAX_LIGHTS = "16"
+
+        [[targets]]
+        format = "spirv-asm"
+        output-dir = "asm"
+
+        [[overrides]]
+        files = "debug_*.slang"
+        optimization = "default"
+        defines = { DEBUG = "1" }
+
+        [[permutations]]
+        files = "rounded_*.slang"
+        defines = { FILL = ["SOLID", "GRADIENT"] }
+        constants = { BORDER = [false, true] }
+        "#,
+    )
+    .unwrap();
+
+    let settings = file
+        .settings(Path::new("/crate/slang-build.toml"))
+        .unwrap();
+
+    assert_eq!(settings.input_dir, Path::new("/crate/shaders"));
+    assert_eq!(settings.targets.len(), 1);
+    assert_eq!(
+        settings.targets[0].output_dir,
+        Path::new("/crate/src/shaders/asm")
+    );
+    assert!(settings.is_included("deep/quad.slang"));
+    assert!(!settings.is_included("common/color.slang"));
+
+    let (optimization, defines) =
+        settings.module_options("debug_quad.slang");
+
+    assert_eq!(optimization, OptimizationLevel::Default);
+    assert_eq!(defines.len(), 2);
+    assert_eq!(
+        settings.module_options("quad.slang").0,
+        OptimizationLevel::Maximal
+    );
+    assert!(
+        settings.module_permutations("quad.slang").is_empty()
+    );
+
+    let options =
+        settings.module_permutations("rounded_fill.slang");
+
+    assert_eq!(options.len(), 2);
+    assert_eq!(options[0].name, "FILL");
+    assert_eq!(options[1].kind, OptionKind::Constant);
+    assert!(
+        toml::from_str::<ConfigFile>(
+            "input-dir = \"a\"\noutput-dir = \"b\"\n\
+             optimisation = \"high\""
+        )
+        .is_err()
+    );
+}
@@ -0,0 +1,282 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::registry::EntryPoint;
+
+/// What the previous build produced, kept in `OUT_DIR` so that
+/// only modules whose sources, includes or outputs changed are
+/// compiled again.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub(crate) struct DependencyManifest {
+    /// Compiler options the records were built with. Any change
+    /// invalidates every module.
+    #[serde(default)]
+    options: String,
+    #[serde(default)]
+    modules: BTreeMap<String, ModuleRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ModuleRecord {
+    /// Hash of the module source and all of its includes.
+    pub(crate) input_hash: String,
+    /// Transitive includes, for review and debugging.
+    pub(crate) includes: Vec<PathBuf>,
+    pub(crate) entry_points: Vec<EntryPoint>,
+    pub(crate) outputs: Vec<OutputRecord>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct OutputRecord {
+    pub(crate) path: PathBuf,
+    pub(crate) hash: String,
+}
+
+impl DependencyManifest {
+    const FILE_NAME: &'static str = "slang-build-deps.toml";
+
+    /// Reads the manifest in `dir`. A missing or unreadable
+    /// manifest gives an empty one, rebuilding everything.
+    pub(crate) fn load(dir: &Path, options: &str) -> Self {
+        let manifest =
+            std::fs::read_to_string(dir.join(Self::FILE_NAME))
+                .ok()
+                .and_then(|text| {
+                    toml::from_str::<Self>(&text).ok()
+                })
+                .unwrap_or_default();
+
+        if manifest.options == options {
+            manifest
+        } else {
+            Self {
+                options: options.to_owned(),
+                modules: BTreeMap::new(),
+            }
+        }
+    }
+
+    pub(crate) fn save(&self, dir: &Path) -> crate::Result<()> {
+        let path = dir.join(Self::FILE_NAME);
+
+        std::fs::write(&path, toml::to_string(self)?)
+            .map_err(crate::Error::io(path))
+    }
+
+    /// Whether `module` has to be compiled again: it's new, its
+    /// inputs changed, or one of its outputs is missing or was
+    /// modified.
+    pub(crate) fn is_outdated(
+        &self,
+        module: &str,
+        input_hash: &str,
+    ) -> bool {
+        let Some(record) = self.modules.get(module) else {
+            return true;
+        };
+
+        record.input_hash != input_hash
+            || record.outputs.iter().any(|output| {
+                std::fs::read(&output.path)
+                    .map(|bytes| hash(&bytes) != output.hash)
+                    .unwrap_or(true)
+            })
+    }
+
+    pub(crate) fn insert(
+        &mut self,
+        module: String,
+        record: ModuleRecord,
+    ) {
+        self.modules.insert(module, record);
+    }
+
+    pub(crate) fn module(
+        &self,
+        module: &str,
+    ) -> Option<&ModuleRecord> {
+        self.modules.get(module)
+    }
+
+    /// Entry points of all modules, as of the last compile of
+    /// each.
+    pub(crate) fn entry_points(
+        &self,
+    ) -> impl Iterator<Item = &EntryPoint> {
+        self.modules
+            .values()
+            .flat_map(|module| module.entry_points.iter())
+    }
+
+    /// Drops records of modules that no longer exist.
+    pub(crate) fn retain<'a>(
+        &mut self,
+        modules: impl IntoIterator<Item = &'a str>,
+    ) {
+        let modules =
+            modules.into_iter().collect::<BTreeSet<_>>();
+
+        self.modules.retain(|module, _| {
+            modules.contains(module.as_str())
+        });
+    }
+}
+
+/// 64-bit FNV-1a as hex. Unlike `DefaultHasher` it's stable
+/// between compiler versions, which the manifest relies on.
+pub(crate) fn hash(bytes: &[u8]) -> String {
+    let hash = bytes.iter().fold(
+        0xcbf2_9ce4_8422_2325_u64,
+        |hash, &byte| {
+            (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+        },
+    );
+
+    format!("{hash:016x}")
+}
+
+/// Files `source` includes or imports, directly or through
+/// other includes, sorted. Paths are resolved against the
+/// including file's directory first, then `search_paths`, like
+/// Slang does. Includes that can't be found are left for the
+/// compiler to report.
+pub(crate) fn includes(
+    source: &Path,
+    search_paths: &[&Path],
+) -> BTreeSet<PathBuf> {
+    let mut found = BTreeSet::new();
+    let mut pending = vec![source.to_path_buf()];
+
+    while let Some(file) = pending.pop() {
+        let Ok(text) = std::fs::read_to_string(&file) else {
+            continue;
+        };
+
+        let directory = file.parent().unwrap_or(Path::new(""));
+
+        for name in text.lines().filter_map(include_name) {
+            let resolved = std::iter::once(directory)
+                .chain(search_paths.iter().copied())
+                .flat_map(|dir| {
+                    name.candidates()
+                        .map(move |name| dir.join(name))
+                })
+                .find(|path| path.is_file());
+
+            let Some(path) = resolved else {
+                continue;
+            };
+
+            if found.insert(path.clone()) {
+                pending.push(path);
+            }
+        }
+    }
+
+    found
+}
+
+/// File name referenced by an include or import.
+enum IncludeName<'a> {
+    /// `#include "file.slang"` and `import "file.slang";`.
+    Path(&'a str),
+    /// `import common.utils;`, a module name that Slang looks
+    /// up as `common/utils.slang`, with `_` also tried as `-`.
+    Module(&'a str),
+}
+
+impl IncludeName<'_> {
+    fn candidates(&self) -> impl Iterator<Item = String> {
+        let (exact, dashed) = match *self {
+            Self::Path(path) => (path.to_owned(), None),
+            Self::Module(module) => {
+                let path = format!(
+                    "{}.slang",
+                    module.replace('.', "/")
+                );
+                let dashed = path.replace('_', "-");
+
+                (
+                    path.clone(),
+                    (dashed != path).then_some(dashed),
+                )
+            }
+        };
+
+        std::iter::once(exact).chain(dashed)
+    }
+}
+
+fn include_name(line: &str) -> Option<IncludeName<'_>> {
+    let line = line.trim_start();
+
+    if let Some(rest) = line.strip_prefix("#include") {
+        let rest = rest.trim();
+        let (open, close) = match rest.chars().next()? {
+            '"' => ('"', '"'),
+            '<' => ('<', '>'),
+            _ => return None,
+        };
+
+        let path = rest.strip_prefix(open)?;
+
+        return Some(IncludeName::Path(
+            &path[..path.find(close)?],
+        ));
+    }
+
+    let rest = line
+        .strip_prefix("import")
+        .or_else(|| line.strip_prefix("__include"))?;
+
+    if !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let rest = rest.trim_start();
+
+    if let Some(path) = rest.strip_prefix('"') {
+        return Some(IncludeName::Path(
+            &path[..path.find('"')?],
+        ));
+    }
+
+    let module = &rest[..rest.find(';')?];
+
+    Some(IncludeName::Module(module.trim()))
+}
+
+#[test]
+fn test_include_name() {
+    let path = |line| match include_name(line) {
+        Some(name) => name.candidates().collect::<Vec<_>>(),
+        None => Vec::new(),
+    };
+
+    assert_eq!(
+        path("#include \"common/color.slang\""),
+        ["common/color.slang"],
+    );
+    assert_eq!(
+        path("  #include <utils.slang>"),
+        ["utils.slang"]
+    );
+    assert_eq!(
+        path("import common.vertex_with_uv;"),
+        [
+            "common/vertex_with_uv.slang",
+            "common/vertex-with-uv.slang"
+        ],
+    );
+    assert_eq!(
+        path("__include \"paint.slang\";"),
+        ["paint.slang"]
+    );
+    assert!(path("important = 1;").is_empty());
+    assert!(path("// #include \"x.slang\"").is_empty());
+    assert_eq!(hash(b""), "cbf29ce484222325");
+}
@@ -0,0 +1,233 @@
+use std::{fmt, path::PathBuf};
+
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord,
+)]
+pub enum Severity {
+    Note,
+    Warning,
+    Error,
+}
+
+/// One message of the Slang diagnostic output.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub path: PathBuf,
+    pub line: u32,
+    /// Taken from the caret under the quoted source line. `None`
+    /// when Slang didn't quote the source.
+    pub column: Option<u32>,
+    pub severity: Severity,
+    /// Slang diagnostic number, like `30015`.
+    pub code: Option<u32>,
+    pub message: String,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Note => "note",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// `path:line:column: severity[code]: message`, the format
+/// editors and terminals turn into links.
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.path.display(), self.line)?;
+
+        if let Some(column) = self.column {
+            write!(f, ":{column}")?;
+        }
+
+        write!(f, ": {}", self.severity)?;
+
+        if let Some(code) = self.code {
+            write!(f, "[E{code}]")?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl Diagnostic {
+    /// The diagnostic followed by the line of the shader source it
+    /// points at, with a caret under the column.
+    pub fn render(&self) -> String {
+        let mut rendered = self.to_string();
+
+        let source_line = std::fs::read_to_string(&self.path)
+            .ok()
+            .and_then(|source| {
+                let line = self.line.checked_sub(1)?;
+
+                source
+                    .lines()
+                    .nth(line as usize)
+                    .map(str::to_owned)
+            });
+
+        let Some(source_line) = source_line else {
+            return rendered;
+        };
+
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+
+        rendered.push_str(&format!(
+            "\n{gutter} |\n{number} | {source_line}"
+        ));
+
+        if let Some(column) = self.column {
+            let indent = source_line
+                .chars()
+                .take(column.saturating_sub(1) as usize)
+                .map(|c| if c == '\t' { '\t' } else { ' ' })
+                .collect::<String>();
+
+            rendered
+                .push_str(&format!("\n{gutter} | {indent}^"));
+        }
+
+        rendered
+    }
+}
+
+/// Parses the output of Slang. Lines that aren't part of a
+/// diagnostic are skipped.
+pub(crate) fn parse(output: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::<Diagnostic>::new();
+    let mut quoted_line = false;
+
+    for line in output.lines() {
+        if let Some(diagnostic) = parse_header(line) {
+            diagnostics.push(diagnostic);
+            quoted_line = false;
+            continue;
+        }
+
+        let Some(last) = diagnostics.last_mut() else {
+            continue;
+        };
+
+        // Slang quotes the source line, then puts a caret line
+        // under it.
+        if !quoted_line {
+            quoted_line = true;
+            continue;
+        }
+
+        let caret = line.trim_end();
+
+        if last.column.is_none()
+            && caret.trim_start().starts_with('^')
+        {
+            let column =
+                caret.chars().take_while(|c| *c != '^');
+
+            last.column = Some(column.count() as u32 + 1);
+        }
+    }
+
+    diagnostics
+}
+
+/// `path(line): severity code: message`, also accepting
+/// `path(line,column)` and severities without a code.
+fn parse_header(line: &str) -> Option<Diagnostic> {
+    let (location, rest) = line.split_once("): ")?;
+    let (path, position) = location.rsplit_once('(')?;
+
+    let (line, column) = match position.split_once(',') {
+        Some((line, column)) => {
+            (line, Some(column.trim().parse().ok()?))
+        }
+        None => (position, None),
+    };
+
+    let (severity, message) = rest.split_once(": ")?;
+
+    let (severity, code) = match severity.rsplit_once(' ') {
+        Some((name, code))
+            if code.chars().all(|c| c.is_ascii_digit()) =>
+        {
+            (name, Some(code.parse().ok()?))
+        }
+        _ => (severity, None),
+    };
+
+    let severity = match severity {
+        "note" => Severity::Note,
+        "warning" => Severity::Warning,
+        "error" | "fatal error" | "internal error" => {
+            Severity::Error
+        }
+        _ => return None,
+    };
+
+    Some(Diagnostic {
+        path: PathBuf::from(path),
+        line: line.trim().parse().ok()?,
+        column,
+        severity,
+        code,
+        message: message.to_owned(),
+    })
+}
+
+/// Errors of a failed compile, each pointing at the source, or
+/// the whole output when none could be parsed.
+pub(crate) fn render(
+    diagnostics: &[Diagnostic],
+    output: &str,
+) -> String {
+    let errors = diagnostics
+        .iter()
+        .filter(|diagnostic| {
+            diagnostic.severity == Severity::Error
+        })
+        .map(Diagnostic::render)
+        .collect::<Vec<_>>();
+
+    if errors.is_empty() {
+        output.trim_end().to_owned()
+    } else {
+        errors.join("\n\n")
+    }
+}
+
+#[test]
+fn test_parse() {
+    let output = "\
+shaders/quad.slang(12): error 30015: undefined identifier 'uvv'.
+    output.uv = uvv;
+                ^~~
+shaders/quad.slang(3,5): warning 15205: unused variable
+(0): fatal error: compilation ceased
+";
+
+    let diagnostics = parse(output);
+
+    assert_eq!(diagnostics.len(), 3);
+    assert_eq!(
+        diagnostics[0],
+        Diagnostic {
+            path: PathBuf::from("shaders/quad.slang"),
+            line: 12,
+            column: Some(17),
+            severity: Severity::Error,
+            code: Some(30015),
+            message: "undefined identifier 'uvv'.".to_owned(),
+        }
+    );
+    assert_eq!(
+        diagnostics[1].to_string(),
+        "shaders/quad.slang:3:5: warning[E15205]: unused variable"
+    );
+    assert_eq!(diagnostics[2].severity, Severity::Error);
+    assert_eq!(diagnostics[2].code, None);
+    assert!(parse("import common;").is_empty());
+}
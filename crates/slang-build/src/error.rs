@@ -0,0 +1,93 @@
+use std::path::PathBuf;
+
+use crate::diagnostics::Diagnostic;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Environment variable {0} is not set")]
+    MissingEnvironment(&'static str),
+    #[error("Failed to access `{}`: {source}", path.display())]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+    #[error("Path `{}` is not valid UTF-8 without nul bytes", .0.display())]
+    InvalidPath(PathBuf),
+    #[error("Invalid `{}`: {source}", path.display())]
+    ConfigFile {
+        path: PathBuf,
+        source: toml::de::Error,
+    },
+    #[error("Invalid configuration: {0}")]
+    InvalidConfig(String),
+    #[error("Failed to write dependency manifest: {0}")]
+    Manifest(#[from] toml::ser::Error),
+    #[error(
+        "Slang function named {function_name} returned error code {code:#010x}"
+    )]
+    SlangError {
+        function_name: &'static str,
+        code: u32,
+    },
+    #[error("Slang function named {0} returned null")]
+    SlangNull(&'static str),
+    #[error("Unknown Slang profile `{0}`")]
+    UnknownProfile(String),
+    #[error("Entry point without a name")]
+    EmptyEntryPointName,
+    #[error("Entry point `{0}` has an unsupported stage")]
+    UnsupportedStage(String),
+    #[error(
+        "Modules `{}` and `{}` are both named `{name}`, rename one of them",
+        first.display(),
+        second.display()
+    )]
+    DuplicateModule {
+        name: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
+    #[error(
+        "Entry points `{first}` and `{second}` both generate `{name}` for the {stage} stage, rename one of them"
+    )]
+    DuplicateEntryPoint {
+        name: String,
+        stage: String,
+        first: String,
+        second: String,
+    },
+    #[error(
+        "Field `{name}::{field}` overlaps the field before it"
+    )]
+    OverlappingField { name: String, field: String },
+    #[error(
+        "SPIR-V of entry point `{0}` has no `OpEntryPoint`"
+    )]
+    MissingSpirvEntryPoint(String),
+    #[error("Kernel `{entry_point}`: {reason}")]
+    HostKernel { entry_point: String, reason: String },
+    #[error(
+        "Failed to compile shaders:\n{}",
+        crate::diagnostics::render(.diagnostics, .output)
+    )]
+    Compile {
+        /// Parsed errors, if any.
+        diagnostics: Vec<Diagnostic>,
+        /// Everything Slang printed, for when nothing could be
+        /// parsed.
+        output: String,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, self::Error>;
+
+impl Error {
+    /// [`Error::Io`] for `path`, for use with `map_err`.
+    pub(crate) fn io(
+        path: impl Into<PathBuf>,
+    ) -> impl FnOnce(std::io::Error) -> Self {
+        let path = path.into();
+
+        move |source| Self::Io { path, source }
+    }
+}
@@ -0,0 +1,111 @@
+/// Pattern over `/`-separated paths relative to the input
+/// directory. `*` and `?` match within one path segment and a
+/// `**` segment matches any number of segments.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Glob {
+    segments: Box<[Box<[char]>]>,
+}
+
+impl Glob {
+    pub(crate) fn new(pattern: &str) -> Result<Self, String> {
+        if pattern.is_empty() {
+            return Err("Empty pattern".to_owned());
+        }
+
+        if pattern.contains('\\') {
+            return Err(format!(
+                "Pattern `{pattern}` must use `/` as separator"
+            ));
+        }
+
+        let segments = pattern
+            .split('/')
+            .map(|segment| {
+                if segment.is_empty() {
+                    return Err(format!(
+                        "Pattern `{pattern}` has an empty segment"
+                    ));
+                }
+
+                if segment.contains("**") && segment != "**" {
+                    return Err(format!(
+                        "`**` must be a whole segment in `{pattern}`"
+                    ));
+                }
+
+                Ok(segment.chars().collect())
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { segments })
+    }
+
+    pub(crate) fn matches(&self, path: &str) -> bool {
+        let path = path
+            .split('/')
+            .map(|segment| segment.chars().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        match_segments(&self.segments, &path)
+    }
+}
+
+fn match_segments(
+    pattern: &[Box<[char]>],
+    path: &[Vec<char>],
+) -> bool {
+    let Some((segment, pattern_rest)) = pattern.split_first()
+    else {
+        return path.is_empty();
+    };
+
+    if **segment == ['*', '*'] {
+        return (0..=path.len()).any(|skipped| {
+            match_segments(pattern_rest, &path[skipped..])
+        });
+    }
+
+    match path.split_first() {
+        Some((name, path_rest)) => {
+            match_segment(segment, name)
+                && match_segments(pattern_rest, path_rest)
+        }
+        None => false,
+    }
+}
+
+fn match_segment(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skipped| {
+            match_segment(rest, &name[skipped..])
+        }),
+        Some(('?', rest)) => {
+            !name.is_empty() && match_segment(rest, &name[1..])
+        }
+        Some((c, rest)) => {
+            name.first() == Some(c)
+                && match_segment(rest, &name[1..])
+        }
+    }
+}
+
+#[test]
+fn test_glob() {
+    let matches = |pattern, path| {
+        Glob::new(pattern).unwrap().matches(path)
+    };
+
+    assert!(matches("*.slang", "quad_emit_uv.slang"));
+    assert!(!matches("*.slang", "common/color.slang"));
+    assert!(matches("**/*.slang", "quad_emit_uv.slang"));
+    assert!(matches("**/*.slang", "common/deep/color.slang"));
+    assert!(matches("common/**", "common/deep/color.slang"));
+    assert!(matches(
+        "rounded_?_fill.slang",
+        "rounded_x_fill.slang"
+    ));
+    assert!(!matches("*.slang", "quad.glsl"));
+    assert!(Glob::new("a/**b").is_err());
+    assert!(Glob::new("a//b").is_err());
+}
@@ -0,0 +1,284 @@
+use std::{
+    ffi::c_void, fmt::Debug, marker::PhantomData, path::Path,
+};
+
+use crate::{
+    builder::path_to_cstring,
+    slang::{
+        ComputeVaryingInput, HostBuffer, HostCallable, Slang,
+    },
+};
+
+/// Compute shader compiled for and run on the CPU, for unit
+/// testing shader math against a Rust implementation without a
+/// GPU. Slang compiles it with the C++ compiler it finds, such
+/// as Clang, GCC or MSVC.
+///
+/// The kernel reads `inputs` and writes one output per input to
+/// `outputs`, with one thread per group:
+///
+/// ```slang
+/// #include "common/utils.slang"
+///
+/// StructuredBuffer<float3> inputs;
+/// RWStructuredBuffer<float> outputs;
+///
+/// [shader("compute")]
+/// [numthreads(1, 1, 1)]
+/// void median_kernel(uint3 id: SV_DispatchThreadID)
+/// {
+///     outputs[id.x] = median(inputs[id.x]);
+/// }
+/// ```
+///
+/// ```ignore
+/// let kernel = HostKernel::<[f32; 3], f32>::compile(
+///     "shaders/tests/median.slang",
+///     "median_kernel",
+///     &[Path::new("shaders")],
+/// )?;
+///
+/// kernel.assert_reference(&inputs, median, 1e-6);
+/// ```
+pub struct HostKernel<I, O> {
+    entry_point: String,
+    callable: HostCallable,
+    /// Dropped after `callable`.
+    _slang: Slang,
+    _data: PhantomData<fn(I) -> O>,
+}
+
+/// Type with the same layout in Rust as in the C++ Slang
+/// generates, like `f32` for `float` and `[f32; 3]` for
+/// `float3`.
+///
+/// # Safety
+///
+/// Structs have to be `#[repr(C)]` with fields in the order of
+/// the Slang struct, and all zeros has to be a valid value.
+pub unsafe trait HostData: Copy + 'static {}
+
+unsafe impl HostData for f32 {}
+unsafe impl HostData for i32 {}
+unsafe impl HostData for u32 {}
+unsafe impl<T: HostData, const N: usize> HostData for [T; N] {}
+
+/// Output compared with a reference by the largest difference
+/// of its components.
+pub trait Approx {
+    /// `NaN` if either side has a `NaN`.
+    fn difference(&self, other: &Self) -> f32;
+}
+
+impl Approx for f32 {
+    fn difference(&self, other: &Self) -> f32 {
+        (self - other).abs()
+    }
+}
+
+impl Approx for i32 {
+    fn difference(&self, other: &Self) -> f32 {
+        self.abs_diff(*other) as f32
+    }
+}
+
+impl Approx for u32 {
+    fn difference(&self, other: &Self) -> f32 {
+        self.abs_diff(*other) as f32
+    }
+}
+
+impl<T: Approx, const N: usize> Approx for [T; N] {
+    fn difference(&self, other: &Self) -> f32 {
+        self.iter().zip(other).fold(0.0, |max, (a, b)| {
+            let difference = a.difference(b);
+
+            if difference > max || difference.is_nan() {
+                difference
+            } else {
+                max
+            }
+        })
+    }
+}
+
+impl<I: HostData, O: HostData> HostKernel<I, O> {
+    /// Compiles `entry_point` of the module at `path`, with
+    /// includes and imports looked up in `search_paths`.
+    pub fn compile(
+        path: impl AsRef<Path>,
+        entry_point: &str,
+        search_paths: &[&Path],
+    ) -> crate::Result<Self> {
+        let slang = Slang::new()?;
+        let compile_request = slang.create_compile_request(
+            crate::OptimizationLevel::Default,
+        )?;
+
+        for search_path in search_paths {
+            compile_request.add_search_path(&path_to_cstring(
+                search_path,
+            )?);
+        }
+
+        let unit_index =
+            compile_request.add_translation_unit(c"kernel");
+
+        compile_request.add_translation_unit_source_file(
+            unit_index,
+            &path_to_cstring(path.as_ref())?,
+        );
+
+        let callable = compile_request
+            .compile_host_callable(entry_point)?;
+
+        let sizes = [
+            ("inputs", callable.inputs, size_of::<I>()),
+            ("outputs", callable.outputs, size_of::<O>()),
+        ];
+
+        for (name, buffer, size) in sizes {
+            if buffer.element_size != size {
+                return Err(crate::Error::HostKernel {
+                    entry_point: entry_point.to_owned(),
+                    reason: format!(
+                        "Elements of `{name}` are {} bytes, the \
+                         Rust type {size}",
+                        buffer.element_size,
+                    ),
+                });
+            }
+        }
+
+        Ok(Self {
+            entry_point: entry_point.to_owned(),
+            callable,
+            _slang: slang,
+            _data: PhantomData,
+        })
+    }
+
+    /// Runs the kernel once per input, with an error if there
+    /// are more inputs than groups of a dispatch.
+    pub fn run(&self, inputs: &[I]) -> crate::Result<Vec<O>> {
+        let group_count = u32::try_from(inputs.len()).map_err(
+            |_| crate::Error::HostKernel {
+                entry_point: self.entry_point.clone(),
+                reason: format!(
+                    "{} inputs are more than the {} groups of a \
+                     dispatch",
+                    inputs.len(),
+                    u32::MAX,
+                ),
+            },
+        )?;
+
+        // All zeros is a valid `HostData` value.
+        let mut outputs =
+            vec![
+                unsafe { std::mem::zeroed::<O>() };
+                inputs.len()
+            ];
+
+        // `u64`s keep the pointers in it aligned.
+        let mut globals =
+            vec![0u64; self.callable.globals_size.div_ceil(8)];
+
+        let buffers = [
+            (
+                self.callable.inputs,
+                inputs.as_ptr().cast::<c_void>(),
+            ),
+            (
+                self.callable.outputs,
+                outputs
+                    .as_mut_ptr()
+                    .cast::<c_void>()
+                    .cast_const(),
+            ),
+        ];
+
+        for (buffer, data) in buffers {
+            // At an offset within `globals_size`, as reflected by
+            // Slang.
+            unsafe {
+                globals
+                    .as_mut_ptr()
+                    .cast::<u8>()
+                    .add(buffer.offset)
+                    .cast::<HostBuffer>()
+                    .write_unaligned(HostBuffer {
+                        data,
+                        count: inputs.len(),
+                    });
+            }
+        }
+
+        let groups = ComputeVaryingInput {
+            start_group_id: [0; 3],
+            end_group_id: [group_count, 1, 1],
+        };
+
+        unsafe {
+            (self.callable.function)(
+                &groups,
+                std::ptr::null_mut(),
+                globals.as_mut_ptr().cast(),
+            )
+        };
+
+        Ok(outputs)
+    }
+
+    /// Runs the kernel and panics, listing the inputs, outputs
+    /// and expected outputs, if any output is further than
+    /// `tolerance` from what `reference` returns for its input,
+    /// or if it fails to run.
+    #[track_caller]
+    pub fn assert_reference(
+        &self,
+        inputs: &[I],
+        reference: impl Fn(&I) -> O,
+        tolerance: f32,
+    ) where
+        I: Debug,
+        O: Approx + Debug,
+    {
+        let outputs =
+            self.run(inputs).unwrap_or_else(|error| {
+                panic!("{error}");
+            });
+
+        let mismatches = inputs
+            .iter()
+            .zip(outputs)
+            .filter_map(|(input, output)| {
+                let expected = reference(input);
+                let difference = output.difference(&expected);
+
+                (difference.is_nan() || difference > tolerance)
+                    .then(|| {
+                        format!(
+                            "    {input:?}: {output:?}, \
+                             expected {expected:?}"
+                        )
+                    })
+            })
+            .collect::<Vec<_>>();
+
+        assert!(
+            mismatches.is_empty(),
+            "`{}` differs from the reference for:\n{}",
+            self.entry_point,
+            mismatches.join("\n"),
+        );
+    }
+}
+
+#[test]
+fn test_approx() {
+    assert_eq!(1.5f32.difference(&1.0), 0.5);
+    assert_eq!([1.0f32, 4.0].difference(&[1.0, 2.0]), 2.0);
+    assert!([f32::NAN, 4.0].difference(&[1.0, 4.0]).is_nan());
+    assert_eq!(3u32.difference(&5), 2.0);
+}
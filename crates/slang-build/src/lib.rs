@@ -1,6 +1,22 @@
 mod builder;
 mod config;
+mod config_file;
+mod dependencies;
+mod diagnostics;
+mod error;
+mod glob;
+mod host;
+mod output_manifest;
+mod permutations;
+mod reflection;
+mod registry;
 mod slang;
+mod uniforms;
 
 pub use builder::Builder;
-pub use config::{Config, OptimizationLevel};
+pub use config::{
+    Config, OptimizationLevel, Target, TargetFormat,
+};
+pub use diagnostics::{Diagnostic, Severity};
+pub use error::{Error, Result};
+pub use host::{Approx, HostData, HostKernel};
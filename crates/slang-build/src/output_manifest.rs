@@ -0,0 +1,158 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use serde::Serialize;
+
+use crate::{
+    permutations::Value,
+    reflection::Reflection,
+    registry::{EntryPoint, Stage},
+};
+
+/// `shaders.toml` in the output directory: the entry points of
+/// every module, their stages and the files they were written
+/// to, for tools and for review.
+///
+/// ```toml
+/// [modules.quad_emit_uv]
+/// source = "quad_emit_uv.slang"
+///
+/// [[modules.quad_emit_uv.entry-points]]
+/// name = "quad_emit_uv"
+/// stage = "vertex"
+/// outputs = ["quad_emit_uv/quad_emit_uv.spv"]
+///
+/// [[modules.quad_emit_uv.entry-points.reflection.vertex-inputs]]
+/// name = "position"
+/// location = 0
+/// scalar = "f32"
+/// components = 2
+/// ```
+#[derive(Debug, Default, Serialize)]
+pub(crate) struct OutputManifest<'a> {
+    modules: BTreeMap<&'a str, ModuleOutputs<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct ModuleOutputs<'a> {
+    /// Relative to the input directory.
+    source: PathBuf,
+    entry_points: Vec<EntryPointOutputs<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct EntryPointOutputs<'a> {
+    name: &'a str,
+    stage: Stage,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    permutation: BTreeMap<&'a str, &'a Value>,
+    /// Relative to the output directory, one per target.
+    outputs: Vec<PathBuf>,
+    reflection: &'a Reflection,
+}
+
+impl<'a> OutputManifest<'a> {
+    pub(crate) const FILE_NAME: &'static str = "shaders.toml";
+
+    /// Adds the module `name` compiled from `source`. Paths are
+    /// made relative to `input_dir` and `output_dir`, where they
+    /// are under them.
+    pub(crate) fn insert(
+        &mut self,
+        name: &'a str,
+        source: &Path,
+        entry_points: impl IntoIterator<Item = &'a EntryPoint>,
+        input_dir: &Path,
+        output_dir: &Path,
+    ) {
+        let relative = |path: &Path, dir: &Path| {
+            path.strip_prefix(dir).unwrap_or(path).to_path_buf()
+        };
+
+        let entry_points = entry_points
+            .into_iter()
+            .map(|entry| EntryPointOutputs {
+                name: &entry.name,
+                stage: entry.stage,
+                permutation: entry
+                    .permutation
+                    .iter()
+                    .map(|(option, value)| {
+                        (option.as_str(), value)
+                    })
+                    .collect(),
+                outputs: entry
+                    .outputs
+                    .iter()
+                    .map(|output| relative(output, output_dir))
+                    .collect(),
+                reflection: &entry.reflection,
+            })
+            .collect();
+
+        self.modules.insert(
+            name,
+            ModuleOutputs {
+                source: relative(source, input_dir),
+                entry_points,
+            },
+        );
+    }
+
+    pub(crate) fn source(&self) -> crate::Result<String> {
+        Ok(format!(
+            "# Generated by slang-build. Do not edit.\n\n{}",
+            toml::to_string(self)?
+        ))
+    }
+}
+
+#[test]
+fn test_output_manifest() {
+    let entry_point = EntryPoint {
+        name: "quad_emit_uv".to_owned(),
+        stage: Stage::Vertex,
+        module: "quad_emit_uv".to_owned(),
+        permutation: vec![(
+            "BORDER".to_owned(),
+            Value::Bool(true),
+        )],
+        outputs: vec![PathBuf::from(
+            "/crate/src/shaders/quad_emit_uv/quad_emit_uv.spv",
+        )],
+        code_name: "main".to_owned(),
+        reflection: Reflection {
+            vertex_inputs: vec![
+                crate::reflection::VertexInput {
+                    name: "position".to_owned(),
+                    location: 0,
+                    scalar: crate::uniforms::Scalar::F32,
+                    components: 2,
+                },
+            ],
+            ..Reflection::default()
+        },
+    };
+
+    let mut manifest = OutputManifest::default();
+    manifest.insert(
+        "quad_emit_uv",
+        Path::new("/crate/shaders/quad_emit_uv.slang"),
+        [&entry_point],
+        Path::new("/crate/shaders"),
+        Path::new("/crate/src/shaders"),
+    );
+
+    let source = manifest.source().unwrap();
+
+    assert!(source.contains("source = \"quad_emit_uv.slang\""));
+    assert!(source.contains("stage = \"vertex\""));
+    assert!(source.contains(
+        "outputs = [\"quad_emit_uv/quad_emit_uv.spv\"]"
+    ));
+    assert!(source.contains("BORDER = true"));
+    assert!(source.contains("scalar = \"f32\""));
+}
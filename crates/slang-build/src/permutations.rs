@@ -0,0 +1,401 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{glob::Glob, registry::pascal_case};
+
+/// Value of a permutation option.
+#[derive(
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(untagged)]
+pub(crate) enum Value {
+    Bool(bool),
+    Integer(i64),
+    String(String),
+}
+
+impl Value {
+    /// Value of a `#define`. Booleans are `1` and `0` so that
+    /// `#if` works on them.
+    pub(crate) fn define(&self) -> String {
+        match self {
+            Self::Bool(value) => (*value as u8).to_string(),
+            Self::Integer(value) => value.to_string(),
+            Self::String(value) => value.clone(),
+        }
+    }
+
+    /// Initializer of an `export static const`.
+    fn constant(&self) -> Option<String> {
+        match self {
+            Self::Bool(value) => Some(value.to_string()),
+            Self::Integer(value) => Some(value.to_string()),
+            Self::String(_) => None,
+        }
+    }
+
+    /// Part of the names of the outputs and generated ids.
+    pub(crate) fn name(&self) -> String {
+        match self {
+            Self::Bool(value) => value.to_string(),
+            Self::Integer(value) => {
+                format!("v{value}").replace('-', "neg")
+            }
+            Self::String(value) => crate::uniforms::identifier(
+                &value.to_lowercase(),
+            ),
+        }
+    }
+}
+
+/// Whether an option is passed to the preprocessor or linked in
+/// as `export static const`, for modules that declare it with
+/// `extern static const`.
+#[derive(
+    Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub(crate) enum OptionKind {
+    Define,
+    Constant,
+}
+
+/// Option a module is compiled with once per value.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub(crate) struct PermutationOption {
+    pub(crate) name: String,
+    pub(crate) kind: OptionKind,
+    pub(crate) values: Vec<Value>,
+}
+
+/// Options of the modules matching `files`.
+#[derive(Debug)]
+pub(crate) struct Permutations {
+    pub(crate) files: Glob,
+    pub(crate) options: Vec<PermutationOption>,
+}
+
+/// One value per option, in the order of the options.
+pub(crate) type Permutation = Vec<(String, Value)>;
+
+impl PermutationOption {
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        let name = &self.name;
+
+        if self.values.is_empty() {
+            return Err(format!(
+                "Option `{name}` has no values"
+            ));
+        }
+
+        let kinds =
+            |value: &Value| std::mem::discriminant(value);
+
+        if self
+            .values
+            .iter()
+            .any(|value| kinds(value) != kinds(&self.values[0]))
+        {
+            return Err(format!(
+                "Values of option `{name}` have different types"
+            ));
+        }
+
+        if self.kind == OptionKind::Constant
+            && self.values[0].constant().is_none()
+        {
+            return Err(format!(
+                "Constant `{name}` must be a boolean or an integer"
+            ));
+        }
+
+        for (index, value) in self.values.iter().enumerate() {
+            let duplicate = self.values[..index]
+                .iter()
+                .any(|other| other.name() == value.name());
+
+            if duplicate || value.name().is_empty() {
+                return Err(format!(
+                    "Option `{name}` has the value {value:?} twice \
+                     or one without a usable name"
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn field(&self) -> String {
+        crate::uniforms::identifier(&self.name.to_lowercase())
+    }
+
+    /// Rust type of the key field. Booleans stay `bool`, other
+    /// options get an enum of their values.
+    fn rust_type(&self) -> String {
+        match self.values[0] {
+            Value::Bool(_) => "bool".to_owned(),
+            _ => pascal_case(&self.field()),
+        }
+    }
+
+    /// Expression or pattern of `value`, with the path of the
+    /// generated module of the enum as `prefix`.
+    fn pattern(&self, prefix: &str, value: &Value) -> String {
+        match value {
+            Value::Bool(value) => value.to_string(),
+            _ => format!(
+                "{prefix}{}::{}",
+                self.rust_type(),
+                pascal_case(&value.name()),
+            ),
+        }
+    }
+}
+
+/// Every combination of one value per option, the first option
+/// changing slowest. No options give the one empty permutation.
+pub(crate) fn combinations(
+    options: &[PermutationOption],
+) -> Vec<Permutation> {
+    options.iter().fold(
+        vec![Vec::new()],
+        |permutations, option| {
+            permutations
+                .iter()
+                .flat_map(|permutation| {
+                    option.values.iter().map(|value| {
+                        let mut permutation =
+                            permutation.clone();
+                        permutation.push((
+                            option.name.clone(),
+                            value.clone(),
+                        ));
+                        permutation
+                    })
+                })
+                .collect()
+        },
+    )
+}
+
+/// Source of the translation unit that gives the constants of
+/// `permutation` their values, or `None` when it has none.
+pub(crate) fn constants_source(
+    options: &[PermutationOption],
+    permutation: &Permutation,
+) -> Option<String> {
+    let mut source = String::new();
+
+    for (option, (name, value)) in
+        options.iter().zip(permutation)
+    {
+        if option.kind == OptionKind::Define {
+            continue;
+        }
+
+        let ty = match value {
+            Value::Bool(_) => "bool",
+            _ => "int",
+        };
+
+        // Strings are rejected by `PermutationOption::validate`.
+        let Some(constant) = value.constant() else {
+            continue;
+        };
+
+        writeln!(
+            source,
+            "export static const {ty} {name} = {constant};"
+        )
+        .unwrap();
+    }
+
+    (!source.is_empty()).then_some(source)
+}
+
+/// `keys` module of the registry: a module per permuted shader
+/// module with a `Key` struct, an enum per non-boolean option
+/// and `Key::ALL`.
+pub(crate) fn keys_source<'a>(
+    modules: impl IntoIterator<
+        Item = (&'a str, &'a [PermutationOption]),
+    >,
+) -> String {
+    let mut source = String::from("\npub mod keys {\n");
+
+    for (module, options) in modules {
+        if options.is_empty() {
+            continue;
+        }
+
+        writeln!(source, "    pub mod {module} {{").unwrap();
+
+        for option in options.iter() {
+            if matches!(option.values[0], Value::Bool(_)) {
+                continue;
+            }
+
+            write!(
+                source,
+                "        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n        \
+                 pub enum {} {{\n",
+                option.rust_type(),
+            )
+            .unwrap();
+
+            for value in option.values.iter() {
+                writeln!(
+                    source,
+                    "            {},",
+                    pascal_case(&value.name())
+                )
+                .unwrap();
+            }
+
+            source.push_str("        }\n\n");
+        }
+
+        source.push_str(
+            "        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n        \
+             pub struct Key {\n",
+        );
+
+        for option in options.iter() {
+            writeln!(
+                source,
+                "            pub {}: {},",
+                option.field(),
+                option.rust_type(),
+            )
+            .unwrap();
+        }
+
+        source.push_str(
+            "        }\n\n        \
+             impl Key {\n            \
+                 pub const ALL: &[Self] = &[\n",
+        );
+
+        for permutation in combinations(options) {
+            let fields = options
+                .iter()
+                .zip(permutation.iter())
+                .map(|(option, (_, value))| {
+                    format!(
+                        "{}: {}",
+                        option.field(),
+                        option.pattern("", value)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            writeln!(
+                source,
+                "                Self {{ {fields} }},"
+            )
+            .unwrap();
+        }
+
+        source.push_str("            ];\n        }\n    }\n");
+    }
+
+    source.push_str("}\n");
+    source
+}
+
+/// Arm of the generated lookup from a key to an id.
+pub(crate) fn key_pattern(
+    module: &str,
+    options: &[PermutationOption],
+    permutation: &Permutation,
+) -> String {
+    let values = options
+        .iter()
+        .zip(permutation.iter())
+        .map(|(option, (_, value))| {
+            option.pattern(
+                &format!("super::keys::{module}::"),
+                value,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    format!("({},)", values.join(", "))
+}
+
+/// Tuple of the key fields the lookup matches on.
+pub(crate) fn key_fields(
+    options: &[PermutationOption],
+) -> String {
+    let fields = options
+        .iter()
+        .map(|option| format!("key.{}", option.field()))
+        .collect::<Vec<_>>();
+
+    format!("({},)", fields.join(", "))
+}
+
+#[test]
+fn test_permutations() {
+    let options = [
+        PermutationOption {
+            name: "BORDER".to_owned(),
+            kind: OptionKind::Constant,
+            values: vec![Value::Bool(false), Value::Bool(true)],
+        },
+        PermutationOption {
+            name: "FILL".to_owned(),
+            kind: OptionKind::Define,
+            values: vec![
+                Value::String("SOLID".to_owned()),
+                Value::String("GRADIENT".to_owned()),
+            ],
+        },
+    ];
+
+    let permutations = combinations(&options);
+
+    assert_eq!(permutations.len(), 4);
+    assert_eq!(
+        permutations[1],
+        [
+            ("BORDER".to_owned(), Value::Bool(false)),
+            (
+                "FILL".to_owned(),
+                Value::String("GRADIENT".to_owned())
+            ),
+        ]
+    );
+    assert_eq!(
+        constants_source(&options, &permutations[3]).unwrap(),
+        "export static const bool BORDER = true;\n"
+    );
+    assert_eq!(
+        key_pattern("rounded", &options, &permutations[3]),
+        "(true, super::keys::rounded::Fill::Gradient,)"
+    );
+    assert!(keys_source([("rounded", &options[..])]).contains(
+        "Self { border: false, fill: Fill::Solid },"
+    ));
+    assert_eq!(Value::Integer(-2).name(), "vneg2");
+    assert!(options[0].validate().is_ok());
+    assert!(
+        PermutationOption {
+            kind: OptionKind::Constant,
+            ..options[1].clone()
+        }
+        .validate()
+        .is_err()
+    );
+}
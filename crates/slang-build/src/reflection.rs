@@ -0,0 +1,296 @@
+use std::fmt::Write;
+
+use serde::{Deserialize, Serialize};
+
+use crate::uniforms::Scalar;
+
+/// What an entry point binds and reads, reflected by Slang, for
+/// creating descriptor set layouts, push constant ranges and
+/// vertex input state without repeating the shader in Rust.
+#[derive(
+    Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct Reflection {
+    /// Sorted by set and binding.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) bindings: Vec<DescriptorBinding>,
+    /// Bytes of push constants, `0` without any.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) push_constant_size: usize,
+    /// Vertex shader inputs, sorted by location.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) vertex_inputs: Vec<VertexInput>,
+    /// Threads per workgroup of compute, task and mesh shaders.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) workgroup_size: Option<[u32; 3]>,
+}
+
+/// Descriptor of a global or entry point parameter. Resources
+/// in constant buffers and parameter blocks are listed on their
+/// own, after the buffer that holds their ordinary data.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct DescriptorBinding {
+    /// Path of the parameter, like `material.albedo`.
+    pub(crate) name: String,
+    pub(crate) set: u32,
+    pub(crate) binding: u32,
+    pub(crate) kind: DescriptorKind,
+    /// Length of descriptor arrays, `0` for unsized ones.
+    pub(crate) count: u32,
+    /// Bytes of ordinary data of uniform buffers, `0` for other
+    /// descriptors.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) size: usize,
+}
+
+/// Kind of resource a binding holds.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum DescriptorKind {
+    Sampler,
+    CombinedImageSampler,
+    SampledImage,
+    StorageImage,
+    UniformTexelBuffer,
+    StorageTexelBuffer,
+    UniformBuffer,
+    StorageBuffer,
+    InputAttachment,
+    AccelerationStructure,
+}
+
+impl DescriptorKind {
+    /// `VkDescriptorType` of the kind.
+    const fn vk_type(self) -> i32 {
+        match self {
+            Self::Sampler => 0,
+            Self::CombinedImageSampler => 1,
+            Self::SampledImage => 2,
+            Self::StorageImage => 3,
+            Self::UniformTexelBuffer => 4,
+            Self::StorageTexelBuffer => 5,
+            Self::UniformBuffer => 6,
+            Self::StorageBuffer => 7,
+            Self::InputAttachment => 10,
+            Self::AccelerationStructure => 1_000_150_000,
+        }
+    }
+}
+
+/// Input of a vertex shader. Matrices and arrays take one
+/// input per location.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) struct VertexInput {
+    /// Path of the parameter, like `input.position`.
+    pub(crate) name: String,
+    pub(crate) location: u32,
+    pub(crate) scalar: Scalar,
+    /// `1` for scalars.
+    pub(crate) components: u32,
+}
+
+impl VertexInput {
+    /// `VkFormat` of an attribute with the input's type, `None`
+    /// for types vertex buffers can't hold.
+    pub(crate) const fn vk_format(&self) -> Option<i32> {
+        let components = self.components as usize;
+
+        if components == 0 || components > 4 {
+            return None;
+        }
+
+        // `R<n>_UINT` with the scalar's size and the component
+        // count, followed by `SINT` and then `SFLOAT`. Formats
+        // with 8-bit components aren't evenly spaced.
+        let uint = match self.scalar {
+            Scalar::U8 | Scalar::I8 => {
+                [13, 20, 27, 41][components - 1]
+            }
+            Scalar::U16 | Scalar::I16 | Scalar::F16 => {
+                74 + 7 * (components as i32 - 1)
+            }
+            Scalar::U32 | Scalar::I32 | Scalar::F32 => {
+                98 + 3 * (components as i32 - 1)
+            }
+            Scalar::U64 | Scalar::I64 | Scalar::F64 => {
+                110 + 3 * (components as i32 - 1)
+            }
+            Scalar::Bool => return None,
+        };
+
+        Some(match self.scalar {
+            Scalar::I8
+            | Scalar::I16
+            | Scalar::I32
+            | Scalar::I64 => uint + 1,
+            Scalar::F16 | Scalar::F32 | Scalar::F64 => uint + 2,
+            _ => uint,
+        })
+    }
+
+    /// Bytes the input takes in a tightly packed vertex.
+    pub(crate) const fn size(&self) -> usize {
+        self.scalar.size() * self.components as usize
+    }
+}
+
+fn is_zero(value: &usize) -> bool {
+    *value == 0
+}
+
+/// Header of the generated `shaders.rs` with the types the
+/// reflection of every `ShaderSource` is made of.
+pub(crate) const PRELUDE: &str = "
+/// Descriptor an entry point binds, see `ShaderSource::bindings`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DescriptorBinding {
+    pub name: &'static str,
+    pub set: u32,
+    pub binding: u32,
+    /// `VkDescriptorType` of the binding.
+    pub descriptor_type: i32,
+    /// Length of descriptor arrays, `0` for unsized ones.
+    pub count: u32,
+    /// Bytes of ordinary data of uniform buffers.
+    pub size: u32,
+}
+
+/// Input of a vertex shader, see `ShaderSource::vertex_inputs`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VertexInput {
+    pub name: &'static str,
+    pub location: u32,
+    /// `VkFormat` of the input.
+    pub format: i32,
+    /// Bytes the input takes in a tightly packed vertex.
+    pub size: u32,
+}
+";
+
+/// Fields of the generated `ShaderSource` constant that hold
+/// `reflection`, indented for its initializer.
+pub(crate) fn fields_source(reflection: &Reflection) -> String {
+    let mut source = String::from("bindings: &[\n");
+
+    for binding in reflection.bindings.iter() {
+        writeln!(
+            source,
+            "            super::DescriptorBinding {{ \
+             name: {:?}, set: {}, binding: {}, \
+             descriptor_type: {}, count: {}, size: {} }},",
+            binding.name,
+            binding.set,
+            binding.binding,
+            binding.kind.vk_type(),
+            binding.count,
+            binding.size,
+        )
+        .unwrap();
+    }
+
+    write!(
+        source,
+        "        ],\n        \
+         push_constant_size: {},\n        \
+         vertex_inputs: &[\n",
+        reflection.push_constant_size,
+    )
+    .unwrap();
+
+    // Inputs without a vertex format, like `bool`s, are left
+    // out.
+    for input in reflection.vertex_inputs.iter() {
+        let Some(format) = input.vk_format() else {
+            continue;
+        };
+
+        writeln!(
+            source,
+            "            super::VertexInput {{ \
+             name: {:?}, location: {}, format: {format}, \
+             size: {} }},",
+            input.name,
+            input.location,
+            input.size(),
+        )
+        .unwrap();
+    }
+
+    write!(
+        source,
+        "        ],\n        \
+         workgroup_size: {:?},",
+        reflection.workgroup_size.unwrap_or([0; 3]),
+    )
+    .unwrap();
+
+    source
+}
+
+#[test]
+fn test_reflection() {
+    let input = |scalar, components| VertexInput {
+        name: "position".to_owned(),
+        location: 0,
+        scalar,
+        components,
+    };
+
+    // R32G32B32A32_SFLOAT, R32G32_SINT, R16_UINT, R8G8B8A8_SINT
+    // and R64G64B64_SFLOAT.
+    assert_eq!(input(Scalar::F32, 4).vk_format(), Some(109));
+    assert_eq!(input(Scalar::I32, 2).vk_format(), Some(102));
+    assert_eq!(input(Scalar::U16, 1).vk_format(), Some(74));
+    assert_eq!(input(Scalar::I8, 4).vk_format(), Some(42));
+    assert_eq!(input(Scalar::F64, 3).vk_format(), Some(118));
+    assert_eq!(input(Scalar::F16, 2).vk_format(), Some(83));
+    assert_eq!(input(Scalar::Bool, 1).vk_format(), None);
+    assert_eq!(input(Scalar::F32, 3).size(), 12);
+
+    let reflection = Reflection {
+        bindings: vec![DescriptorBinding {
+            name: "paint".to_owned(),
+            set: 0,
+            binding: 1,
+            kind: DescriptorKind::UniformBuffer,
+            count: 1,
+            size: 64,
+        }],
+        vertex_inputs: vec![input(Scalar::F32, 2)],
+        ..Reflection::default()
+    };
+
+    let source = fields_source(&reflection);
+
+    assert!(source.contains(
+        "name: \"paint\", set: 0, binding: 1, \
+         descriptor_type: 6, count: 1, size: 64 },"
+    ));
+    assert!(source.contains(
+        "name: \"position\", location: 0, format: 103, size: 8 },"
+    ));
+    assert!(source.contains("workgroup_size: [0, 0, 0],"));
+    assert!(
+        toml::to_string(&reflection)
+            .unwrap()
+            .contains("kind = \"uniform-buffer\"")
+    );
+}
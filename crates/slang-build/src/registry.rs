@@ -0,0 +1,686 @@
+use std::{fmt::Write, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    permutations::{self, Permutation, PermutationOption},
+    reflection::{self, Reflection},
+};
+
+/// Pipeline stage of an entry point.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub(crate) enum Stage {
+    Vertex,
+    TessellationControl,
+    TessellationEvaluation,
+    Geometry,
+    Fragment,
+    Compute,
+    Task,
+    Mesh,
+    RayGeneration,
+    AnyHit,
+    ClosestHit,
+    Miss,
+    Intersection,
+    Callable,
+}
+
+impl Stage {
+    /// Name of the generated module and id type.
+    const fn names(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Vertex => ("vs", "VertexShaderId"),
+            Self::TessellationControl => {
+                ("tcs", "TessellationControlShaderId")
+            }
+            Self::TessellationEvaluation => {
+                ("tes", "TessellationEvaluationShaderId")
+            }
+            Self::Geometry => ("gs", "GeometryShaderId"),
+            Self::Fragment => ("fs", "FragmentShaderId"),
+            Self::Compute => ("cs", "ComputeShaderId"),
+            Self::Task => ("ts", "TaskShaderId"),
+            Self::Mesh => ("ms", "MeshShaderId"),
+            Self::RayGeneration => {
+                ("rgen", "RayGenerationShaderId")
+            }
+            Self::AnyHit => ("rahit", "AnyHitShaderId"),
+            Self::ClosestHit => ("rchit", "ClosestHitShaderId"),
+            Self::Miss => ("rmiss", "MissShaderId"),
+            Self::Intersection => {
+                ("rint", "IntersectionShaderId")
+            }
+            Self::Callable => ("rcall", "CallableShaderId"),
+        }
+    }
+
+    /// `VkShaderStageFlagBits` of the stage.
+    const fn vk_flags(self) -> u32 {
+        match self {
+            Self::Vertex => 0x1,
+            Self::TessellationControl => 0x2,
+            Self::TessellationEvaluation => 0x4,
+            Self::Geometry => 0x8,
+            Self::Fragment => 0x10,
+            Self::Compute => 0x20,
+            Self::Task => 0x40,
+            Self::Mesh => 0x80,
+            Self::RayGeneration => 0x100,
+            Self::AnyHit => 0x200,
+            Self::ClosestHit => 0x400,
+            Self::Miss => 0x800,
+            Self::Intersection => 0x1000,
+            Self::Callable => 0x2000,
+        }
+    }
+}
+
+/// Compiled entry point and the files its code was written to,
+/// one per target in the order of the targets.
+#[derive(
+    Clone, Debug, PartialEq, Eq, Serialize, Deserialize,
+)]
+pub(crate) struct EntryPoint {
+    pub(crate) name: String,
+    pub(crate) stage: Stage,
+    /// Rust name of the shader module.
+    pub(crate) module: String,
+    /// Values of the options of the module this entry point was
+    /// compiled with.
+    #[serde(default)]
+    pub(crate) permutation: Permutation,
+    pub(crate) outputs: Vec<PathBuf>,
+    /// Name of the entry point in its compiled code, read back
+    /// from the SPIR-V since Slang renames entry points to `main`
+    /// there by default. Required, like `reflection`.
+    pub(crate) code_name: String,
+    /// Required, so that manifests of builds from before it was
+    /// recorded fail to load and everything is compiled again.
+    pub(crate) reflection: Reflection,
+}
+
+impl EntryPoint {
+    /// Name of the entry point with the values of its
+    /// permutation, like `quad_fill_border_true`, used for its
+    /// outputs and generated id.
+    pub(crate) fn id_name(&self) -> String {
+        self.with_permutation(self.name.clone())
+    }
+
+    /// Name of the entry point prefixed with its module unless
+    /// they are the same, like `quad_emit_uv` for the entry
+    /// point of the module of that name or `blur_main` for
+    /// `main` of `blur`, used for the generated key lookup.
+    pub(crate) fn qualified_name(&self) -> String {
+        if self.name == self.module {
+            self.name.clone()
+        } else {
+            format!("{}_{}", self.module, self.name)
+        }
+    }
+
+    /// [`EntryPoint::qualified_name`] with the values of its
+    /// permutation, used for the generated id and constant so
+    /// that modules can export entry points of the same name.
+    pub(crate) fn generated_name(&self) -> String {
+        self.with_permutation(self.qualified_name())
+    }
+
+    fn with_permutation(&self, mut name: String) -> String {
+        for (option, value) in self.permutation.iter() {
+            write!(
+                name,
+                "_{}_{}",
+                option.to_lowercase(),
+                value.name()
+            )
+            .unwrap();
+        }
+
+        name
+    }
+}
+
+/// Name of the first `OpEntryPoint` of a SPIR-V module, `None`
+/// if it has none or isn't valid little-endian SPIR-V.
+pub(crate) fn spirv_entry_point_name(
+    code: &[u8],
+) -> Option<String> {
+    const MAGIC: u32 = 0x0723_0203;
+    const OP_ENTRY_POINT: u32 = 15;
+
+    let words = code
+        .chunks_exact(4)
+        .map(|word| {
+            u32::from_le_bytes([
+                word[0], word[1], word[2], word[3],
+            ])
+        })
+        .collect::<Vec<_>>();
+
+    if words.first() != Some(&MAGIC) {
+        return None;
+    }
+
+    // Instructions follow the five words of the header.
+    let mut rest = words.get(5..)?;
+
+    while let Some(&first) = rest.first() {
+        let count = (first >> 16) as usize;
+
+        if count == 0 || count > rest.len() {
+            return None;
+        }
+
+        let (instruction, next) = rest.split_at(count);
+
+        // Execution model, function id and the nul-terminated
+        // name.
+        if first & 0xffff == OP_ENTRY_POINT {
+            let name = instruction
+                .get(3..)?
+                .iter()
+                .flat_map(|word| word.to_le_bytes())
+                .take_while(|&byte| byte != 0)
+                .collect::<Vec<_>>();
+
+            return String::from_utf8(name).ok();
+        }
+
+        rest = next;
+    }
+
+    None
+}
+
+/// Checks that no two entry points of a stage generate the same
+/// id or key lookup, which would not compile. Ids are compared
+/// as generated, so `foo_bar` and `fooBar` collide.
+pub(crate) fn check_duplicates<'a>(
+    entry_points: impl IntoIterator<Item = &'a EntryPoint>,
+) -> crate::Result<()> {
+    let mut names = std::collections::BTreeMap::new();
+
+    for entry in entry_points {
+        let mut generated =
+            vec![pascal_case(&entry.generated_name())];
+
+        if !entry.permutation.is_empty() {
+            // The lookup function of the key, shared by the
+            // permutations of the entry point.
+            generated
+                .push(format!("fn {}", entry.qualified_name()));
+        }
+
+        let owner =
+            (entry.module.as_str(), entry.name.as_str());
+
+        for name in generated {
+            match names
+                .insert((entry.stage, name.clone()), owner)
+            {
+                Some(first) if first != owner => {
+                    return Err(
+                        crate::Error::DuplicateEntryPoint {
+                            name,
+                            stage: format!("{:?}", entry.stage),
+                            first: format!(
+                                "{}::{}",
+                                first.0, first.1
+                            ),
+                            second: format!(
+                                "{}::{}",
+                                owner.0, owner.1
+                            ),
+                        },
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Header of the generated `shaders.rs`, before the modules.
+const PRELUDE: &str = "\
+// Generated by slang-build. Do not edit.
+
+/// Compiled entry point, with its SPIR-V embedded.
+#[derive(Clone, Copy, Debug)]
+pub struct ShaderSource<K> {
+    pub id: K,
+    /// Name of the entry point in the Slang source.
+    pub name: &'static str,
+    /// Name of the entry point in the SPIR-V module.
+    pub entry_point: &'static std::ffi::CStr,
+    /// `VkShaderStageFlags` of the entry point.
+    pub stage_flags: u32,
+    pub code: &'static [u32],
+    /// Descriptors of the whole module, sorted by set and binding.
+    pub bindings: &'static [DescriptorBinding],
+    /// Bytes of push constants, `0` without any.
+    pub push_constant_size: u32,
+    /// Inputs of vertex shaders, sorted by location.
+    pub vertex_inputs: &'static [VertexInput],
+    /// Threads per workgroup of compute, task and mesh shaders,
+    /// zeros for other stages.
+    pub workgroup_size: [u32; 3],
+}
+
+#[repr(C, align(4))]
+struct Aligned<T: ?Sized>(T);
+
+const fn words(bytes: &'static Aligned<[u8]>) -> &'static [u32] {
+    let bytes = &bytes.0;
+
+    assert!(bytes.len().is_multiple_of(4));
+
+    // `Aligned` keeps the bytes aligned for `u32`.
+    unsafe {
+        std::slice::from_raw_parts(
+            bytes.as_ptr().cast(),
+            bytes.len() / 4,
+        )
+    }
+}
+";
+
+/// Source of the generated registry: a module per stage with an
+/// id enum and a `ShaderSource` constant per entry point.
+/// Vertex and fragment modules are always there, even if empty,
+/// because the renderer names them.
+///
+/// `spirv` is the index of the SPIR-V target in the outputs of
+/// every entry point. Without one the modules are all empty.
+///
+/// Ids, constants and functions are named after
+/// [`EntryPoint::generated_name`] and
+/// [`EntryPoint::qualified_name`]. Permuted entry points get an
+/// id per permutation, and a function that maps a key of
+/// `keys`, the options of each module, to the id.
+pub(crate) fn source<'a>(
+    entry_points: impl IntoIterator<Item = &'a EntryPoint>,
+    spirv: Option<usize>,
+    keys: &[(&str, &[PermutationOption])],
+) -> String {
+    let mut entry_points = entry_points
+        .into_iter()
+        .filter_map(|entry| {
+            Some((entry, entry.outputs.get(spirv?)?))
+        })
+        .collect::<Vec<_>>();
+    entry_points.sort_by_cached_key(|(entry, _)| {
+        (entry.stage, entry.generated_name())
+    });
+
+    let mut source = PRELUDE.to_owned();
+    source.push_str(reflection::PRELUDE);
+    source.push_str(&permutations::keys_source(
+        keys.iter().copied(),
+    ));
+
+    let mut stages = entry_points
+        .iter()
+        .map(|(entry, _)| entry.stage)
+        .collect::<Vec<_>>();
+    stages.extend([Stage::Vertex, Stage::Fragment]);
+    stages.sort();
+    stages.dedup();
+
+    for stage in stages {
+        let (module, id) = stage.names();
+        let entries = entry_points
+            .iter()
+            .filter(|(entry, _)| entry.stage == stage)
+            .collect::<Vec<_>>();
+
+        write!(
+            source,
+            "\npub mod {module} {{\n    \
+                 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n    \
+                 pub enum {id} {{\n"
+        )
+        .unwrap();
+
+        for (entry, _) in entries.iter() {
+            writeln!(
+                source,
+                "        {},",
+                pascal_case(&entry.generated_name())
+            )
+            .unwrap();
+        }
+
+        write!(
+            source,
+            "    }}\n\n    \
+                 impl {id} {{\n        \
+                     pub const ALL: &[Self] = &[\n"
+        )
+        .unwrap();
+
+        for (entry, _) in entries.iter() {
+            writeln!(
+                source,
+                "            Self::{},",
+                pascal_case(&entry.generated_name())
+            )
+            .unwrap();
+        }
+
+        write!(
+            source,
+            "        ];\n\n        \
+                     pub const fn source(self) -> super::ShaderSource<Self> {{\n            \
+                         match self {{\n"
+        )
+        .unwrap();
+
+        for (entry, _) in entries.iter() {
+            writeln!(
+                source,
+                "                Self::{} => {},",
+                pascal_case(&entry.generated_name()),
+                entry.generated_name().to_uppercase(),
+            )
+            .unwrap();
+        }
+
+        source.push_str("            }\n        }\n");
+
+        for (module, options) in keys.iter() {
+            let mut names = entries
+                .iter()
+                .filter(|(entry, _)| entry.module == *module)
+                .map(|(entry, _)| entry.name.as_str())
+                .collect::<Vec<_>>();
+            names.sort_unstable();
+            names.dedup();
+
+            for name in names {
+                let permuted = entries
+                    .iter()
+                    .filter(|(entry, _)| {
+                        entry.module == *module
+                            && entry.name == name
+                    })
+                    .collect::<Vec<_>>();
+
+                // Modules compiled with other options than the
+                // current ones get no lookup rather than a
+                // non-exhaustive one.
+                if options.is_empty()
+                    || permuted.len()
+                        != permutations::combinations(options)
+                            .len()
+                {
+                    continue;
+                }
+
+                write!(
+                    source,
+                    "\n        \
+                     pub const fn {}(key: super::keys::{module}::Key) -> Self {{\n            \
+                         match {} {{\n",
+                    permuted[0].0.qualified_name(),
+                    permutations::key_fields(options),
+                )
+                .unwrap();
+
+                for (entry, _) in permuted {
+                    writeln!(
+                        source,
+                        "                {} => Self::{},",
+                        permutations::key_pattern(
+                            module,
+                            options,
+                            &entry.permutation
+                        ),
+                        pascal_case(&entry.generated_name()),
+                    )
+                    .unwrap();
+                }
+
+                source.push_str("            }\n        }\n");
+            }
+        }
+
+        source.push_str("    }\n");
+
+        for (entry, output) in entries.iter() {
+            let constant =
+                entry.generated_name().to_uppercase();
+
+            write!(
+                source,
+                "\n    \
+                 const {constant}_CODE: &super::Aligned<[u8]> =\n        \
+                     &super::Aligned(*include_bytes!({:?}));\n\n    \
+                 pub const {constant}: super::ShaderSource<{id}> = super::ShaderSource {{\n        \
+                     id: {id}::{},\n        \
+                     name: {:?},\n        \
+                     entry_point: c{:?},\n        \
+                     stage_flags: {:#x},\n        \
+                     code: super::words({constant}_CODE),\n        \
+                     {}\n    \
+                 }};\n",
+                output,
+                pascal_case(&entry.generated_name()),
+                entry.name,
+                entry.code_name,
+                stage.vk_flags(),
+                reflection::fields_source(&entry.reflection),
+            )
+            .unwrap();
+        }
+
+        source.push_str("}\n");
+    }
+
+    source
+}
+
+pub(crate) fn pascal_case(name: &str) -> String {
+    name.split('_')
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+#[test]
+fn test_registry_source() {
+    use crate::permutations::{OptionKind, Value};
+
+    let border = [PermutationOption {
+        name: "BORDER".to_owned(),
+        kind: OptionKind::Define,
+        values: vec![Value::Bool(false), Value::Bool(true)],
+    }];
+    let fill = |value| EntryPoint {
+        name: "rounded_rectangle_color_fill".to_owned(),
+        stage: Stage::Fragment,
+        module: "rounded_rectangle_color_fill".to_owned(),
+        permutation: vec![(
+            "BORDER".to_owned(),
+            Value::Bool(value),
+        )],
+        outputs: vec![PathBuf::from(format!(
+            "shaders/rounded_rectangle_color_fill_border_{value}.spvasm"
+        ))],
+        code_name: "main".to_owned(),
+        reflection: Reflection::default(),
+    };
+    let entry_points = [
+        EntryPoint {
+            name: "quad_emit_uv".to_owned(),
+            stage: Stage::Vertex,
+            module: "quad_emit_uv".to_owned(),
+            permutation: Vec::new(),
+            outputs: vec![
+                PathBuf::from("shaders/quad_emit_uv.spvasm"),
+                PathBuf::from("shaders/quad_emit_uv.spv"),
+            ],
+            code_name: "quad_emit_uv".to_owned(),
+            reflection: Reflection::default(),
+        },
+        fill(true),
+        fill(false),
+    ];
+    let source = source(
+        &entry_points,
+        Some(1),
+        &[("rounded_rectangle_color_fill", &border)],
+    );
+
+    assert!(source.contains(
+        "pub enum VertexShaderId {\n        QuadEmitUv,\n"
+    ));
+    assert!(source.contains("pub const QUAD_EMIT_UV: super::ShaderSource<VertexShaderId>"));
+    assert!(source.contains(
+        "include_bytes!(\"shaders/quad_emit_uv.spv\")"
+    ));
+    assert!(source.contains("entry_point: c\"quad_emit_uv\","));
+    assert!(
+        source.contains("pub enum FragmentShaderId {\n    }")
+    );
+    assert!(!source.contains("pub mod cs"));
+    assert!(
+        source
+            .contains("pub mod rounded_rectangle_color_fill {")
+    );
+
+    let source = crate::registry::source(
+        &entry_points,
+        Some(0),
+        &[("rounded_rectangle_color_fill", &border)],
+    );
+
+    assert!(source.contains(
+        "RoundedRectangleColorFillBorderFalse,\n        \
+         RoundedRectangleColorFillBorderTrue,"
+    ));
+    assert!(source.contains(
+        "(true,) => Self::RoundedRectangleColorFillBorderTrue,"
+    ));
+    assert!(check_duplicates(&entry_points).is_ok());
+    assert_eq!(
+        pascal_case("rounded_rectangle_fill"),
+        "RoundedRectangleFill"
+    );
+
+    // Header, `OpCapability Shader` and `OpEntryPoint Vertex %1
+    // "main"`.
+    let spirv = [
+        0x0723_0203,
+        0x0001_0000,
+        0,
+        2,
+        0,
+        0x0002_0011,
+        1,
+        0x0005_000f,
+        0,
+        1,
+        u32::from_le_bytes(*b"main"),
+        0,
+    ]
+    .iter()
+    .flat_map(|word: &u32| word.to_le_bytes())
+    .collect::<Vec<_>>();
+
+    assert_eq!(
+        spirv_entry_point_name(&spirv).as_deref(),
+        Some("main")
+    );
+    assert_eq!(spirv_entry_point_name(&spirv[..28]), None);
+    assert_eq!(spirv_entry_point_name(b"not spirv"), None);
+}
+
+#[test]
+fn test_registry_module_names() {
+    use crate::permutations::{OptionKind, Value};
+
+    let border = [PermutationOption {
+        name: "BORDER".to_owned(),
+        kind: OptionKind::Define,
+        values: vec![Value::Bool(false), Value::Bool(true)],
+    }];
+    let entry =
+        |module: &str, name: &str, border: Option<bool>| {
+            EntryPoint {
+                name: name.to_owned(),
+                stage: Stage::Fragment,
+                module: module.to_owned(),
+                permutation: border
+                    .map(|value| {
+                        vec![(
+                            "BORDER".to_owned(),
+                            Value::Bool(value),
+                        )]
+                    })
+                    .unwrap_or_default(),
+                outputs: vec![PathBuf::from(format!(
+                    "shaders/{module}/{name}.spv"
+                ))],
+                code_name: "main".to_owned(),
+                reflection: Reflection::default(),
+            }
+        };
+
+    // Modules exporting `main` for the same stage.
+    let entry_points = [
+        entry("blur", "main", Some(false)),
+        entry("blur", "main", Some(true)),
+        entry("glow", "main", None),
+    ];
+
+    assert!(check_duplicates(&entry_points).is_ok());
+
+    let source =
+        source(&entry_points, Some(0), &[("blur", &border)]);
+
+    assert!(source.contains(
+        "pub enum FragmentShaderId {\n        \
+         BlurMainBorderFalse,\n        \
+         BlurMainBorderTrue,\n        \
+         GlowMain,\n"
+    ));
+    assert!(source.contains("pub const GLOW_MAIN: super::ShaderSource<FragmentShaderId>"));
+    assert!(source.contains(
+        "pub const fn blur_main(key: super::keys::blur::Key)"
+    ));
+
+    // Different names generating the same id.
+    let entry_points = [
+        entry("shapes", "foo_bar", None),
+        entry("shapes", "fooBar", None),
+    ];
+
+    assert!(matches!(
+        check_duplicates(&entry_points),
+        Err(crate::Error::DuplicateEntryPoint { name, .. })
+            if name == "ShapesFooBar"
+    ));
+}
@@ -5,10 +5,21 @@ use super::{
     compile_target::SlangCompileTarget,
     global_session::IGlobalSessionRef,
     global_session_desc::SlangGlobalSessionDesc,
+    matrix_layout_mode::SlangMatrixLayoutMode,
     optimization_level::SlangOptimizationLevel,
+    parameter_category::SlangParameterCategory,
     reflect::SlangReflectionRef,
     reflection_entry_point::SlangReflectionEntryPointRef,
-    result::SlangResult, source_language::SlangSourceLanguage,
+    reflection_type::SlangReflectionTypeRef,
+    reflection_type_layout::SlangReflectionTypeLayoutRef,
+    reflection_variable::SlangReflectionVariableRef,
+    reflection_variable_layout::SlangReflectionVariableLayoutRef,
+    resource_access::SlangResourceAccess,
+    resource_shape::SlangResourceShape, result::SlangResult,
+    scalar_type::SlangScalarType,
+    shared_library::ISlangSharedLibrary,
+    source_language::SlangSourceLanguage, stage::SlangStage,
+    type_kind::SlangTypeKind,
 };
 
 #[link(name = "slang")]
@@ -44,6 +55,26 @@ unsafe extern "C" {
         target: SlangCompileTarget,
     ) -> i32;
 
+    #[link_name = "spFindProfile"]
+    pub(crate) fn sp_find_profile(
+        session: IGlobalSessionRef,
+        name: *const std::ffi::c_char,
+    ) -> u32;
+
+    #[link_name = "spSetTargetProfile"]
+    pub(crate) fn sp_set_target_profile(
+        compile_request: ICompileRequestRef,
+        target_index: i32,
+        profile: u32,
+    );
+
+    #[link_name = "spAddPreprocessorDefine"]
+    pub(crate) fn sp_add_preprocessor_define(
+        compile_request: ICompileRequestRef,
+        key: *const std::ffi::c_char,
+        value: *const std::ffi::c_char,
+    );
+
     #[link_name = "spAddSearchPath"]
     pub(crate) fn sp_add_search_path(
         compile_request: ICompileRequestRef,
@@ -64,6 +95,14 @@ unsafe extern "C" {
         path: *const std::ffi::c_char,
     );
 
+    #[link_name = "spAddTranslationUnitSourceString"]
+    pub(crate) fn sp_add_translation_unit_source_string(
+        compile_request: ICompileRequestRef,
+        translation_unit_index: u32,
+        path: *const std::ffi::c_char,
+        source: *const std::ffi::c_char,
+    );
+
     #[link_name = "spGetDiagnosticOutput"]
     pub(crate) fn sp_get_diagnostic_output(
         compile_request: ICompileRequestRef,
@@ -72,11 +111,21 @@ unsafe extern "C" {
     #[link_name = "spGetEntryPointCodeBlob"]
     pub(crate) fn sp_get_entry_point_code_blob(
         compile_request: ICompileRequestRef,
-        entry_point_index: u32,
+        entry_point_index: i32,
         target_index: i32,
         blob: &mut Option<std::ptr::NonNull<ISlangBlob>>,
     ) -> SlangResult;
 
+    #[link_name = "spGetEntryPointHostCallable"]
+    pub(crate) fn sp_get_entry_point_host_callable(
+        compile_request: ICompileRequestRef,
+        entry_point_index: i32,
+        target_index: i32,
+        shared_library: &mut Option<
+            NonNull<ISlangSharedLibrary>,
+        >,
+    ) -> SlangResult;
+
     #[link_name = "spCompile"]
     pub(crate) fn sp_compile(
         compile_request: ICompileRequestRef,
@@ -90,15 +139,191 @@ unsafe extern "C" {
     #[link_name = "spReflection_getEntryPointCount"]
     pub(crate) fn sp_reflection_get_entry_point_count(
         reflection: SlangReflectionRef,
-    ) -> u32;
+    ) -> usize;
 
     #[link_name = "spReflection_getEntryPointByIndex"]
     pub(crate) fn sp_reflection_get_entry_point_by_index(
         reflection: SlangReflectionRef,
+        index: usize,
     ) -> Option<SlangReflectionEntryPointRef>;
 
     #[link_name = "spReflectionEntryPoint_getName"]
     pub(crate) fn sp_reflection_entry_point_get_name(
         reflection_entry_point: SlangReflectionEntryPointRef,
     ) -> Option<NonNull<std::ffi::c_char>>;
+
+    #[link_name = "spReflectionEntryPoint_getStage"]
+    pub(crate) fn sp_reflection_entry_point_get_stage(
+        reflection_entry_point: SlangReflectionEntryPointRef,
+    ) -> SlangStage;
+
+    #[link_name = "spReflectionEntryPoint_getParameterCount"]
+    pub(crate) fn sp_reflection_entry_point_get_parameter_count(
+        reflection_entry_point: SlangReflectionEntryPointRef,
+    ) -> u32;
+
+    #[link_name = "spReflectionEntryPoint_getParameterByIndex"]
+    pub(crate) fn sp_reflection_entry_point_get_parameter_by_index(
+        reflection_entry_point: SlangReflectionEntryPointRef,
+        index: u32,
+    ) -> Option<SlangReflectionVariableLayoutRef>;
+
+    #[link_name = "spReflectionEntryPoint_getVarLayout"]
+    pub(crate) fn sp_reflection_entry_point_get_var_layout(
+        reflection_entry_point: SlangReflectionEntryPointRef,
+    ) -> Option<SlangReflectionVariableLayoutRef>;
+
+    #[link_name = "spReflectionEntryPoint_getComputeThreadGroupSize"]
+    pub(crate) fn sp_reflection_entry_point_get_compute_thread_group_size(
+        reflection_entry_point: SlangReflectionEntryPointRef,
+        axis_count: usize,
+        out_size_along_axis: *mut usize,
+    );
+
+    #[link_name = "spReflection_GetParameterCount"]
+    pub(crate) fn sp_reflection_get_parameter_count(
+        reflection: SlangReflectionRef,
+    ) -> u32;
+
+    #[link_name = "spReflection_GetParameterByIndex"]
+    pub(crate) fn sp_reflection_get_parameter_by_index(
+        reflection: SlangReflectionRef,
+        index: u32,
+    ) -> Option<SlangReflectionVariableLayoutRef>;
+
+    #[link_name = "spReflectionVariableLayout_GetVariable"]
+    pub(crate) fn sp_reflection_variable_layout_get_variable(
+        variable_layout: SlangReflectionVariableLayoutRef,
+    ) -> Option<SlangReflectionVariableRef>;
+
+    #[link_name = "spReflectionVariableLayout_GetTypeLayout"]
+    pub(crate) fn sp_reflection_variable_layout_get_type_layout(
+        variable_layout: SlangReflectionVariableLayoutRef,
+    ) -> Option<SlangReflectionTypeLayoutRef>;
+
+    #[link_name = "spReflectionVariableLayout_GetOffset"]
+    pub(crate) fn sp_reflection_variable_layout_get_offset(
+        variable_layout: SlangReflectionVariableLayoutRef,
+        category: SlangParameterCategory,
+    ) -> usize;
+
+    #[link_name = "spReflectionVariableLayout_GetSpace"]
+    pub(crate) fn sp_reflection_variable_layout_get_space(
+        variable_layout: SlangReflectionVariableLayoutRef,
+        category: SlangParameterCategory,
+    ) -> usize;
+
+    #[link_name = "spReflectionVariableLayout_GetSemanticName"]
+    pub(crate) fn sp_reflection_variable_layout_get_semantic_name(
+        variable_layout: SlangReflectionVariableLayoutRef,
+    ) -> Option<NonNull<std::ffi::c_char>>;
+
+    #[link_name = "spReflectionVariable_GetName"]
+    pub(crate) fn sp_reflection_variable_get_name(
+        variable: SlangReflectionVariableRef,
+    ) -> Option<NonNull<std::ffi::c_char>>;
+
+    #[link_name = "spReflectionTypeLayout_GetType"]
+    pub(crate) fn sp_reflection_type_layout_get_type(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> Option<SlangReflectionTypeRef>;
+
+    #[link_name = "spReflectionTypeLayout_getKind"]
+    pub(crate) fn sp_reflection_type_layout_get_kind(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> SlangTypeKind;
+
+    #[link_name = "spReflectionTypeLayout_GetSize"]
+    pub(crate) fn sp_reflection_type_layout_get_size(
+        type_layout: SlangReflectionTypeLayoutRef,
+        category: SlangParameterCategory,
+    ) -> usize;
+
+    #[link_name = "spReflectionTypeLayout_GetFieldByIndex"]
+    pub(crate) fn sp_reflection_type_layout_get_field_by_index(
+        type_layout: SlangReflectionTypeLayoutRef,
+        index: u32,
+    ) -> Option<SlangReflectionVariableLayoutRef>;
+
+    #[link_name = "spReflectionTypeLayout_GetElementStride"]
+    pub(crate) fn sp_reflection_type_layout_get_element_stride(
+        type_layout: SlangReflectionTypeLayoutRef,
+        category: SlangParameterCategory,
+    ) -> usize;
+
+    #[link_name = "spReflectionTypeLayout_GetElementTypeLayout"]
+    pub(crate) fn sp_reflection_type_layout_get_element_type_layout(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> Option<SlangReflectionTypeLayoutRef>;
+
+    #[link_name = "spReflectionTypeLayout_GetElementVarLayout"]
+    pub(crate) fn sp_reflection_type_layout_get_element_var_layout(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> Option<SlangReflectionVariableLayoutRef>;
+
+    #[link_name = "spReflectionTypeLayout_getContainerVarLayout"]
+    pub(crate) fn sp_reflection_type_layout_get_container_var_layout(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> Option<SlangReflectionVariableLayoutRef>;
+
+    #[link_name = "spReflectionTypeLayout_GetParameterCategory"]
+    pub(crate) fn sp_reflection_type_layout_get_parameter_category(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> SlangParameterCategory;
+
+    #[link_name = "spReflectionTypeLayout_GetCategoryCount"]
+    pub(crate) fn sp_reflection_type_layout_get_category_count(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> u32;
+
+    #[link_name = "spReflectionTypeLayout_GetCategoryByIndex"]
+    pub(crate) fn sp_reflection_type_layout_get_category_by_index(
+        type_layout: SlangReflectionTypeLayoutRef,
+        index: u32,
+    ) -> SlangParameterCategory;
+
+    #[link_name = "spReflectionTypeLayout_GetMatrixLayoutMode"]
+    pub(crate) fn sp_reflection_type_layout_get_matrix_layout_mode(
+        type_layout: SlangReflectionTypeLayoutRef,
+    ) -> SlangMatrixLayoutMode;
+
+    #[link_name = "spReflectionType_GetName"]
+    pub(crate) fn sp_reflection_type_get_name(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> Option<NonNull<std::ffi::c_char>>;
+
+    #[link_name = "spReflectionType_GetFieldCount"]
+    pub(crate) fn sp_reflection_type_get_field_count(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> u32;
+
+    #[link_name = "spReflectionType_GetElementCount"]
+    pub(crate) fn sp_reflection_type_get_element_count(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> usize;
+
+    #[link_name = "spReflectionType_GetRowCount"]
+    pub(crate) fn sp_reflection_type_get_row_count(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> u32;
+
+    #[link_name = "spReflectionType_GetColumnCount"]
+    pub(crate) fn sp_reflection_type_get_column_count(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> u32;
+
+    #[link_name = "spReflectionType_GetScalarType"]
+    pub(crate) fn sp_reflection_type_get_scalar_type(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> SlangScalarType;
+
+    #[link_name = "spReflectionType_GetResourceShape"]
+    pub(crate) fn sp_reflection_type_get_resource_shape(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> SlangResourceShape;
+
+    #[link_name = "spReflectionType_GetResourceAccess"]
+    pub(crate) fn sp_reflection_type_get_resource_access(
+        reflection_type: SlangReflectionTypeRef,
+    ) -> SlangResourceAccess;
 }
@@ -1,12 +1,20 @@
 use std::{
     ffi::{CStr, CString},
+    path::{Path, PathBuf},
     ptr::NonNull,
 };
 
+use crate::{
+    diagnostics::{self, Diagnostic},
+    permutations::Permutation,
+    registry::{self, EntryPoint},
+};
+
 use super::{
     bindings::*, compile_target::SlangCompileTarget,
     global_session::IGlobalSessionRef,
     optimization_level::SlangOptimizationLevel,
+    reflect::SlangReflectionRef, shared_library::SharedLibrary,
     source_language::SlangSourceLanguage,
 };
 
@@ -24,7 +32,14 @@ unsafe impl Sync for ICompileRequestRef {}
 
 pub(crate) struct CompileRequest {
     reference: ICompileRequestRef,
-    target_index: i32,
+    session: IGlobalSessionRef,
+    targets: Vec<CodeGenTarget>,
+}
+
+struct CodeGenTarget {
+    index: i32,
+    output_dir: PathBuf,
+    format: crate::TargetFormat,
 }
 
 impl CompileRequest {
@@ -32,26 +47,75 @@ impl CompileRequest {
     pub(crate) fn create(
         session: IGlobalSessionRef,
         optimization: SlangOptimizationLevel,
-    ) -> Self {
-        let reference = unsafe {
-            sp_create_compile_request(session).unwrap()
+    ) -> crate::Result<Self> {
+        let reference =
+            unsafe { sp_create_compile_request(session) }
+                .ok_or(crate::Error::SlangNull(
+                    "spCreateCompileRequest",
+                ))?;
+
+        unsafe {
+            sp_set_optimization_level(reference, optimization)
         };
 
-        let target_index = unsafe {
+        Ok(Self {
+            reference,
+            session,
+            targets: Vec::new(),
+        })
+    }
+
+    /// Adds a target whose code [`CompileRequest::compile`]
+    /// writes to `output_dir`.
+    pub(crate) fn add_target(
+        &mut self,
+        format: crate::TargetFormat,
+        profile: Option<&CStr>,
+        output_dir: &Path,
+    ) -> crate::Result<()> {
+        let index = unsafe {
             sp_add_code_gen_target(
-                reference,
-                SlangCompileTarget::Spirv,
+                self.reference,
+                SlangCompileTarget::from(format),
             )
         };
 
-        unsafe {
-            sp_set_optimization_level(reference, optimization)
-        };
+        if let Some(profile) = profile {
+            let profile_id = unsafe {
+                sp_find_profile(self.session, profile.as_ptr())
+            };
 
-        Self {
-            reference,
-            target_index,
+            if profile_id == 0 {
+                return Err(crate::Error::UnknownProfile(
+                    profile.to_string_lossy().into_owned(),
+                ));
+            }
+
+            unsafe {
+                sp_set_target_profile(
+                    self.reference,
+                    index,
+                    profile_id,
+                )
+            };
         }
+
+        self.targets.push(CodeGenTarget {
+            index,
+            output_dir: output_dir.to_path_buf(),
+            format,
+        });
+
+        Ok(())
+    }
+
+    /// Reflection of the compiled program. Only valid after a
+    /// successful [`CompileRequest::compile`].
+    pub(super) fn reflection(
+        &self,
+    ) -> crate::Result<SlangReflectionRef> {
+        unsafe { sp_get_reflection(self.reference) }
+            .ok_or(crate::Error::SlangNull("spGetReflection"))
     }
 
     #[inline]
@@ -61,6 +125,21 @@ impl CompileRequest {
         };
     }
 
+    #[inline]
+    pub(crate) fn add_preprocessor_define(
+        &self,
+        key: &CStr,
+        value: &CStr,
+    ) {
+        unsafe {
+            sp_add_preprocessor_define(
+                self.reference,
+                key.as_ptr(),
+                value.as_ptr(),
+            )
+        };
+    }
+
     #[inline]
     pub(crate) fn add_translation_unit(
         &self,
@@ -90,74 +169,224 @@ impl CompileRequest {
         }
     }
 
+    /// Adds `source` as a file named `path`, for code generated
+    /// by the build.
     #[inline]
-    pub(crate) fn compile(&self, output_dir: &std::path::Path) {
+    pub(crate) fn add_translation_unit_source_string(
+        &self,
+        translation_unit_index: u32,
+        path: &CStr,
+        source: &CStr,
+    ) {
         unsafe {
-            if sp_compile(self.reference).failed() {
-                panic!("Failed compile")
-            };
+            sp_add_translation_unit_source_string(
+                self.reference,
+                translation_unit_index,
+                path.as_ptr(),
+                source.as_ptr(),
+            )
+        }
+    }
 
-            if let Some(diagnostic) =
-                sp_get_diagnostic_output(self.reference)
-                    .map(|v| CStr::from_ptr(v.as_ptr()))
-            {
-                if !diagnostic.is_empty() {
-                    println!("{diagnostic:?}");
-                }
-            }
+    /// Diagnostics of the last [`CompileRequest::compile`].
+    pub(crate) fn diagnostics(&self) -> Vec<Diagnostic> {
+        diagnostics::parse(&self.diagnostic_output())
+    }
 
-            let reflection =
-                sp_get_reflection(self.reference).unwrap();
+    fn diagnostic_output(&self) -> String {
+        unsafe { sp_get_diagnostic_output(self.reference) }
+            .map(|v| unsafe { CStr::from_ptr(v.as_ptr()) })
+            .map(|v| v.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
 
-            let entry_point_count =
-                sp_reflection_get_entry_point_count(reflection);
+    /// Compiles the request, with the errors as
+    /// [`crate::Error::Compile`].
+    pub(super) fn compile_program(&self) -> crate::Result<()> {
+        if unsafe { sp_compile(self.reference) }.failed() {
+            let output = self.diagnostic_output();
 
-            for entry_point_index in 0..entry_point_count {
-                let entry_point =
-                    sp_reflection_get_entry_point_by_index(
-                        reflection,
-                    )
-                    .unwrap();
+            return Err(crate::Error::Compile {
+                diagnostics: diagnostics::parse(&output),
+                output,
+            });
+        }
 
-                let entry_point_name =
-                    sp_reflection_entry_point_get_name(
-                        entry_point,
-                    )
-                    .map(|v| CStr::from_ptr(v.as_ptr()))
-                    .unwrap()
-                    .to_str()
-                    .unwrap();
+        Ok(())
+    }
 
-                if entry_point_name.is_empty() {
-                    panic!("Empty entry point name");
-                }
+    /// Adds a target compiling to code loaded into this process,
+    /// returning its index for
+    /// [`CompileRequest::host_callable`].
+    pub(super) fn add_host_callable_target(&self) -> i32 {
+        unsafe {
+            sp_add_code_gen_target(
+                self.reference,
+                SlangCompileTarget::ShaderHostCallable,
+            )
+        }
+    }
 
-                let mut blob = None;
+    /// Code of an entry point for a target added with
+    /// [`CompileRequest::add_host_callable_target`], after
+    /// [`CompileRequest::compile_program`].
+    pub(super) fn host_callable(
+        &self,
+        entry_point_index: usize,
+        target_index: i32,
+    ) -> crate::Result<SharedLibrary> {
+        let mut library = None;
 
-                if sp_get_entry_point_code_blob(
-                    self.reference,
+        let result = unsafe {
+            sp_get_entry_point_host_callable(
+                self.reference,
+                entry_point_index as i32,
+                target_index,
+                &mut library,
+            )
+        };
+
+        if result.failed() {
+            return Err(crate::Error::SlangError {
+                function_name: "spGetEntryPointHostCallable",
+                code: result.raw(),
+            });
+        }
+
+        library.map(SharedLibrary::new).ok_or(
+            crate::Error::SlangNull(
+                "spGetEntryPointHostCallable",
+            ),
+        )
+    }
+
+    /// Compiles the request and writes one file per entry point
+    /// and target, to `<target dir>/<module>/<id name>.<ext>`
+    /// with the name from [`EntryPoint::id_name`].
+    pub(crate) fn compile(
+        &self,
+        module: &str,
+        permutation: &Permutation,
+    ) -> crate::Result<Vec<EntryPoint>> {
+        let mut outputs = Vec::new();
+
+        self.compile_program()?;
+
+        let reflection = self.reflection()?;
+
+        let entry_point_count = unsafe {
+            sp_reflection_get_entry_point_count(reflection)
+        };
+
+        for entry_point_index in 0..entry_point_count {
+            let entry_point = unsafe {
+                sp_reflection_get_entry_point_by_index(
+                    reflection,
                     entry_point_index,
-                    self.target_index,
-                    &mut blob,
                 )
-                .failed()
-                {
-                    println!(
-                        "Failed sp_get_entry_point_code_blob"
-                    );
+            }
+            .ok_or(crate::Error::SlangNull(
+                "spReflection_getEntryPointByIndex",
+            ))?;
+
+            let entry_point_name = unsafe {
+                sp_reflection_entry_point_get_name(entry_point)
+            }
+            .map(|v| unsafe { CStr::from_ptr(v.as_ptr()) })
+            .map(|v| v.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+            if entry_point_name.is_empty() {
+                return Err(crate::Error::EmptyEntryPointName);
+            }
+
+            let Some(stage) = unsafe {
+                sp_reflection_entry_point_get_stage(entry_point)
+            }
+            .to_stage() else {
+                return Err(crate::Error::UnsupportedStage(
+                    entry_point_name,
+                ));
+            };
+
+            let mut entry = EntryPoint {
+                code_name: entry_point_name.clone(),
+                name: entry_point_name,
+                stage,
+                module: module.to_owned(),
+                permutation: permutation.clone(),
+                outputs: Vec::with_capacity(self.targets.len()),
+                reflection: self.entry_point_reflection(
+                    entry_point,
+                    stage,
+                )?,
+            };
+
+            for target in self.targets.iter() {
+                let mut blob = None;
+
+                let result = unsafe {
+                    sp_get_entry_point_code_blob(
+                        self.reference,
+                        entry_point_index as i32,
+                        target.index,
+                        &mut blob,
+                    )
+                };
+
+                if result.failed() {
+                    return Err(crate::Error::SlangError {
+                        function_name: "spGetEntryPointCodeBlob",
+                        code: result.raw(),
+                    });
                 }
 
-                let mut blob = blob.unwrap();
+                let mut blob =
+                    blob.ok_or(crate::Error::SlangNull(
+                        "spGetEntryPointCodeBlob",
+                    ))?;
 
-                std::fs::write(
-                    output_dir.join(entry_point_name),
-                    blob.as_ref().as_slice(),
-                )
-                .unwrap();
+                let module_dir = target.output_dir.join(module);
+
+                std::fs::create_dir_all(&module_dir)
+                    .map_err(crate::Error::io(&module_dir))?;
+
+                let output = module_dir
+                    .join(entry.id_name())
+                    .with_extension(target.format.extension());
+
+                let code = unsafe { blob.as_ref().as_slice() };
+                let written = std::fs::write(&output, code);
+                let code_name = (target.format
+                    == crate::TargetFormat::Spirv)
+                    .then(|| {
+                        registry::spirv_entry_point_name(code)
+                    });
+
+                unsafe { blob.as_mut().release() };
 
-                blob.as_mut().release();
+                written.map_err(crate::Error::io(&output))?;
+
+                match code_name {
+                    Some(Some(code_name)) => {
+                        entry.code_name = code_name
+                    }
+                    Some(None) => {
+                        return Err(
+                            crate::Error::MissingSpirvEntryPoint(
+                                entry.name,
+                            ),
+                        );
+                    }
+                    None => {}
+                }
+                entry.outputs.push(output);
             }
-        };
+
+            outputs.push(entry);
+        }
+
+        Ok(outputs)
     }
 }
 
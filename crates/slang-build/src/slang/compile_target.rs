@@ -35,3 +35,17 @@ pub enum SlangCompileTarget {
     WgslSpirv = 30,
     CountOf = 31,
 }
+
+impl From<crate::TargetFormat> for SlangCompileTarget {
+    fn from(format: crate::TargetFormat) -> Self {
+        match format {
+            crate::TargetFormat::Spirv => Self::Spirv,
+            crate::TargetFormat::SpirvAsm => Self::SpirvAsm,
+            crate::TargetFormat::Glsl => Self::Glsl,
+            crate::TargetFormat::Hlsl => Self::Hlsl,
+            crate::TargetFormat::Wgsl => Self::Wgsl,
+            crate::TargetFormat::Metal => Self::Metal,
+            crate::TargetFormat::Cpp => Self::CppSource,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use std::{ffi::CString, os::raw::c_void};
+
+use super::{
+    compile_request::CompileRequest,
+    parameter_category::SlangParameterCategory,
+    shared_library::SharedLibrary, stage::SlangStage,
+};
+
+const UNIFORM: SlangParameterCategory =
+    SlangParameterCategory::UNIFORM;
+
+/// Groups a [`ComputeFunction`] runs, from the start group up to
+/// but not including the end group.
+#[repr(C)]
+pub(crate) struct ComputeVaryingInput {
+    pub(crate) start_group_id: [u32; 3],
+    pub(crate) end_group_id: [u32; 3],
+}
+
+/// Function Slang generates for a compute entry point on the
+/// CPU, taking the groups to run, the uniform parameters of the
+/// entry point and the global parameters.
+pub(crate) type ComputeFunction = unsafe extern "C" fn(
+    groups: *const ComputeVaryingInput,
+    entry_point_parameters: *mut c_void,
+    global_parameters: *mut c_void,
+);
+
+/// `StructuredBuffer` or `RWStructuredBuffer` on the CPU.
+#[repr(C)]
+pub(crate) struct HostBuffer {
+    pub(crate) data: *const c_void,
+    pub(crate) count: usize,
+}
+
+/// Global [`HostBuffer`] of a [`HostCallable`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct GlobalBuffer {
+    /// In the global parameters.
+    pub(crate) offset: usize,
+    pub(crate) element_size: usize,
+}
+
+/// Compute entry point loaded into this process, reading the
+/// global buffer `inputs` and writing `outputs`.
+pub(crate) struct HostCallable {
+    pub(crate) function: ComputeFunction,
+    /// Bytes of the global parameters.
+    pub(crate) globals_size: usize,
+    pub(crate) inputs: GlobalBuffer,
+    pub(crate) outputs: GlobalBuffer,
+    /// Keeps `function` loaded.
+    _library: SharedLibrary,
+}
+
+impl CompileRequest {
+    /// Compiles the request for the CPU and loads `entry_point`,
+    /// which has to be a compute shader with one thread per
+    /// group, so that a group runs per input.
+    pub(crate) fn compile_host_callable(
+        &self,
+        entry_point: &str,
+    ) -> crate::Result<HostCallable> {
+        let error = |reason: &str| crate::Error::HostKernel {
+            entry_point: entry_point.to_owned(),
+            reason: reason.to_owned(),
+        };
+
+        let name = CString::new(entry_point)
+            .map_err(|_| error("Name has a nul byte"))?;
+
+        let target = self.add_host_callable_target();
+        self.compile_program()?;
+
+        let reflection = self.reflection()?;
+
+        let (index, reflected) = reflection
+            .entry_points()
+            .find(|(_, reflected)| {
+                reflected.name().as_deref() == Some(entry_point)
+            })
+            .ok_or_else(|| error("No such entry point"))?;
+
+        if reflected.stage() != SlangStage::COMPUTE {
+            return Err(error("Not a compute shader"));
+        }
+
+        if reflected.thread_group_size() != [1, 1, 1] {
+            return Err(error("Needs `[numthreads(1, 1, 1)]`"));
+        }
+
+        let mut globals_size = 0;
+        let mut inputs = None;
+        let mut outputs = None;
+
+        for parameter in reflection.parameters() {
+            let Some(type_layout) = parameter.type_layout()
+            else {
+                continue;
+            };
+
+            let offset = parameter.offset(UNIFORM);
+            globals_size = globals_size
+                .max(offset + type_layout.size(UNIFORM));
+
+            let buffer = GlobalBuffer {
+                offset,
+                element_size: type_layout
+                    .element_type_layout()
+                    .map_or(0, |element| element.size(UNIFORM)),
+            };
+
+            match parameter.name().as_deref() {
+                Some("inputs") => inputs = Some(buffer),
+                Some("outputs") => outputs = Some(buffer),
+                _ => {}
+            }
+        }
+
+        let (Some(inputs), Some(outputs)) = (inputs, outputs)
+        else {
+            return Err(error(
+                "Needs global `inputs` and `outputs` buffers",
+            ));
+        };
+
+        let library = self.host_callable(index, target)?;
+
+        let symbol =
+            library.symbol(&name).ok_or_else(|| {
+                error("Missing from the CPU code")
+            })?;
+
+        Ok(HostCallable {
+            // Slang generates it with this signature.
+            function: unsafe {
+                std::mem::transmute::<
+                    *mut c_void,
+                    ComputeFunction,
+                >(symbol.as_ptr())
+            },
+            globals_size,
+            inputs,
+            outputs,
+            _library: library,
+        })
+    }
+}
@@ -23,7 +23,7 @@ struct GlobalSessionArc {
 pub struct Slang;
 
 impl Slang {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new() -> crate::Result<Self> {
         let descriptor = SlangGlobalSessionDesc::default();
         let mut global_session = None;
 
@@ -34,7 +34,7 @@ impl Slang {
                 GLOBAL_SESSION
                     .counter
                     .fetch_add(1, Ordering::Relaxed);
-                return Self;
+                return Ok(Self);
             }
         }
 
@@ -45,7 +45,10 @@ impl Slang {
             );
 
             if result.failed() {
-                panic!("slang_createGlobalSession2 failed");
+                return Err(crate::Error::SlangError {
+                    function_name: "slang_createGlobalSession2",
+                    code: result.raw(),
+                });
             }
 
             let mut session =
@@ -57,13 +60,13 @@ impl Slang {
                 .fetch_add(1, Ordering::Relaxed);
         }
 
-        Self
+        Ok(Self)
     }
 
     pub(crate) fn create_compile_request(
         &self,
         optimization: crate::OptimizationLevel,
-    ) -> CompileRequest {
+    ) -> crate::Result<CompileRequest> {
         let session = GLOBAL_SESSION.session.read().unwrap();
         let session = session.unwrap();
         CompileRequest::create(
@@ -0,0 +1,10 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangMatrixLayoutMode(u32);
+
+#[allow(unused)]
+impl SlangMatrixLayoutMode {
+    pub(crate) const UNKNOWN: Self = Self(0);
+    pub(crate) const ROW_MAJOR: Self = Self(1);
+    pub(crate) const COLUMN_MAJOR: Self = Self(2);
+}
@@ -4,11 +4,30 @@ mod compile_request;
 mod compile_target;
 mod global_session;
 mod global_session_desc;
+mod host_callable;
 mod instance;
+mod matrix_layout_mode;
 mod optimization_level;
+mod parameter_category;
 mod reflect;
 mod reflection_entry_point;
+mod reflection_type;
+mod reflection_type_layout;
+mod reflection_variable;
+mod reflection_variable_layout;
+mod resource_access;
+mod resource_layout;
+mod resource_shape;
 mod result;
+mod scalar_type;
+mod shared_library;
 mod source_language;
+mod stage;
+mod type_kind;
+mod uniform_layout;
 
+pub(crate) use compile_request::CompileRequest;
+pub(crate) use host_callable::{
+    ComputeVaryingInput, HostBuffer, HostCallable,
+};
 pub(crate) use instance::Slang;
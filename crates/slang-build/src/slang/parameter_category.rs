@@ -0,0 +1,34 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangParameterCategory(u32);
+
+#[allow(unused)]
+impl SlangParameterCategory {
+    pub(crate) const NONE: Self = Self(0);
+    pub(crate) const MIXED: Self = Self(1);
+    pub(crate) const CONSTANT_BUFFER: Self = Self(2);
+    pub(crate) const SHADER_RESOURCE: Self = Self(3);
+    pub(crate) const UNORDERED_ACCESS: Self = Self(4);
+    pub(crate) const VARYING_INPUT: Self = Self(5);
+    pub(crate) const VARYING_OUTPUT: Self = Self(6);
+    pub(crate) const SAMPLER_STATE: Self = Self(7);
+    pub(crate) const UNIFORM: Self = Self(8);
+    pub(crate) const DESCRIPTOR_TABLE_SLOT: Self = Self(9);
+    pub(crate) const SPECIALIZATION_CONSTANT: Self = Self(10);
+    pub(crate) const PUSH_CONSTANT_BUFFER: Self = Self(11);
+    pub(crate) const REGISTER_SPACE: Self = Self(12);
+    pub(crate) const GENERIC: Self = Self(13);
+    pub(crate) const RAY_PAYLOAD: Self = Self(14);
+    pub(crate) const HIT_ATTRIBUTES: Self = Self(15);
+    pub(crate) const CALLABLE_PAYLOAD: Self = Self(16);
+    pub(crate) const SHADER_RECORD: Self = Self(17);
+    pub(crate) const EXISTENTIAL_TYPE_PARAM: Self = Self(18);
+    pub(crate) const EXISTENTIAL_OBJECT_PARAM: Self = Self(19);
+    pub(crate) const SUB_ELEMENT_REGISTER_SPACE: Self =
+        Self(20);
+    pub(crate) const SUBPASS: Self = Self(21);
+    pub(crate) const METAL_ARGUMENT_BUFFER_ELEMENT: Self =
+        Self(22);
+    pub(crate) const METAL_ATTRIBUTE: Self = Self(23);
+    pub(crate) const METAL_PAYLOAD: Self = Self(24);
+}
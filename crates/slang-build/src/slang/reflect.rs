@@ -1,5 +1,11 @@
 use std::ptr::NonNull;
 
+use super::{
+    bindings::*,
+    reflection_entry_point::SlangReflectionEntryPointRef,
+    reflection_variable_layout::SlangReflectionVariableLayoutRef,
+};
+
 #[repr(C)]
 pub(crate) struct SlangReflection {
     _phantom: u32,
@@ -11,3 +17,39 @@ pub(crate) struct SlangReflectionRef(NonNull<SlangReflection>);
 
 unsafe impl Send for SlangReflectionRef {}
 unsafe impl Sync for SlangReflectionRef {}
+
+impl SlangReflectionRef {
+    /// Global shader parameters.
+    pub(crate) fn parameters(
+        self,
+    ) -> impl Iterator<Item = SlangReflectionVariableLayoutRef>
+    {
+        let count =
+            unsafe { sp_reflection_get_parameter_count(self) };
+
+        (0..count).filter_map(move |index| unsafe {
+            sp_reflection_get_parameter_by_index(self, index)
+        })
+    }
+
+    /// Entry points with their indices, which the code of each
+    /// target is looked up by.
+    pub(crate) fn entry_points(
+        self,
+    ) -> impl Iterator<Item = (usize, SlangReflectionEntryPointRef)>
+    {
+        let count = unsafe {
+            sp_reflection_get_entry_point_count(self)
+        };
+
+        (0..count).filter_map(move |index| {
+            let entry_point = unsafe {
+                sp_reflection_get_entry_point_by_index(
+                    self, index,
+                )
+            }?;
+
+            Some((index, entry_point))
+        })
+    }
+}
@@ -1,4 +1,10 @@
-use std::ptr::NonNull;
+use std::{ffi::CStr, ptr::NonNull};
+
+use super::{
+    bindings::*,
+    reflection_variable_layout::SlangReflectionVariableLayoutRef,
+    stage::SlangStage,
+};
 
 #[repr(C)]
 pub(crate) struct SlangReflectionEntryPoint {
@@ -13,3 +19,59 @@ pub(crate) struct SlangReflectionEntryPointRef(
 
 unsafe impl Send for SlangReflectionEntryPointRef {}
 unsafe impl Sync for SlangReflectionEntryPointRef {}
+
+impl SlangReflectionEntryPointRef {
+    pub(crate) fn name(self) -> Option<String> {
+        unsafe {
+            sp_reflection_entry_point_get_name(self)
+                .map(|name| CStr::from_ptr(name.as_ptr()))
+                .map(|name| name.to_string_lossy().into_owned())
+        }
+    }
+
+    pub(crate) fn stage(self) -> SlangStage {
+        unsafe { sp_reflection_entry_point_get_stage(self) }
+    }
+
+    /// Parameters of the entry point function, varying and
+    /// uniform.
+    pub(crate) fn parameters(
+        self,
+    ) -> impl Iterator<Item = SlangReflectionVariableLayoutRef>
+    {
+        let count = unsafe {
+            sp_reflection_entry_point_get_parameter_count(self)
+        };
+
+        (0..count).filter_map(move |index| unsafe {
+            sp_reflection_entry_point_get_parameter_by_index(
+                self, index,
+            )
+        })
+    }
+
+    /// Layout of all parameters together, whose offsets those
+    /// of [`Self::parameters`] are relative to.
+    pub(crate) fn variable_layout(
+        self,
+    ) -> Option<SlangReflectionVariableLayoutRef> {
+        unsafe {
+            sp_reflection_entry_point_get_var_layout(self)
+        }
+    }
+
+    /// `[numthreads]` of compute, task and mesh shaders.
+    pub(crate) fn thread_group_size(self) -> [u32; 3] {
+        let mut size = [0usize; 3];
+
+        unsafe {
+            sp_reflection_entry_point_get_compute_thread_group_size(
+                self,
+                size.len(),
+                size.as_mut_ptr(),
+            )
+        };
+
+        size.map(|axis| axis as u32)
+    }
+}
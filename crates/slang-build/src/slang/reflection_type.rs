@@ -0,0 +1,63 @@
+use std::{ffi::CStr, ptr::NonNull};
+
+use super::{
+    bindings::*, resource_access::SlangResourceAccess,
+    resource_shape::SlangResourceShape,
+    scalar_type::SlangScalarType,
+};
+
+#[repr(C)]
+pub(crate) struct SlangReflectionType {
+    _phantom: u32,
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub(crate) struct SlangReflectionTypeRef(
+    NonNull<SlangReflectionType>,
+);
+
+unsafe impl Send for SlangReflectionTypeRef {}
+unsafe impl Sync for SlangReflectionTypeRef {}
+
+impl SlangReflectionTypeRef {
+    pub(crate) fn name(self) -> Option<String> {
+        unsafe {
+            sp_reflection_type_get_name(self)
+                .map(|name| CStr::from_ptr(name.as_ptr()))
+                .map(|name| name.to_string_lossy().into_owned())
+                .filter(|name| !name.is_empty())
+        }
+    }
+
+    pub(crate) fn field_count(self) -> u32 {
+        unsafe { sp_reflection_type_get_field_count(self) }
+    }
+
+    /// Length of arrays and vectors, `0` for unsized arrays.
+    pub(crate) fn element_count(self) -> usize {
+        unsafe { sp_reflection_type_get_element_count(self) }
+    }
+
+    pub(crate) fn row_count(self) -> u32 {
+        unsafe { sp_reflection_type_get_row_count(self) }
+    }
+
+    pub(crate) fn column_count(self) -> u32 {
+        unsafe { sp_reflection_type_get_column_count(self) }
+    }
+
+    /// Scalar type of scalars, or of the components of vectors
+    /// and matrices.
+    pub(crate) fn scalar_type(self) -> SlangScalarType {
+        unsafe { sp_reflection_type_get_scalar_type(self) }
+    }
+
+    pub(crate) fn resource_shape(self) -> SlangResourceShape {
+        unsafe { sp_reflection_type_get_resource_shape(self) }
+    }
+
+    pub(crate) fn resource_access(self) -> SlangResourceAccess {
+        unsafe { sp_reflection_type_get_resource_access(self) }
+    }
+}
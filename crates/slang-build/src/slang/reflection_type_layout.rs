@@ -0,0 +1,139 @@
+use std::ptr::NonNull;
+
+use super::{
+    bindings::*, matrix_layout_mode::SlangMatrixLayoutMode,
+    parameter_category::SlangParameterCategory,
+    reflection_type::SlangReflectionTypeRef,
+    reflection_variable_layout::SlangReflectionVariableLayoutRef,
+    type_kind::SlangTypeKind,
+};
+
+#[repr(C)]
+pub(crate) struct SlangReflectionTypeLayout {
+    _phantom: u32,
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub(crate) struct SlangReflectionTypeLayoutRef(
+    NonNull<SlangReflectionTypeLayout>,
+);
+
+unsafe impl Send for SlangReflectionTypeLayoutRef {}
+unsafe impl Sync for SlangReflectionTypeLayoutRef {}
+
+impl SlangReflectionTypeLayoutRef {
+    pub(crate) fn reflection_type(
+        self,
+    ) -> Option<SlangReflectionTypeRef> {
+        unsafe { sp_reflection_type_layout_get_type(self) }
+    }
+
+    pub(crate) fn kind(self) -> SlangTypeKind {
+        unsafe { sp_reflection_type_layout_get_kind(self) }
+    }
+
+    pub(crate) fn size(
+        self,
+        category: SlangParameterCategory,
+    ) -> usize {
+        unsafe {
+            sp_reflection_type_layout_get_size(self, category)
+        }
+    }
+
+    pub(crate) fn field_count(self) -> u32 {
+        self.reflection_type()
+            .map_or(0, SlangReflectionTypeRef::field_count)
+    }
+
+    pub(crate) fn field(
+        self,
+        index: u32,
+    ) -> Option<SlangReflectionVariableLayoutRef> {
+        unsafe {
+            sp_reflection_type_layout_get_field_by_index(
+                self, index,
+            )
+        }
+    }
+
+    pub(crate) fn element_stride(
+        self,
+        category: SlangParameterCategory,
+    ) -> usize {
+        unsafe {
+            sp_reflection_type_layout_get_element_stride(
+                self, category,
+            )
+        }
+    }
+
+    pub(crate) fn element_type_layout(self) -> Option<Self> {
+        unsafe {
+            sp_reflection_type_layout_get_element_type_layout(
+                self,
+            )
+        }
+    }
+
+    pub(crate) fn matrix_layout_mode(
+        self,
+    ) -> SlangMatrixLayoutMode {
+        unsafe {
+            sp_reflection_type_layout_get_matrix_layout_mode(
+                self,
+            )
+        }
+    }
+
+    /// Layout of the contents of constant buffers and parameter
+    /// blocks.
+    pub(crate) fn element_variable_layout(
+        self,
+    ) -> Option<SlangReflectionVariableLayoutRef> {
+        unsafe {
+            sp_reflection_type_layout_get_element_var_layout(
+                self,
+            )
+        }
+    }
+
+    /// Layout of the buffer of constant buffers and parameter
+    /// blocks, apart from their contents.
+    pub(crate) fn container_variable_layout(
+        self,
+    ) -> Option<SlangReflectionVariableLayoutRef> {
+        unsafe {
+            sp_reflection_type_layout_get_container_var_layout(
+                self,
+            )
+        }
+    }
+
+    /// Category of the resources the type takes,
+    /// [`SlangParameterCategory::MIXED`] for several.
+    pub(crate) fn parameter_category(
+        self,
+    ) -> SlangParameterCategory {
+        unsafe {
+            sp_reflection_type_layout_get_parameter_category(
+                self,
+            )
+        }
+    }
+
+    pub(crate) fn categories(
+        self,
+    ) -> impl Iterator<Item = SlangParameterCategory> {
+        let count = unsafe {
+            sp_reflection_type_layout_get_category_count(self)
+        };
+
+        (0..count).map(move |index| unsafe {
+            sp_reflection_type_layout_get_category_by_index(
+                self, index,
+            )
+        })
+    }
+}
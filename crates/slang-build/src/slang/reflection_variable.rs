@@ -0,0 +1,27 @@
+use std::{ffi::CStr, ptr::NonNull};
+
+use super::bindings::sp_reflection_variable_get_name;
+
+#[repr(C)]
+pub(crate) struct SlangReflectionVariable {
+    _phantom: u32,
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub(crate) struct SlangReflectionVariableRef(
+    NonNull<SlangReflectionVariable>,
+);
+
+unsafe impl Send for SlangReflectionVariableRef {}
+unsafe impl Sync for SlangReflectionVariableRef {}
+
+impl SlangReflectionVariableRef {
+    pub(crate) fn name(self) -> Option<String> {
+        unsafe {
+            sp_reflection_variable_get_name(self)
+                .map(|name| CStr::from_ptr(name.as_ptr()))
+                .map(|name| name.to_string_lossy().into_owned())
+        }
+    }
+}
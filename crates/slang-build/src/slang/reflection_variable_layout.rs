@@ -0,0 +1,72 @@
+use std::{ffi::CStr, ptr::NonNull};
+
+use super::{
+    bindings::*, parameter_category::SlangParameterCategory,
+    reflection_type_layout::SlangReflectionTypeLayoutRef,
+};
+
+#[repr(C)]
+pub(crate) struct SlangReflectionVariableLayout {
+    _phantom: u32,
+}
+
+#[repr(transparent)]
+#[derive(Clone, Copy)]
+pub(crate) struct SlangReflectionVariableLayoutRef(
+    NonNull<SlangReflectionVariableLayout>,
+);
+
+unsafe impl Send for SlangReflectionVariableLayoutRef {}
+unsafe impl Sync for SlangReflectionVariableLayoutRef {}
+
+impl SlangReflectionVariableLayoutRef {
+    pub(crate) fn name(self) -> Option<String> {
+        unsafe {
+            sp_reflection_variable_layout_get_variable(self)?
+                .name()
+        }
+    }
+
+    pub(crate) fn type_layout(
+        self,
+    ) -> Option<SlangReflectionTypeLayoutRef> {
+        unsafe {
+            sp_reflection_variable_layout_get_type_layout(self)
+        }
+    }
+
+    pub(crate) fn offset(
+        self,
+        category: SlangParameterCategory,
+    ) -> usize {
+        unsafe {
+            sp_reflection_variable_layout_get_offset(
+                self, category,
+            )
+        }
+    }
+
+    /// Register space of `category`, the descriptor set for
+    /// [`SlangParameterCategory::DESCRIPTOR_TABLE_SLOT`].
+    pub(crate) fn space(
+        self,
+        category: SlangParameterCategory,
+    ) -> usize {
+        unsafe {
+            sp_reflection_variable_layout_get_space(
+                self, category,
+            )
+        }
+    }
+
+    /// Semantic of varying parameters, like `SV_Position`.
+    pub(crate) fn semantic_name(self) -> Option<String> {
+        unsafe {
+            sp_reflection_variable_layout_get_semantic_name(
+                self,
+            )
+            .map(|name| CStr::from_ptr(name.as_ptr()))
+            .map(|name| name.to_string_lossy().into_owned())
+        }
+    }
+}
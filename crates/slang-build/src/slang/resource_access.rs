@@ -0,0 +1,16 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangResourceAccess(u32);
+
+#[allow(unused)]
+impl SlangResourceAccess {
+    pub(crate) const NONE: Self = Self(0);
+    pub(crate) const READ: Self = Self(1);
+    pub(crate) const READ_WRITE: Self = Self(2);
+    pub(crate) const RASTER_ORDERED: Self = Self(3);
+    pub(crate) const APPEND: Self = Self(4);
+    pub(crate) const CONSUME: Self = Self(5);
+    pub(crate) const WRITE: Self = Self(6);
+    pub(crate) const FEEDBACK: Self = Self(7);
+    pub(crate) const UNKNOWN: Self = Self(0x7fff_ffff);
+}
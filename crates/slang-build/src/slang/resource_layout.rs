@@ -0,0 +1,404 @@
+use super::{
+    compile_request::CompileRequest,
+    matrix_layout_mode::SlangMatrixLayoutMode,
+    parameter_category::SlangParameterCategory,
+    reflection_entry_point::SlangReflectionEntryPointRef,
+    reflection_type::SlangReflectionTypeRef,
+    reflection_type_layout::SlangReflectionTypeLayoutRef,
+    reflection_variable_layout::SlangReflectionVariableLayoutRef,
+    resource_access::SlangResourceAccess,
+    resource_shape::SlangResourceShape,
+    type_kind::SlangTypeKind, uniform_layout::scalar,
+};
+use crate::{
+    reflection::{
+        DescriptorBinding, DescriptorKind, Reflection,
+        VertexInput,
+    },
+    registry::Stage,
+};
+
+const DESCRIPTOR: SlangParameterCategory =
+    SlangParameterCategory::DESCRIPTOR_TABLE_SLOT;
+const UNIFORM: SlangParameterCategory =
+    SlangParameterCategory::UNIFORM;
+const VARYING_INPUT: SlangParameterCategory =
+    SlangParameterCategory::VARYING_INPUT;
+
+impl CompileRequest {
+    /// Descriptors, push constants, vertex inputs and workgroup
+    /// size of `entry_point`. Bindings are those of the whole
+    /// program, as Slang lays out global parameters the same for
+    /// every entry point.
+    pub(super) fn entry_point_reflection(
+        &self,
+        entry_point: SlangReflectionEntryPointRef,
+        stage: Stage,
+    ) -> crate::Result<Reflection> {
+        let mut resources = Resources::default();
+
+        for parameter in self.reflection()?.parameters() {
+            resources.add(parameter, "", Base::default(), 1);
+        }
+
+        // Resource parameters of the entry point function, and
+        // the buffer Slang puts its uniform parameters in.
+        if let Some(parameters) = entry_point.variable_layout()
+        {
+            resources.add(parameters, "", Base::default(), 1);
+        }
+
+        resources.bindings.sort_by_key(|binding| {
+            (binding.set, binding.binding)
+        });
+
+        let mut vertex_inputs = Vec::new();
+
+        if stage == Stage::Vertex {
+            for parameter in entry_point.parameters() {
+                vertex_input(
+                    parameter,
+                    "",
+                    0,
+                    &mut vertex_inputs,
+                );
+            }
+
+            vertex_inputs.sort_by_key(|input| input.location);
+        }
+
+        Ok(Reflection {
+            bindings: resources.bindings,
+            push_constant_size: resources.push_constant_size,
+            vertex_inputs,
+            workgroup_size: matches!(
+                stage,
+                Stage::Compute | Stage::Task | Stage::Mesh
+            )
+            .then(|| entry_point.thread_group_size()),
+        })
+    }
+}
+
+/// Set and binding the offsets of a variable are relative to.
+#[derive(Clone, Copy, Default)]
+struct Base {
+    set: usize,
+    binding: usize,
+}
+
+#[derive(Default)]
+struct Resources {
+    bindings: Vec<DescriptorBinding>,
+    push_constant_size: usize,
+}
+
+impl Resources {
+    /// Adds the descriptors of `variable`, `count` times over
+    /// for the arrays it is in.
+    fn add(
+        &mut self,
+        variable: SlangReflectionVariableLayoutRef,
+        prefix: &str,
+        base: Base,
+        count: u32,
+    ) {
+        let Some(type_layout) = variable.type_layout() else {
+            return;
+        };
+
+        let base = Base {
+            set: base.set + variable.space(DESCRIPTOR),
+            binding: base.binding + variable.offset(DESCRIPTOR),
+        };
+
+        self.add_type(
+            type_layout,
+            path(prefix, variable.name()),
+            base,
+            count,
+        );
+    }
+
+    fn add_type(
+        &mut self,
+        type_layout: SlangReflectionTypeLayoutRef,
+        name: String,
+        base: Base,
+        count: u32,
+    ) {
+        let kind = match type_layout.kind() {
+            SlangTypeKind::STRUCT => {
+                for index in 0..type_layout.field_count() {
+                    if let Some(field) =
+                        type_layout.field(index)
+                    {
+                        self.add(field, &name, base, count);
+                    }
+                }
+
+                return;
+            }
+            // Slang gives every resource in an array of structs
+            // an array of its own.
+            SlangTypeKind::ARRAY => {
+                let length = type_layout
+                    .reflection_type()
+                    .map_or(0, |ty| ty.element_count());
+
+                if let Some(element) =
+                    type_layout.element_type_layout()
+                {
+                    self.add_type(
+                        element,
+                        name,
+                        base,
+                        count * length as u32,
+                    );
+                }
+
+                return;
+            }
+            SlangTypeKind::CONSTANT_BUFFER
+            | SlangTypeKind::PARAMETER_BLOCK => {
+                self.add_buffer(type_layout, name, base, count);
+                return;
+            }
+            SlangTypeKind::SAMPLER_STATE => {
+                DescriptorKind::Sampler
+            }
+            SlangTypeKind::TEXTURE_BUFFER
+            | SlangTypeKind::SHADER_STORAGE_BUFFER => {
+                DescriptorKind::StorageBuffer
+            }
+            SlangTypeKind::RESOURCE => {
+                let kind = type_layout
+                    .reflection_type()
+                    .and_then(resource_kind);
+
+                match kind {
+                    Some(kind) => kind,
+                    None => return,
+                }
+            }
+            _ => return,
+        };
+
+        self.bindings.push(DescriptorBinding {
+            name,
+            set: base.set as u32,
+            binding: base.binding as u32,
+            kind,
+            count,
+            size: 0,
+        });
+    }
+
+    /// Adds the uniform buffer of a constant buffer or parameter
+    /// block, if it has ordinary data, and the resources in it.
+    /// Parameter blocks get a set of their own, which the space
+    /// of their variable already points at.
+    fn add_buffer(
+        &mut self,
+        type_layout: SlangReflectionTypeLayoutRef,
+        name: String,
+        base: Base,
+        count: u32,
+    ) {
+        let (Some(container), Some(element)) = (
+            type_layout.container_variable_layout(),
+            type_layout.element_variable_layout(),
+        ) else {
+            return;
+        };
+
+        let size = element
+            .type_layout()
+            .map_or(0, |element| element.size(UNIFORM));
+
+        if type_layout.parameter_category()
+            == SlangParameterCategory::PUSH_CONSTANT_BUFFER
+        {
+            self.push_constant_size =
+                self.push_constant_size.max(size);
+            return;
+        }
+
+        if size > 0 {
+            self.bindings.push(DescriptorBinding {
+                name: name.clone(),
+                set: (base.set + container.space(DESCRIPTOR))
+                    as u32,
+                binding: (base.binding
+                    + container.offset(DESCRIPTOR))
+                    as u32,
+                kind: DescriptorKind::UniformBuffer,
+                count,
+                size,
+            });
+        }
+
+        self.add(element, &name, base, count);
+    }
+}
+
+fn resource_kind(
+    ty: SlangReflectionTypeRef,
+) -> Option<DescriptorKind> {
+    let shape = ty.resource_shape();
+    let read_only =
+        ty.resource_access() == SlangResourceAccess::READ;
+
+    Some(match shape.base() {
+        SlangResourceShape::TEXTURE_1D
+        | SlangResourceShape::TEXTURE_2D
+        | SlangResourceShape::TEXTURE_3D
+        | SlangResourceShape::TEXTURE_CUBE => {
+            if shape.is_combined() {
+                DescriptorKind::CombinedImageSampler
+            } else if read_only {
+                DescriptorKind::SampledImage
+            } else {
+                DescriptorKind::StorageImage
+            }
+        }
+        SlangResourceShape::TEXTURE_BUFFER if read_only => {
+            DescriptorKind::UniformTexelBuffer
+        }
+        SlangResourceShape::TEXTURE_BUFFER => {
+            DescriptorKind::StorageTexelBuffer
+        }
+        SlangResourceShape::STRUCTURED_BUFFER
+        | SlangResourceShape::BYTE_ADDRESS_BUFFER => {
+            DescriptorKind::StorageBuffer
+        }
+        SlangResourceShape::ACCELERATION_STRUCTURE => {
+            DescriptorKind::AccelerationStructure
+        }
+        SlangResourceShape::TEXTURE_SUBPASS => {
+            DescriptorKind::InputAttachment
+        }
+        _ => return None,
+    })
+}
+
+/// Adds the inputs of a vertex shader parameter, skipping system
+/// values like `SV_VertexID` that take no location.
+fn vertex_input(
+    variable: SlangReflectionVariableLayoutRef,
+    prefix: &str,
+    location: usize,
+    inputs: &mut Vec<VertexInput>,
+) {
+    let Some(type_layout) = variable.type_layout() else {
+        return;
+    };
+
+    let system_value =
+        variable.semantic_name().is_some_and(|semantic| {
+            semantic.to_ascii_uppercase().starts_with("SV_")
+        });
+
+    if system_value
+        || !type_layout
+            .categories()
+            .any(|category| category == VARYING_INPUT)
+    {
+        return;
+    }
+
+    vertex_input_type(
+        type_layout,
+        path(prefix, variable.name()),
+        location + variable.offset(VARYING_INPUT),
+        inputs,
+    );
+}
+
+fn vertex_input_type(
+    type_layout: SlangReflectionTypeLayoutRef,
+    name: String,
+    location: usize,
+    inputs: &mut Vec<VertexInput>,
+) {
+    let Some(ty) = type_layout.reflection_type() else {
+        return;
+    };
+
+    let mut push = |name, location, components| {
+        if let Some(scalar) = scalar(ty.scalar_type()) {
+            inputs.push(VertexInput {
+                name,
+                location: location as u32,
+                scalar,
+                components,
+            });
+        }
+    };
+
+    match type_layout.kind() {
+        SlangTypeKind::SCALAR => push(name, location, 1),
+        SlangTypeKind::VECTOR => {
+            push(name, location, ty.element_count() as u32)
+        }
+        // A location per row or column, like the vectors of
+        // matrices in buffers.
+        SlangTypeKind::MATRIX => {
+            let (vectors, components) = if type_layout
+                .matrix_layout_mode()
+                == SlangMatrixLayoutMode::ROW_MAJOR
+            {
+                (ty.row_count(), ty.column_count())
+            } else {
+                (ty.column_count(), ty.row_count())
+            };
+
+            for index in 0..vectors as usize {
+                push(
+                    format!("{name}[{index}]"),
+                    location + index,
+                    components,
+                );
+            }
+        }
+        SlangTypeKind::ARRAY => {
+            let Some(element) =
+                type_layout.element_type_layout()
+            else {
+                return;
+            };
+            let stride =
+                type_layout.element_stride(VARYING_INPUT);
+
+            for index in 0..ty.element_count() {
+                vertex_input_type(
+                    element,
+                    format!("{name}[{index}]"),
+                    location + index * stride,
+                    inputs,
+                );
+            }
+        }
+        SlangTypeKind::STRUCT => {
+            for index in 0..type_layout.field_count() {
+                if let Some(field) = type_layout.field(index) {
+                    vertex_input(
+                        field, &name, location, inputs,
+                    );
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// `prefix.name`, for parameters nested in structs and buffers.
+fn path(prefix: &str, name: Option<String>) -> String {
+    match name {
+        Some(name) if !prefix.is_empty() => {
+            format!("{prefix}.{name}")
+        }
+        Some(name) => name,
+        None => prefix.to_owned(),
+    }
+}
@@ -0,0 +1,33 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangResourceShape(u32);
+
+#[allow(unused)]
+impl SlangResourceShape {
+    pub(crate) const NONE: Self = Self(0x00);
+    pub(crate) const TEXTURE_1D: Self = Self(0x01);
+    pub(crate) const TEXTURE_2D: Self = Self(0x02);
+    pub(crate) const TEXTURE_3D: Self = Self(0x03);
+    pub(crate) const TEXTURE_CUBE: Self = Self(0x04);
+    pub(crate) const TEXTURE_BUFFER: Self = Self(0x05);
+    pub(crate) const STRUCTURED_BUFFER: Self = Self(0x06);
+    pub(crate) const BYTE_ADDRESS_BUFFER: Self = Self(0x07);
+    pub(crate) const UNKNOWN: Self = Self(0x08);
+    pub(crate) const ACCELERATION_STRUCTURE: Self = Self(0x09);
+    pub(crate) const TEXTURE_SUBPASS: Self = Self(0x0a);
+
+    const BASE_SHAPE_MASK: u32 = 0x0f;
+    const COMBINED_FLAG: u32 = 0x100;
+
+    /// Shape without the array, multisample, shadow and other
+    /// flags.
+    pub(crate) const fn base(self) -> Self {
+        Self(self.0 & Self::BASE_SHAPE_MASK)
+    }
+
+    /// Whether a texture comes with its sampler, like
+    /// `Sampler2D`.
+    pub(crate) const fn is_combined(self) -> bool {
+        self.0 & Self::COMBINED_FLAG != 0
+    }
+}
@@ -16,6 +16,11 @@ impl SlangResult {
         !self.succeeded()
     }
 
+    #[inline(always)]
+    pub(crate) fn raw(&self) -> u32 {
+        self.0
+    }
+
     #[inline(always)]
     pub(crate) fn severity(&self) -> u32 {
         self.0 >> 31
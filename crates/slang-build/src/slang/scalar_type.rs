@@ -0,0 +1,23 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangScalarType(u32);
+
+#[allow(unused)]
+impl SlangScalarType {
+    pub(crate) const NONE: Self = Self(0);
+    pub(crate) const VOID: Self = Self(1);
+    pub(crate) const BOOL: Self = Self(2);
+    pub(crate) const INT32: Self = Self(3);
+    pub(crate) const UINT32: Self = Self(4);
+    pub(crate) const INT64: Self = Self(5);
+    pub(crate) const UINT64: Self = Self(6);
+    pub(crate) const FLOAT16: Self = Self(7);
+    pub(crate) const FLOAT32: Self = Self(8);
+    pub(crate) const FLOAT64: Self = Self(9);
+    pub(crate) const INT8: Self = Self(10);
+    pub(crate) const UINT8: Self = Self(11);
+    pub(crate) const INT16: Self = Self(12);
+    pub(crate) const UINT16: Self = Self(13);
+    pub(crate) const INTPTR: Self = Self(14);
+    pub(crate) const UINTPTR: Self = Self(15);
+}
@@ -0,0 +1,76 @@
+use std::{
+    ffi::{CStr, c_char},
+    os::raw::c_void,
+    ptr::NonNull,
+};
+
+use super::blob::Guid;
+
+#[repr(C)]
+pub(crate) struct ISlangSharedLibraryVtbl {
+    pub(crate) query_interface: unsafe extern "C" fn(
+        this: *mut ISlangSharedLibrary,
+        riid: *const Guid,
+        object: *mut *mut c_void,
+    )
+        -> i32,
+    pub(crate) add_ref: unsafe extern "C" fn(
+        this: *mut ISlangSharedLibrary,
+    ) -> u32,
+    pub(crate) release: unsafe extern "C" fn(
+        this: *mut ISlangSharedLibrary,
+    ) -> u32,
+
+    pub(crate) cast_as: unsafe extern "C" fn(
+        this: *mut ISlangSharedLibrary,
+        guid: *const Guid,
+    )
+        -> *mut c_void,
+
+    pub(crate) find_symbol_address_by_name:
+        unsafe extern "C" fn(
+            this: *mut ISlangSharedLibrary,
+            name: *const c_char,
+        ) -> *mut c_void,
+}
+
+#[repr(C)]
+pub(crate) struct ISlangSharedLibrary {
+    pub(crate) lp_vtable: *const ISlangSharedLibraryVtbl,
+}
+
+/// Code Slang compiled for and loaded into this process,
+/// released on drop.
+pub(crate) struct SharedLibrary(NonNull<ISlangSharedLibrary>);
+
+impl SharedLibrary {
+    /// Takes over the reference Slang returned.
+    pub(crate) fn new(
+        library: NonNull<ISlangSharedLibrary>,
+    ) -> Self {
+        Self(library)
+    }
+
+    pub(crate) fn symbol(
+        &self,
+        name: &CStr,
+    ) -> Option<NonNull<c_void>> {
+        let library = self.0.as_ptr();
+
+        NonNull::new(unsafe {
+            ((*(*library).lp_vtable)
+                .find_symbol_address_by_name)(
+                library,
+                name.as_ptr(),
+            )
+        })
+    }
+}
+
+impl Drop for SharedLibrary {
+    fn drop(&mut self) {
+        let library = self.0.as_ptr();
+
+        unsafe { ((*(*library).lp_vtable).release)(library) };
+    }
+}
@@ -0,0 +1,44 @@
+use crate::registry::Stage;
+
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangStage(u32);
+
+#[allow(unused)]
+impl SlangStage {
+    pub(crate) const NONE: Self = Self(0);
+    pub(crate) const VERTEX: Self = Self(1);
+    pub(crate) const HULL: Self = Self(2);
+    pub(crate) const DOMAIN: Self = Self(3);
+    pub(crate) const GEOMETRY: Self = Self(4);
+    pub(crate) const FRAGMENT: Self = Self(5);
+    pub(crate) const COMPUTE: Self = Self(6);
+    pub(crate) const RAY_GENERATION: Self = Self(7);
+    pub(crate) const INTERSECTION: Self = Self(8);
+    pub(crate) const ANY_HIT: Self = Self(9);
+    pub(crate) const CLOSEST_HIT: Self = Self(10);
+    pub(crate) const MISS: Self = Self(11);
+    pub(crate) const CALLABLE: Self = Self(12);
+    pub(crate) const MESH: Self = Self(13);
+    pub(crate) const AMPLIFICATION: Self = Self(14);
+
+    pub(crate) fn to_stage(self) -> Option<Stage> {
+        Some(match self {
+            Self::VERTEX => Stage::Vertex,
+            Self::HULL => Stage::TessellationControl,
+            Self::DOMAIN => Stage::TessellationEvaluation,
+            Self::GEOMETRY => Stage::Geometry,
+            Self::FRAGMENT => Stage::Fragment,
+            Self::COMPUTE => Stage::Compute,
+            Self::RAY_GENERATION => Stage::RayGeneration,
+            Self::INTERSECTION => Stage::Intersection,
+            Self::ANY_HIT => Stage::AnyHit,
+            Self::CLOSEST_HIT => Stage::ClosestHit,
+            Self::MISS => Stage::Miss,
+            Self::CALLABLE => Stage::Callable,
+            Self::MESH => Stage::Mesh,
+            Self::AMPLIFICATION => Stage::Task,
+            _ => return None,
+        })
+    }
+}
@@ -0,0 +1,28 @@
+#[repr(transparent)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct SlangTypeKind(u32);
+
+#[allow(unused)]
+impl SlangTypeKind {
+    pub(crate) const NONE: Self = Self(0);
+    pub(crate) const STRUCT: Self = Self(1);
+    pub(crate) const ARRAY: Self = Self(2);
+    pub(crate) const MATRIX: Self = Self(3);
+    pub(crate) const VECTOR: Self = Self(4);
+    pub(crate) const SCALAR: Self = Self(5);
+    pub(crate) const CONSTANT_BUFFER: Self = Self(6);
+    pub(crate) const RESOURCE: Self = Self(7);
+    pub(crate) const SAMPLER_STATE: Self = Self(8);
+    pub(crate) const TEXTURE_BUFFER: Self = Self(9);
+    pub(crate) const SHADER_STORAGE_BUFFER: Self = Self(10);
+    pub(crate) const PARAMETER_BLOCK: Self = Self(11);
+    pub(crate) const GENERIC_TYPE_PARAMETER: Self = Self(12);
+    pub(crate) const INTERFACE: Self = Self(13);
+    pub(crate) const OUTPUT_STREAM: Self = Self(14);
+    pub(crate) const MESH_OUTPUT: Self = Self(15);
+    pub(crate) const SPECIALIZED: Self = Self(16);
+    pub(crate) const FEEDBACK: Self = Self(17);
+    pub(crate) const POINTER: Self = Self(18);
+    pub(crate) const DYNAMIC_RESOURCE: Self = Self(19);
+    pub(crate) const COUNT_OF: Self = Self(20);
+}
@@ -0,0 +1,189 @@
+use super::{
+    compile_request::CompileRequest,
+    matrix_layout_mode::SlangMatrixLayoutMode,
+    parameter_category::SlangParameterCategory,
+    reflection_type_layout::SlangReflectionTypeLayoutRef,
+    scalar_type::SlangScalarType, type_kind::SlangTypeKind,
+};
+use crate::uniforms::{
+    Scalar, UniformField, UniformStruct, UniformType,
+    identifier,
+};
+
+const UNIFORM: SlangParameterCategory =
+    SlangParameterCategory::UNIFORM;
+
+impl CompileRequest {
+    /// Structs behind the constant buffers, parameter blocks and
+    /// storage buffers of the compiled program, nested structs
+    /// before the structs containing them.
+    pub(crate) fn uniform_structs(
+        &self,
+    ) -> crate::Result<Vec<UniformStruct>> {
+        let mut structs = Vec::new();
+
+        for parameter in self.reflection()?.parameters() {
+            let Some(type_layout) = parameter.type_layout()
+            else {
+                continue;
+            };
+
+            let element = match type_layout.kind() {
+                SlangTypeKind::CONSTANT_BUFFER
+                | SlangTypeKind::PARAMETER_BLOCK
+                | SlangTypeKind::TEXTURE_BUFFER
+                | SlangTypeKind::SHADER_STORAGE_BUFFER
+                | SlangTypeKind::RESOURCE => {
+                    type_layout.element_type_layout()
+                }
+                _ => None,
+            };
+
+            let Some(element) = element.filter(|element| {
+                element.kind() == SlangTypeKind::STRUCT
+            }) else {
+                continue;
+            };
+
+            // `cbuffer` blocks have no type name of their own.
+            let name = element
+                .reflection_type()
+                .and_then(|ty| ty.name())
+                .or_else(|| parameter.name());
+
+            if let Some(name) = name {
+                uniform_struct(&name, element, &mut structs);
+            }
+        }
+
+        Ok(structs)
+    }
+}
+
+/// Adds the struct and the structs it contains to `structs`,
+/// returning the name it got. A struct laid out differently
+/// than an earlier one of the same name, like one used in both
+/// a std140 and a std430 buffer, gets a numbered name.
+fn uniform_struct(
+    name: &str,
+    type_layout: SlangReflectionTypeLayoutRef,
+    structs: &mut Vec<UniformStruct>,
+) -> String {
+    let mut fields = Vec::new();
+
+    for index in 0..type_layout.field_count() {
+        let Some(field) = type_layout.field(index) else {
+            continue;
+        };
+
+        let ty = field
+            .type_layout()
+            .and_then(|field| uniform_type(field, structs));
+
+        // Resources and unsized arrays take no buffer space.
+        if let (Some(name), Some(ty)) = (field.name(), ty) {
+            fields.push(UniformField {
+                name,
+                offset: field.offset(UNIFORM),
+                ty,
+            });
+        }
+    }
+
+    let base = identifier(name);
+    let mut uniform = UniformStruct {
+        name: base.clone(),
+        size: type_layout.size(UNIFORM),
+        fields,
+    };
+
+    for number in 2.. {
+        match structs
+            .iter()
+            .find(|other| other.name == uniform.name)
+        {
+            Some(other) if *other == uniform => break,
+            Some(_) => uniform.name = format!("{base}{number}"),
+            None => {
+                structs.push(uniform.clone());
+                break;
+            }
+        }
+    }
+
+    uniform.name
+}
+
+fn uniform_type(
+    type_layout: SlangReflectionTypeLayoutRef,
+    structs: &mut Vec<UniformStruct>,
+) -> Option<UniformType> {
+    let ty = type_layout.reflection_type()?;
+
+    let uniform = match type_layout.kind() {
+        SlangTypeKind::SCALAR => {
+            UniformType::Scalar(scalar(ty.scalar_type())?)
+        }
+        SlangTypeKind::VECTOR => UniformType::Vector {
+            scalar: scalar(ty.scalar_type())?,
+            count: ty.element_count(),
+        },
+        SlangTypeKind::MATRIX => {
+            let vectors = if type_layout.matrix_layout_mode()
+                == SlangMatrixLayoutMode::ROW_MAJOR
+            {
+                ty.row_count()
+            } else {
+                ty.column_count()
+            } as usize;
+
+            UniformType::Matrix {
+                scalar: scalar(ty.scalar_type())?,
+                vectors,
+                stride: type_layout.size(UNIFORM)
+                    / vectors.max(1),
+            }
+        }
+        SlangTypeKind::ARRAY if ty.element_count() > 0 => {
+            UniformType::Array {
+                element: Box::new(uniform_type(
+                    type_layout.element_type_layout()?,
+                    structs,
+                )?),
+                count: ty.element_count(),
+                stride: type_layout.element_stride(UNIFORM),
+            }
+        }
+        SlangTypeKind::STRUCT => UniformType::Struct {
+            name: uniform_struct(
+                &ty.name()?,
+                type_layout,
+                structs,
+            ),
+            size: type_layout.size(UNIFORM),
+        },
+        _ => return None,
+    };
+
+    Some(uniform)
+}
+
+pub(super) fn scalar(
+    scalar: SlangScalarType,
+) -> Option<Scalar> {
+    Some(match scalar {
+        SlangScalarType::BOOL => Scalar::Bool,
+        SlangScalarType::INT8 => Scalar::I8,
+        SlangScalarType::UINT8 => Scalar::U8,
+        SlangScalarType::INT16 => Scalar::I16,
+        SlangScalarType::UINT16 => Scalar::U16,
+        SlangScalarType::FLOAT16 => Scalar::F16,
+        SlangScalarType::INT32 => Scalar::I32,
+        SlangScalarType::UINT32 => Scalar::U32,
+        SlangScalarType::FLOAT32 => Scalar::F32,
+        SlangScalarType::INT64 => Scalar::I64,
+        SlangScalarType::UINT64 => Scalar::U64,
+        SlangScalarType::FLOAT64 => Scalar::F64,
+        _ => return None,
+    })
+}
@@ -0,0 +1,392 @@
+use std::fmt::Write;
+
+/// Scalar types that can appear in buffers.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Scalar {
+    Bool,
+    I8,
+    U8,
+    I16,
+    U16,
+    F16,
+    I32,
+    U32,
+    F32,
+    I64,
+    U64,
+    F64,
+}
+
+impl Scalar {
+    pub(crate) const fn size(self) -> usize {
+        match self {
+            Self::I8 | Self::U8 => 1,
+            Self::I16 | Self::U16 | Self::F16 => 2,
+            Self::Bool | Self::I32 | Self::U32 | Self::F32 => 4,
+            Self::I64 | Self::U64 | Self::F64 => 8,
+        }
+    }
+
+    /// Rust type with the same size and bits. Buffers store
+    /// `bool` as 32 bits and Rust has no `f16` yet, so those are
+    /// `u32` and `u16`.
+    const fn rust_type(self) -> &'static str {
+        match self {
+            Self::Bool | Self::U32 => "u32",
+            Self::I8 => "i8",
+            Self::U8 => "u8",
+            Self::I16 => "i16",
+            Self::U16 | Self::F16 => "u16",
+            Self::I32 => "i32",
+            Self::F32 => "f32",
+            Self::I64 => "i64",
+            Self::U64 => "u64",
+            Self::F64 => "f64",
+        }
+    }
+}
+
+/// Type of a buffer field with the layout Slang reflected for
+/// it, so std140, std430 and scalar layouts all come out right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum UniformType {
+    Scalar(Scalar),
+    Vector {
+        scalar: Scalar,
+        count: usize,
+    },
+    /// Rows or columns, depending on the matrix layout, each
+    /// padded to `stride` bytes.
+    Matrix {
+        scalar: Scalar,
+        vectors: usize,
+        stride: usize,
+    },
+    Array {
+        element: Box<UniformType>,
+        count: usize,
+        stride: usize,
+    },
+    Struct {
+        name: String,
+        size: usize,
+    },
+}
+
+impl UniformType {
+    fn size(&self) -> usize {
+        match self {
+            Self::Scalar(scalar) => scalar.size(),
+            Self::Vector { scalar, count } => {
+                scalar.size() * count
+            }
+            Self::Matrix {
+                vectors, stride, ..
+            } => vectors * stride,
+            Self::Array { count, stride, .. } => count * stride,
+            Self::Struct { size, .. } => *size,
+        }
+    }
+
+    fn rust_type(&self) -> String {
+        match self {
+            Self::Scalar(scalar) => {
+                scalar.rust_type().to_owned()
+            }
+            Self::Vector { scalar, count } => {
+                format!("[{}; {count}]", scalar.rust_type())
+            }
+            Self::Matrix {
+                scalar,
+                vectors,
+                stride,
+            } => format!(
+                "[[{}; {}]; {vectors}]",
+                scalar.rust_type(),
+                stride / scalar.size(),
+            ),
+            Self::Array {
+                element,
+                count,
+                stride,
+            } => {
+                let padding = stride - element.size();
+
+                if padding == 0 {
+                    format!(
+                        "[{}; {count}]",
+                        element.rust_type()
+                    )
+                } else {
+                    format!(
+                        "[super::Padded<{}, {padding}>; {count}]",
+                        element.rust_type(),
+                    )
+                }
+            }
+            Self::Struct { name, .. } => name.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UniformField {
+    pub(crate) name: String,
+    pub(crate) offset: usize,
+    pub(crate) ty: UniformType,
+}
+
+/// Struct behind a constant buffer, parameter block or storage
+/// buffer, or nested in one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct UniformStruct {
+    pub(crate) name: String,
+    pub(crate) size: usize,
+    pub(crate) fields: Vec<UniformField>,
+}
+
+/// Header of the generated `uniforms.rs`, before the modules.
+const PRELUDE: &str = "\
+// Generated by slang-build from shader reflection. Do not edit.
+
+/// Array element followed by the padding the buffer layout
+/// puts between elements.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Padded<T, const N: usize> {
+    pub value: T,
+    pub padding: [u8; N],
+}
+
+impl<T, const N: usize> From<T> for Padded<T, N> {
+    fn from(value: T) -> Self {
+        Self {
+            value,
+            padding: [0; N],
+        }
+    }
+}
+";
+
+/// Source of the whole generated file, one module per shader
+/// module. Modules without buffers are left out.
+pub(crate) fn source<'a>(
+    modules: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> String {
+    let mut source = PRELUDE.to_owned();
+
+    for (name, body) in modules {
+        if body.is_empty() {
+            continue;
+        }
+
+        write!(source, "\npub mod {name} {{\n{body}}}\n")
+            .unwrap();
+    }
+
+    source
+}
+
+/// Structs of one shader module, with padding fields where the
+/// layout leaves gaps and compile-time checks of every offset.
+/// Fields that overlap, which a `#[repr(C)]` struct can't
+/// express, are an error.
+pub(crate) fn module_source(
+    structs: &[UniformStruct],
+) -> crate::Result<String> {
+    let mut source = String::new();
+
+    for uniform in structs {
+        let name = &uniform.name;
+        let mut cursor = 0;
+        let mut padding = 0;
+        let mut pad = |source: &mut String, size: usize| {
+            writeln!(
+                source,
+                "    pub _padding{padding}: [u8; {size}],"
+            )
+            .unwrap();
+            padding += 1;
+        };
+
+        write!(
+            source,
+            "\n#[repr(C)]\n\
+             #[derive(Clone, Copy, Debug, PartialEq)]\n\
+             pub struct {name} {{\n"
+        )
+        .unwrap();
+
+        for field in uniform.fields.iter() {
+            if field.offset < cursor {
+                return Err(crate::Error::OverlappingField {
+                    name: name.clone(),
+                    field: field.name.clone(),
+                });
+            }
+
+            if field.offset > cursor {
+                pad(&mut source, field.offset - cursor);
+            }
+
+            writeln!(
+                source,
+                "    pub {}: {},",
+                field_name(&field.name),
+                field.ty.rust_type(),
+            )
+            .unwrap();
+
+            cursor = field.offset + field.ty.size();
+        }
+
+        if uniform.size > cursor {
+            pad(&mut source, uniform.size - cursor);
+        }
+
+        write!(
+            source,
+            "}}\n\n\
+             impl Default for {name} {{\n    \
+                 fn default() -> Self {{\n        \
+                     // Only numbers, for which zeroes are valid.\n        \
+                     unsafe {{ std::mem::zeroed() }}\n    \
+                 }}\n\
+             }}\n\n\
+             const _: () = {{\n    \
+                 assert!(std::mem::size_of::<{name}>() == {});\n",
+            uniform.size,
+        )
+        .unwrap();
+
+        for field in uniform.fields.iter() {
+            writeln!(
+                source,
+                "    assert!(std::mem::offset_of!({name}, {}) == {});",
+                field_name(&field.name),
+                field.offset,
+            )
+            .unwrap();
+        }
+
+        source.push_str("};\n");
+    }
+
+    Ok(source)
+}
+
+/// Field name usable in Rust, escaping keywords.
+fn field_name(name: &str) -> String {
+    const KEYWORDS: &[&str] = &[
+        "as", "async", "await", "box", "const", "crate", "do",
+        "dyn", "enum", "extern", "final", "fn", "gen", "impl",
+        "in", "let", "loop", "macro", "match", "mod", "move",
+        "mut", "override", "priv", "pub", "ref", "static",
+        "struct", "trait", "type", "typeof", "unsafe",
+        "unsized", "use", "virtual", "where", "yield",
+    ];
+
+    if KEYWORDS.contains(&name) {
+        format!("r#{name}")
+    } else {
+        name.to_owned()
+    }
+}
+
+/// Identifier made of the characters Rust allows, for names of
+/// generic or otherwise decorated shader types.
+pub(crate) fn identifier(name: &str) -> String {
+    let name = name
+        .chars()
+        .map(
+            |c| if c.is_ascii_alphanumeric() { c } else { '_' },
+        )
+        .collect::<String>();
+
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{name}")
+    } else {
+        name
+    }
+}
+
+#[test]
+fn test_module_source() {
+    // `EmitQuadUvBlock` from `quad_emit_uv.slang` under std140.
+    let block = UniformStruct {
+        name: "EmitQuadUvBlock".to_owned(),
+        size: 64,
+        fields: vec![
+            UniformField {
+                name: "transform".to_owned(),
+                offset: 0,
+                ty: UniformType::Matrix {
+                    scalar: Scalar::F32,
+                    vectors: 3,
+                    stride: 16,
+                },
+            },
+            UniformField {
+                name: "position".to_owned(),
+                offset: 48,
+                ty: UniformType::Vector {
+                    scalar: Scalar::F32,
+                    count: 2,
+                },
+            },
+            UniformField {
+                name: "z".to_owned(),
+                offset: 56,
+                ty: UniformType::Scalar(Scalar::F32),
+            },
+            UniformField {
+                name: "type".to_owned(),
+                offset: 64,
+                ty: UniformType::Array {
+                    element: Box::new(UniformType::Scalar(
+                        Scalar::Bool,
+                    )),
+                    count: 2,
+                    stride: 16,
+                },
+            },
+        ],
+    };
+
+    let source = module_source(&[UniformStruct {
+        size: 96,
+        ..block.clone()
+    }])
+    .unwrap();
+
+    assert!(source.contains("pub transform: [[f32; 4]; 3],"));
+    assert!(
+        source.contains(
+            "pub z: f32,\n    pub _padding0: [u8; 4],"
+        )
+    );
+    assert!(
+        source.contains(
+            "pub r#type: [super::Padded<u32, 12>; 2],"
+        )
+    );
+    assert!(!source.contains("_padding1"));
+    assert!(source.contains(
+        "assert!(std::mem::offset_of!(EmitQuadUvBlock, z) == 56);"
+    ));
+    assert_eq!(identifier("Buffer<float4>"), "Buffer_float4_");
+
+    // `z` inside `position`.
+    let mut overlapping = block;
+    overlapping.fields[2].offset = 52;
+    assert!(module_source(&[overlapping]).is_err());
+}
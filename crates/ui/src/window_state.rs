@@ -27,6 +27,7 @@ impl WindowState {
                 &window,
                 size.width,
                 size.height,
+                graphics::SurfacePreference::default(),
             )?
         };
 